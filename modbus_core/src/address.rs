@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::register_types::ModbusType;
+
+/// Запись адресов при выводе списка тегов
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressNotation {
+    /// Десятичное число, как в tags.csv (с учетом нумерации файла)
+    #[default]
+    Decimal,
+    /// Смещение в протоколе, 0x0014
+    Hex,
+    /// 30011, 40021 (6 цифр для смещений от 9999)
+    Modicon,
+}
+
+impl AddressNotation {
+    pub const ALL: [AddressNotation; 3] = [AddressNotation::Decimal, AddressNotation::Hex, AddressNotation::Modicon];
+
+    /// Адрес тега в выбранной записи
+    pub fn format(self, offset: u16, modbus_type: ModbusType, one_based: bool) -> String {
+        match self {
+            AddressNotation::Decimal => (u32::from(offset) + u32::from(one_based)).to_string(),
+            AddressNotation::Hex => format!("0x{:04X}", offset),
+            AddressNotation::Modicon => {
+                let number = u32::from(offset) + 1;
+                if number <= 9999 {
+                    format!("{}{:04}", modbus_type.modicon_digit(), number)
+                } else {
+                    format!("{}{:05}", modbus_type.modicon_digit(), number)
+                }
+            }
+        }
+    }
+}
+
+/// Наибольшее смещение, которое можно записать в tags.csv: при нумерации с 1
+/// смещение 65535 записывается как 65536 и не помещается в поле адреса
+pub fn max_file_offset(one_based: bool) -> u16 {
+    if one_based { u16::MAX - 1 } else { u16::MAX }
+}
+
+/// Введенный адрес: смещение от нуля и область, если она следует из записи Modicon
+#[derive(Debug, Clone, Copy)]
+pub struct ParsedAddress {
+    pub offset: u16,
+    pub area: Option<ModbusType>,
+}
+
+/// Разбор десятичного (с учетом нумерации файла) или шестнадцатеричного адреса.
+/// Шестнадцатеричный адрес всегда считается смещением в протоколе.
+pub fn parse_plain_address(text: &str, one_based: bool) -> Option<u16> {
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        return u16::from_str_radix(hex, 16).ok();
+    }
+    let number: u32 = text.parse().ok()?;
+    let offset = if one_based { number.checked_sub(1)? } else { number };
+    u16::try_from(offset).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_address_respects_numbering() {
+        assert_eq!(parse_plain_address("10", false), Some(10));
+        assert_eq!(parse_plain_address("10", true), Some(9));
+        assert_eq!(parse_plain_address("0", true), None);
+        assert_eq!(parse_plain_address("65535", false), Some(65535));
+        assert_eq!(parse_plain_address("65536", false), None);
+        assert_eq!(parse_plain_address("65536", true), Some(65535));
+        assert_eq!(parse_plain_address("abc", false), None);
+    }
+
+    #[test]
+    fn hex_address_is_protocol_offset() {
+        assert_eq!(parse_plain_address("0x0014", true), Some(0x14));
+        assert_eq!(parse_plain_address("0XFFFF", false), Some(0xFFFF));
+        assert_eq!(parse_plain_address("0x10000", false), None);
+    }
+
+    #[test]
+    fn format_in_every_notation() {
+        let holding = ModbusType::HoldingRegister;
+        assert_eq!(AddressNotation::Decimal.format(20, holding, false), "20");
+        assert_eq!(AddressNotation::Decimal.format(20, holding, true), "21");
+        assert_eq!(AddressNotation::Decimal.format(65535, holding, true), "65536");
+        assert_eq!(AddressNotation::Hex.format(20, holding, true), "0x0014");
+        assert_eq!(AddressNotation::Modicon.format(20, holding, false), "40021");
+        assert_eq!(AddressNotation::Modicon.format(10, ModbusType::InputRegister, true), "30011");
+        assert_eq!(AddressNotation::Modicon.format(9998, ModbusType::Coil, false), "09999");
+        assert_eq!(AddressNotation::Modicon.format(9999, holding, false), "410000");
+        assert_eq!(AddressNotation::Modicon.format(65535, holding, false), "465536");
+    }
+
+    #[test]
+    fn max_file_offset_by_numbering() {
+        assert_eq!(max_file_offset(false), 65535);
+        assert_eq!(max_file_offset(true), 65534);
+    }
+}
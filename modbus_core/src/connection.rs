@@ -0,0 +1,134 @@
+use std::fmt;
+use std::io;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_modbus::prelude::*;
+use tokio_serial::{SerialPort, SerialStream};
+
+use crate::rs485::DirectionControl;
+use crate::rtu_timing::{RtuTiming, TimedTransport};
+use crate::settings::{ConnectionSettings, ControlLines, FlowControl, PinState, RtsMode};
+
+/// Параметры последовательного порта по настройкам подключения.
+/// Четность Mark/Space в builder не задается - она включается после открытия порта.
+pub fn serial_port_builder(conn: &ConnectionSettings) -> tokio_serial::SerialPortBuilder {
+    let parity = match conn.parity.as_str() {
+        "None" => tokio_serial::Parity::None,
+        "Even" => tokio_serial::Parity::Even,
+        "Odd" => tokio_serial::Parity::Odd,
+        _ => tokio_serial::Parity::None,
+    };
+
+    let stop_bits = match conn.stop_bits {
+        1 => tokio_serial::StopBits::One,
+        2 => tokio_serial::StopBits::Two,
+        _ => tokio_serial::StopBits::One,
+    };
+
+    let data_bits = match conn.data_bits {
+        5 => tokio_serial::DataBits::Five,
+        6 => tokio_serial::DataBits::Six,
+        7 => tokio_serial::DataBits::Seven,
+        _ => tokio_serial::DataBits::Eight,
+    };
+
+    let flow_control = match conn.flow_control {
+        FlowControl::None => tokio_serial::FlowControl::None,
+        FlowControl::Software => tokio_serial::FlowControl::Software,
+        FlowControl::Hardware => tokio_serial::FlowControl::Hardware,
+    };
+
+    tokio_serial::new(&conn.port, conn.baud_rate)
+        .data_bits(data_bits)
+        .parity(parity)
+        .stop_bits(stop_bits)
+        .flow_control(flow_control)
+}
+
+/// Открытие последовательного порта: параметры кадра, четность Mark/Space и линии RTS/DTR
+pub fn open_serial_port(conn: &ConnectionSettings) -> io::Result<SerialStream> {
+    let mut port = SerialStream::open(&serial_port_builder(conn))?;
+    match conn.parity.as_str() {
+        "Mark" => set_stick_parity(&port, true)?,
+        "Space" => set_stick_parity(&port, false)?,
+        _ => {}
+    }
+    set_control_lines(&mut port, &conn.control_lines)?;
+    Ok(port)
+}
+
+/// Поток последовательного порта: сам порт или порт с переключением направления RS-485
+pub trait SerialIo: AsyncRead + AsyncWrite + Unpin + Send + fmt::Debug {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send + fmt::Debug> SerialIo for T {}
+
+/// Транспорт RTU на открытом порту: интервалы t1.5/t3.5 (`TimedTransport`) и,
+/// если RTS управляет направлением RS-485, `DirectionControl`
+pub fn rtu_transport(port: SerialStream, conn: &ConnectionSettings) -> io::Result<TimedTransport<Box<dyn SerialIo>>> {
+    let port: Box<dyn SerialIo> = match conn.control_lines.rts.transmit_level() {
+        Some(level) => Box::new(DirectionControl::new(port, level, &conn.control_lines)?),
+        None => Box::new(port),
+    };
+    Ok(TimedTransport::new(port, RtuTiming::for_settings(conn)))
+}
+
+/// Создание контекста Modbus RTU на открытом порту (адрес устройства задается вызывающим)
+pub async fn rtu_context(port: SerialStream, conn: &ConnectionSettings) -> io::Result<client::Context> {
+    rtu::connect(rtu_transport(port, conn)?).await
+}
+
+/// Открытие порта и создание контекста Modbus RTU (адрес устройства задается вызывающим)
+pub async fn connect_rtu(conn: &ConnectionSettings) -> io::Result<client::Context> {
+    let port = open_serial_port(conn)?;
+    rtu_context(port, conn).await
+}
+
+/// Постоянные уровни RTS/DTR; RTS в режиме передачи переключает `DirectionControl`
+fn set_control_lines(port: &mut SerialStream, lines: &ControlLines) -> io::Result<()> {
+    match lines.rts {
+        RtsMode::On => port.write_request_to_send(true)?,
+        RtsMode::Off => port.write_request_to_send(false)?,
+        RtsMode::Unchanged | RtsMode::Transmit | RtsMode::TransmitInverted => {}
+    }
+    match lines.dtr {
+        PinState::On => port.write_data_terminal_ready(true)?,
+        PinState::Off => port.write_data_terminal_ready(false)?,
+        PinState::Unchanged => {}
+    }
+    Ok(())
+}
+
+/// Четность с постоянным битом (Mark - 1, Space - 0) через флаг CMSPAR termios
+#[cfg(target_os = "linux")]
+fn set_stick_parity(port: &SerialStream, mark: bool) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let fd = port.as_raw_fd();
+    // termios2, как и в serialport: сохраняет произвольную скорость порта
+    let mut termios = std::mem::MaybeUninit::<libc::termios2>::uninit();
+    // SAFETY: fd открытого порта, структура заполняется ядром перед чтением
+    let mut termios = unsafe {
+        if libc::ioctl(fd, libc::TCGETS2, termios.as_mut_ptr()) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        termios.assume_init()
+    };
+    termios.c_cflag |= libc::PARENB | libc::CMSPAR;
+    if mark {
+        termios.c_cflag |= libc::PARODD;
+    } else {
+        termios.c_cflag &= !libc::PARODD;
+    }
+    // SAFETY: структура получена от TCGETS2 для того же fd
+    if unsafe { libc::ioctl(fd, libc::TCSETS2, &termios) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_stick_parity(_port: &SerialStream, _mark: bool) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "четность Mark/Space поддерживается только в Linux",
+    ))
+}
@@ -0,0 +1,346 @@
+use crate::poller::ReadError;
+use crate::raw_request::{hex_bytes, RawClient};
+use crate::settings::{DeviceInfo, DeviceInfoObject};
+
+/// Функция 0x2B (Encapsulated Interface Transport) и тип MEI 0x0E
+const FUNCTION: u8 = 0x2B;
+const MEI_READ_DEVICE_ID: u8 = 0x0E;
+/// Признак "есть продолжение" в ответе
+const MORE_FOLLOWS: u8 = 0xFF;
+/// Ограничение числа запросов на случай устройства, которое не завершает поток
+const MAX_TRANSACTIONS: usize = 64;
+
+/// Категория объектов идентификации (ReadDevId code)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadDeviceIdCode {
+    /// Объекты 0x00-0x02: производитель, код изделия, версия
+    Basic,
+    /// Объекты 0x00-0x7F: добавляются URL, название, модель, приложение
+    Regular,
+    /// Все объекты, включая частные 0x80-0xFF
+    Extended,
+}
+
+impl ReadDeviceIdCode {
+    pub const ALL: [ReadDeviceIdCode; 3] = [
+        ReadDeviceIdCode::Basic,
+        ReadDeviceIdCode::Regular,
+        ReadDeviceIdCode::Extended,
+    ];
+
+    pub fn code(self) -> u8 {
+        match self {
+            ReadDeviceIdCode::Basic => 0x01,
+            ReadDeviceIdCode::Regular => 0x02,
+            ReadDeviceIdCode::Extended => 0x03,
+        }
+    }
+
+    /// Название категории в спецификации
+    pub fn name(self) -> &'static str {
+        match self {
+            ReadDeviceIdCode::Basic => "basic",
+            ReadDeviceIdCode::Regular => "regular",
+            ReadDeviceIdCode::Extended => "extended",
+        }
+    }
+}
+
+/// Имя объекта по спецификации Modbus Application Protocol (6.21)
+pub fn object_name(id: u8) -> String {
+    match id {
+        0x00 => "VendorName".to_string(),
+        0x01 => "ProductCode".to_string(),
+        0x02 => "MajorMinorRevision".to_string(),
+        0x03 => "VendorUrl".to_string(),
+        0x04 => "ProductName".to_string(),
+        0x05 => "ModelName".to_string(),
+        0x06 => "UserApplicationName".to_string(),
+        0x07..=0x7F => format!("Reserved 0x{:02X}", id),
+        _ => format!("Private 0x{:02X}", id),
+    }
+}
+
+/// Уровень соответствия устройства: старшая категория объектов
+/// и поддержка чтения отдельных объектов (бит 0x80)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conformity {
+    pub category: ReadDeviceIdCode,
+    pub individual_access: bool,
+}
+
+impl Conformity {
+    /// None - уровень не описан в спецификации
+    pub fn from_level(level: u8) -> Option<Self> {
+        let category = ReadDeviceIdCode::ALL
+            .into_iter()
+            .find(|category| category.code() == level & 0x7F)?;
+        Some(Conformity {
+            category,
+            individual_access: level & 0x80 != 0,
+        })
+    }
+}
+
+/// Объект идентификации устройства
+#[derive(Debug, Clone)]
+pub struct DeviceObject {
+    pub id: u8,
+    pub value: Vec<u8>,
+}
+
+impl DeviceObject {
+    pub fn name(&self) -> String {
+        object_name(self.id)
+    }
+
+    /// Значение строкой; данные с непечатаемыми символами выводятся в hex
+    pub fn text(&self) -> String {
+        match std::str::from_utf8(&self.value) {
+            Ok(text) if !text.chars().any(char::is_control) => text.to_string(),
+            _ => hex_bytes(&self.value),
+        }
+    }
+}
+
+/// Результат Read Device Identification
+#[derive(Debug, Clone)]
+pub struct DeviceIdentification {
+    pub conformity_level: u8,
+    pub objects: Vec<DeviceObject>,
+}
+
+impl DeviceIdentification {
+    pub fn conformity(&self) -> Option<Conformity> {
+        Conformity::from_level(self.conformity_level)
+    }
+
+    /// Запись для сохранения в метаданных профиля подключения
+    pub fn to_info(&self, device_address: u8) -> DeviceInfo {
+        DeviceInfo {
+            device_address,
+            read_at: chrono::Local::now().to_rfc3339(),
+            conformity_level: self.conformity_level,
+            objects: self
+                .objects
+                .iter()
+                .map(|object| DeviceInfoObject {
+                    id: object.id,
+                    name: object.name(),
+                    value: object.text(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Чтение идентификации устройства потоком: запросы повторяются с номера
+/// следующего объекта, пока устройство сообщает о продолжении
+pub async fn read_device_identification(
+    client: &mut RawClient,
+    slave: u8,
+    category: ReadDeviceIdCode,
+) -> Result<DeviceIdentification, ReadError> {
+    let mut identification = DeviceIdentification {
+        conformity_level: 0,
+        objects: Vec::new(),
+    };
+    let mut object_id = 0u8;
+    let mut response = Vec::new();
+    for _ in 0..MAX_TRANSACTIONS {
+        let pdu = [FUNCTION, MEI_READ_DEVICE_ID, category.code(), object_id];
+        response = client.request(slave, &pdu).await?;
+        let page = parse_response(&response)?;
+        identification.conformity_level = page.conformity_level;
+        identification.objects.extend(page.objects);
+        if page.more_follows != MORE_FOLLOWS {
+            return Ok(identification);
+        }
+        // Следующий объект должен идти дальше по списку, иначе поток зациклится
+        if page.next_object_id <= object_id {
+            return Err(ReadError::Malformed(response));
+        }
+        object_id = page.next_object_id;
+    }
+    // Устройство не завершило передачу объектов
+    Err(ReadError::Malformed(response))
+}
+
+/// Одна часть потока объектов
+struct ResponsePage {
+    conformity_level: u8,
+    more_follows: u8,
+    next_object_id: u8,
+    objects: Vec<DeviceObject>,
+}
+
+/// Разбор PDU ответа: 2B 0E code conformity more next count (id len value)...
+fn parse_response(pdu: &[u8]) -> Result<ResponsePage, ReadError> {
+    let malformed = || ReadError::Malformed(pdu.to_vec());
+    if pdu.len() < 7 || pdu[1] != MEI_READ_DEVICE_ID {
+        return Err(malformed());
+    }
+    let count = usize::from(pdu[6]);
+    let mut objects = Vec::with_capacity(count);
+    let mut rest = &pdu[7..];
+    for _ in 0..count {
+        let [id, len, tail @ ..] = rest else {
+            return Err(malformed());
+        };
+        let len = usize::from(*len);
+        if tail.len() < len {
+            return Err(malformed());
+        }
+        objects.push(DeviceObject {
+            id: *id,
+            value: tail[..len].to_vec(),
+        });
+        rest = &tail[len..];
+    }
+    Ok(ResponsePage {
+        conformity_level: pdu[3],
+        more_follows: pdu[4],
+        next_object_id: pdu[5],
+        objects,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
+
+    use crate::raw_request::rtu_adu;
+
+    /// PDU ответа: заголовок и объекты (id, значение)
+    fn page(conformity: u8, more_follows: u8, next_object_id: u8, objects: &[(u8, &str)]) -> Vec<u8> {
+        let mut pdu = vec![FUNCTION, MEI_READ_DEVICE_ID, 0x01, conformity, more_follows, next_object_id];
+        pdu.push(objects.len() as u8);
+        for (id, value) in objects {
+            pdu.push(*id);
+            pdu.push(value.len() as u8);
+            pdu.extend_from_slice(value.as_bytes());
+        }
+        pdu
+    }
+
+    /// Устройство: на каждый запрос отвечает следующей страницей; возвращает PDU запросов
+    async fn device(line: &mut DuplexStream, pages: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        let mut requests = Vec::new();
+        for page in pages {
+            let mut request = [0u8; 7];
+            if line.read_exact(&mut request).await.is_err() {
+                break;
+            }
+            requests.push(request[1..5].to_vec());
+            line.write_all(&rtu_adu(1, page)).await.unwrap();
+        }
+        requests
+    }
+
+    async fn read_with_pages(
+        category: ReadDeviceIdCode,
+        pages: &[Vec<u8>],
+    ) -> (Result<DeviceIdentification, ReadError>, Vec<Vec<u8>>) {
+        let (mut client, mut line) = RawClient::in_memory(Duration::from_millis(200));
+        let (result, requests) = tokio::join!(
+            async {
+                let result = read_device_identification(&mut client, 1, category).await;
+                // Закрытие линии завершает устройство, если оно ждет лишний запрос
+                drop(client);
+                result
+            },
+            device(&mut line, pages)
+        );
+        (result, requests)
+    }
+
+    #[test]
+    fn parse_single_page() {
+        let parsed = parse_response(&page(0x81, 0x00, 0x00, &[(0x00, "ACME"), (0x02, "1.2")])).unwrap();
+        assert_eq!(parsed.conformity_level, 0x81);
+        assert_eq!(parsed.more_follows, 0x00);
+        let objects: Vec<(u8, String)> = parsed.objects.iter().map(|o| (o.id, o.text())).collect();
+        assert_eq!(objects, [(0x00, "ACME".to_string()), (0x02, "1.2".to_string())]);
+    }
+
+    #[test]
+    fn parse_rejects_truncated_and_foreign_responses() {
+        let mut truncated = page(0x01, 0x00, 0x00, &[(0x00, "ACME")]);
+        truncated.pop();
+        let mut missing_object = page(0x01, 0x00, 0x00, &[(0x00, "ACME")]);
+        missing_object[6] = 2;
+        let mut other_mei = page(0x01, 0x00, 0x00, &[]);
+        other_mei[1] = 0x0D;
+        for pdu in [truncated, missing_object, other_mei, vec![FUNCTION, MEI_READ_DEVICE_ID, 0x01]] {
+            assert!(matches!(parse_response(&pdu), Err(ReadError::Malformed(ref p)) if *p == pdu), "{}", hex_bytes(&pdu));
+        }
+    }
+
+    #[test]
+    fn conformity_levels() {
+        assert_eq!(
+            Conformity::from_level(0x83),
+            Some(Conformity {
+                category: ReadDeviceIdCode::Extended,
+                individual_access: true
+            })
+        );
+        assert_eq!(
+            Conformity::from_level(0x01),
+            Some(Conformity {
+                category: ReadDeviceIdCode::Basic,
+                individual_access: false
+            })
+        );
+        assert_eq!(Conformity::from_level(0x00), None);
+        assert_eq!(Conformity::from_level(0x84), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stream_single_page() {
+        let pages = [page(0x01, 0x00, 0x00, &[(0x00, "ACME"), (0x01, "X-1"), (0x02, "1.0")])];
+        let (result, requests) = read_with_pages(ReadDeviceIdCode::Basic, &pages).await;
+        let identification = result.unwrap();
+        assert_eq!(identification.objects.len(), 3);
+        assert_eq!(requests, [vec![FUNCTION, MEI_READ_DEVICE_ID, 0x01, 0x00]]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stream_multiple_pages() {
+        let pages = [
+            page(0x82, MORE_FOLLOWS, 0x02, &[(0x00, "ACME"), (0x01, "X-1")]),
+            page(0x82, MORE_FOLLOWS, 0x05, &[(0x02, "1.0"), (0x03, "acme.example"), (0x04, "Meter")]),
+            page(0x82, 0x00, 0x00, &[(0x05, "M-100")]),
+        ];
+        let (result, requests) = read_with_pages(ReadDeviceIdCode::Regular, &pages).await;
+        let identification = result.unwrap();
+        let ids: Vec<u8> = identification.objects.iter().map(|object| object.id).collect();
+        assert_eq!(ids, [0x00, 0x01, 0x02, 0x03, 0x04, 0x05]);
+        assert_eq!(identification.conformity_level, 0x82);
+        // Каждый следующий запрос начинается с объекта, указанного устройством
+        let starts: Vec<u8> = requests.iter().map(|request| request[3]).collect();
+        assert_eq!(starts, [0x00, 0x02, 0x05]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stream_truncated_object_is_malformed() {
+        let mut truncated = page(0x01, 0x00, 0x00, &[(0x00, "ACME")]);
+        truncated.truncate(truncated.len() - 2);
+        let (result, _) = read_with_pages(ReadDeviceIdCode::Basic, &[truncated.clone()]).await;
+        assert!(matches!(result, Err(ReadError::Malformed(ref pdu)) if *pdu == truncated), "{:?}", result);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stream_stops_when_next_id_does_not_advance() {
+        let pages = [
+            page(0x02, MORE_FOLLOWS, 0x03, &[(0x00, "ACME")]),
+            page(0x02, MORE_FOLLOWS, 0x03, &[(0x03, "acme.example")]),
+            page(0x02, 0x00, 0x00, &[]),
+        ];
+        let (result, requests) = read_with_pages(ReadDeviceIdCode::Regular, &pages).await;
+        assert!(matches!(result, Err(ReadError::Malformed(ref pdu)) if *pdu == pages[1]), "{:?}", result);
+        assert_eq!(requests.len(), 2);
+    }
+}
@@ -0,0 +1,397 @@
+use std::time::{Duration, Instant};
+
+use crate::poller::ReadError;
+use crate::raw_request::RawClient;
+
+/// Функция 0x08 (Diagnostics) и ее подфункции
+const FUNCTION_DIAGNOSTICS: u8 = 0x08;
+const RETURN_QUERY_DATA: u16 = 0x0000;
+const CLEAR_COUNTERS: u16 = 0x000A;
+/// Функции журнала событий последовательной линии
+const FUNCTION_COMM_EVENT_COUNTER: u8 = 0x0B;
+const FUNCTION_COMM_EVENT_LOG: u8 = 0x0C;
+/// Слово состояния "устройство занято выполнением предыдущей команды"
+const STATUS_BUSY: u16 = 0xFFFF;
+
+/// Счетчик устройства, читаемый подфункцией 0x08
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlaveCounter {
+    /// Все сообщения, обнаруженные на линии (0x0B)
+    BusMessages,
+    /// Сообщения с ошибкой CRC (0x0C)
+    BusCommErrors,
+    /// Ответы-исключения устройства (0x0D)
+    BusExceptions,
+    /// Сообщения, адресованные устройству (0x0E)
+    SlaveMessages,
+    /// Сообщения, оставленные без ответа (0x0F)
+    SlaveNoResponse,
+    /// Ответы NAK (0x10)
+    SlaveNak,
+    /// Ответы "устройство занято" (0x11)
+    SlaveBusy,
+    /// Потерянные символы из-за переполнения приемника (0x12)
+    CharacterOverrun,
+}
+
+impl SlaveCounter {
+    pub const ALL: [SlaveCounter; 8] = [
+        SlaveCounter::BusMessages,
+        SlaveCounter::BusCommErrors,
+        SlaveCounter::BusExceptions,
+        SlaveCounter::SlaveMessages,
+        SlaveCounter::SlaveNoResponse,
+        SlaveCounter::SlaveNak,
+        SlaveCounter::SlaveBusy,
+        SlaveCounter::CharacterOverrun,
+    ];
+
+    pub fn sub_function(self) -> u16 {
+        match self {
+            SlaveCounter::BusMessages => 0x000B,
+            SlaveCounter::BusCommErrors => 0x000C,
+            SlaveCounter::BusExceptions => 0x000D,
+            SlaveCounter::SlaveMessages => 0x000E,
+            SlaveCounter::SlaveNoResponse => 0x000F,
+            SlaveCounter::SlaveNak => 0x0010,
+            SlaveCounter::SlaveBusy => 0x0011,
+            SlaveCounter::CharacterOverrun => 0x0012,
+        }
+    }
+}
+
+/// Результат функции 0x0B (Get Comm Event Counter)
+#[derive(Debug, Clone, Copy)]
+pub struct CommEventCounter {
+    pub status: u16,
+    /// Число успешно выполненных сообщений
+    pub event_count: u16,
+}
+
+impl CommEventCounter {
+    /// Устройство еще выполняет предыдущую команду
+    pub fn busy(&self) -> bool {
+        self.status == STATUS_BUSY
+    }
+}
+
+/// Результат функции 0x0C (Get Comm Event Log)
+#[derive(Debug, Clone)]
+pub struct CommEventLog {
+    pub status: u16,
+    pub event_count: u16,
+    pub message_count: u16,
+    /// События, последнее - первым
+    pub events: Vec<u8>,
+}
+
+impl CommEventLog {
+    pub fn busy(&self) -> bool {
+        self.status == STATUS_BUSY
+    }
+}
+
+/// Байт события журнала (Modbus Application Protocol, 6.13)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommEvent {
+    /// Устройство приняло запрос (бит 7)
+    Receive {
+        comm_error: bool,
+        character_overrun: bool,
+        listen_only: bool,
+        broadcast: bool,
+    },
+    /// Устройство отправило ответ (бит 6)
+    Send {
+        /// Исключения 1-3
+        read_exception: bool,
+        /// Исключение 4
+        abort_exception: bool,
+        /// Исключения 5-6
+        busy_exception: bool,
+        /// Исключение 7
+        nak_exception: bool,
+        write_timeout: bool,
+        listen_only: bool,
+    },
+    /// Перезапуск связи (0x00)
+    CommRestart,
+    /// Переход в режим только прослушивания (0x04)
+    EnteredListenOnly,
+    Unknown(u8),
+}
+
+impl CommEvent {
+    pub fn from_byte(event: u8) -> Self {
+        let bit = |n: u8| event & (1 << n) != 0;
+        if bit(7) {
+            CommEvent::Receive {
+                comm_error: bit(1),
+                character_overrun: bit(4),
+                listen_only: bit(5),
+                broadcast: bit(6),
+            }
+        } else if bit(6) {
+            CommEvent::Send {
+                read_exception: bit(0),
+                abort_exception: bit(1),
+                busy_exception: bit(2),
+                nak_exception: bit(3),
+                write_timeout: bit(4),
+                listen_only: bit(5),
+            }
+        } else {
+            match event {
+                0x00 => CommEvent::CommRestart,
+                0x04 => CommEvent::EnteredListenOnly,
+                _ => CommEvent::Unknown(event),
+            }
+        }
+    }
+}
+
+/// Эхо-тест (0x08/0x0000 Return Query Data).
+/// Возвращает true, если устройство вернуло данные без искажений.
+pub async fn return_query_data(client: &mut RawClient, slave: u8, data: &[u8]) -> Result<bool, ReadError> {
+    let request = diagnostics_pdu(RETURN_QUERY_DATA, data);
+    let response = client.request(slave, &request).await?;
+    Ok(response == request)
+}
+
+/// Чтение счетчика устройства (0x08/0x000B-0x0012)
+pub async fn read_counter(client: &mut RawClient, slave: u8, counter: SlaveCounter) -> Result<u16, ReadError> {
+    let request = diagnostics_pdu(counter.sub_function(), &[0, 0]);
+    let response = client.request(slave, &request).await?;
+    match response.as_slice() {
+        [_, sub_hi, sub_lo, hi, lo] if [*sub_hi, *sub_lo] == request[1..3] => Ok(u16::from_be_bytes([*hi, *lo])),
+        _ => Err(malformed(&response)),
+    }
+}
+
+/// Сброс счетчиков и журнала событий устройства (0x08/0x000A)
+pub async fn clear_counters(client: &mut RawClient, slave: u8) -> Result<(), ReadError> {
+    let request = diagnostics_pdu(CLEAR_COUNTERS, &[0, 0]);
+    let response = client.request(slave, &request).await?;
+    if response != request {
+        return Err(malformed(&response));
+    }
+    Ok(())
+}
+
+/// Счетчик событий связи (0x0B)
+pub async fn get_comm_event_counter(client: &mut RawClient, slave: u8) -> Result<CommEventCounter, ReadError> {
+    let response = client.request(slave, &[FUNCTION_COMM_EVENT_COUNTER]).await?;
+    match response.as_slice() {
+        [_, s_hi, s_lo, c_hi, c_lo] => Ok(CommEventCounter {
+            status: u16::from_be_bytes([*s_hi, *s_lo]),
+            event_count: u16::from_be_bytes([*c_hi, *c_lo]),
+        }),
+        _ => Err(malformed(&response)),
+    }
+}
+
+/// Журнал событий связи (0x0C): 0C count status events messages (event)...
+pub async fn get_comm_event_log(client: &mut RawClient, slave: u8) -> Result<CommEventLog, ReadError> {
+    let response = client.request(slave, &[FUNCTION_COMM_EVENT_LOG]).await?;
+    match response.as_slice() {
+        [_, count, s_hi, s_lo, e_hi, e_lo, m_hi, m_lo, events @ ..] if usize::from(*count) == events.len() + 6 => {
+            Ok(CommEventLog {
+                status: u16::from_be_bytes([*s_hi, *s_lo]),
+                event_count: u16::from_be_bytes([*e_hi, *e_lo]),
+                message_count: u16::from_be_bytes([*m_hi, *m_lo]),
+                events: events.to_vec(),
+            })
+        }
+        _ => Err(malformed(&response)),
+    }
+}
+
+fn diagnostics_pdu(sub_function: u16, data: &[u8]) -> Vec<u8> {
+    let mut pdu = vec![FUNCTION_DIAGNOSTICS];
+    pdu.extend_from_slice(&sub_function.to_be_bytes());
+    pdu.extend_from_slice(data);
+    pdu
+}
+
+fn malformed(pdu: &[u8]) -> ReadError {
+    ReadError::Malformed(pdu.to_vec())
+}
+
+/// Статистика связи со стороны мастера за сеанс диагностики
+#[derive(Debug, Clone, Default)]
+pub struct LinkStats {
+    pub requests: u64,
+    pub responses: u64,
+    pub timeouts: u64,
+    /// Ошибки CRC, порта и разбора ответа
+    pub frame_errors: u64,
+    pub exceptions: u64,
+    /// Эхо-ответы с искаженными данными
+    pub echo_mismatches: u64,
+    total_latency: Duration,
+    pub min_latency: Option<Duration>,
+    pub max_latency: Option<Duration>,
+}
+
+impl LinkStats {
+    /// Выполнение запроса с учетом результата и времени ответа
+    pub async fn track<T, F>(&mut self, request: F) -> Result<T, ReadError>
+    where
+        F: Future<Output = Result<T, ReadError>>,
+    {
+        let started = Instant::now();
+        let result = request.await;
+        self.record(&result, started.elapsed());
+        result
+    }
+
+    /// Учет одного запроса
+    pub fn record<T>(&mut self, result: &Result<T, ReadError>, latency: Duration) {
+        self.requests += 1;
+        match result {
+            Ok(_) | Err(ReadError::Exception(_)) => {
+                self.responses += 1;
+                self.total_latency += latency;
+                self.min_latency = Some(self.min_latency.map_or(latency, |min| min.min(latency)));
+                self.max_latency = Some(self.max_latency.map_or(latency, |max| max.max(latency)));
+                if result.is_err() {
+                    self.exceptions += 1;
+                }
+            }
+            Err(ReadError::Timeout) => self.timeouts += 1,
+            Err(ReadError::Crc(_) | ReadError::Malformed(_) | ReadError::Io(_)) => self.frame_errors += 1,
+        }
+    }
+
+    /// Среднее время ответа
+    pub fn average_latency(&self) -> Option<Duration> {
+        u32::try_from(self.responses)
+            .ok()
+            .filter(|count| *count > 0)
+            .map(|count| self.total_latency / count)
+    }
+
+    /// Доля запросов без ответа или с испорченным ответом, %
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            return 0.0;
+        }
+        (self.timeouts + self.frame_errors + self.echo_mismatches) as f64 * 100.0 / self.requests as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use crate::raw_request::rtu_adu;
+
+    /// Запрос к устройству 1 на линии в памяти; устройство отвечает PDU `response`
+    async fn with_response<T>(
+        request_len: usize,
+        response: &[u8],
+        call: impl AsyncFnOnce(&mut RawClient) -> Result<T, ReadError>,
+    ) -> Result<T, ReadError> {
+        let (mut client, mut line) = RawClient::in_memory(Duration::from_millis(200));
+        let device = async {
+            let mut request = vec![0u8; request_len + 3];
+            line.read_exact(&mut request).await.unwrap();
+            line.write_all(&rtu_adu(1, response)).await.unwrap();
+        };
+        let (result, _) = tokio::join!(call(&mut client), device);
+        result
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn event_log_byte_count_covers_header_and_events() {
+        let log = with_response(1, &[0x0C, 0x08, 0x00, 0x00, 0x01, 0x2C, 0x01, 0x30, 0x20, 0x00], async |client| {
+            get_comm_event_log(client, 1).await
+        })
+        .await
+        .unwrap();
+        assert!(!log.busy());
+        assert_eq!((log.event_count, log.message_count), (300, 304));
+        assert_eq!(log.events, [0x20, 0x00]);
+
+        // Счетчик байт = 6 + число событий; иначе ответ отбрасывается
+        for count in [0x07, 0x09, 0x02] {
+            let response = [0x0C, count, 0xFF, 0xFF, 0x00, 0x01, 0x00, 0x02, 0x20, 0x00];
+            let result = with_response(1, &response, async |client| get_comm_event_log(client, 1).await).await;
+            assert!(matches!(result, Err(ReadError::Malformed(ref pdu)) if *pdu == response), "{:?}", result);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn counter_requires_sub_function_echo() {
+        let value = with_response(5, &[0x08, 0x00, 0x0E, 0x01, 0x02], async |client| {
+            read_counter(client, 1, SlaveCounter::SlaveMessages).await
+        })
+        .await;
+        assert_eq!(value.unwrap(), 0x0102);
+
+        // Ответ с другой подфункцией - это значение другого счетчика
+        let response = [0x08, 0x00, 0x0C, 0x01, 0x02];
+        let result = with_response(5, &response, async |client| {
+            read_counter(client, 1, SlaveCounter::SlaveMessages).await
+        })
+        .await;
+        assert!(matches!(result, Err(ReadError::Malformed(ref pdu)) if *pdu == response), "{:?}", result);
+    }
+
+    #[test]
+    fn link_stats_record() {
+        let mut stats = LinkStats::default();
+        let ms = Duration::from_millis;
+        stats.record(&Ok(()), ms(10));
+        stats.record(&Ok(()), ms(30));
+        stats.record::<()>(&Err(ReadError::Exception(0x02)), ms(20));
+        stats.record::<()>(&Err(ReadError::Timeout), ms(1000));
+        stats.record::<()>(&Err(ReadError::Crc(vec![0x01])), ms(5));
+        stats.record::<()>(&Err(ReadError::Malformed(vec![0x03])), ms(5));
+        stats.record::<()>(&Err(ReadError::Io("port closed".to_string())), ms(5));
+
+        assert_eq!(stats.requests, 7);
+        // Исключение - тоже ответ устройства, его время учитывается
+        assert_eq!(stats.responses, 3);
+        assert_eq!(stats.exceptions, 1);
+        assert_eq!(stats.timeouts, 1);
+        assert_eq!(stats.frame_errors, 3);
+        assert_eq!(stats.min_latency, Some(ms(10)));
+        assert_eq!(stats.max_latency, Some(ms(30)));
+        assert_eq!(stats.average_latency(), Some(ms(20)));
+
+        stats.echo_mismatches = 1;
+        // Таймаут, три ошибки кадра и искаженное эхо из 7 запросов
+        assert!((stats.error_rate() - 500.0 / 7.0).abs() < 1e-9);
+        assert_eq!(LinkStats::default().error_rate(), 0.0);
+        assert_eq!(LinkStats::default().average_latency(), None);
+    }
+
+    #[test]
+    fn comm_event_from_byte() {
+        assert_eq!(CommEvent::from_byte(0x00), CommEvent::CommRestart);
+        assert_eq!(CommEvent::from_byte(0x04), CommEvent::EnteredListenOnly);
+        assert_eq!(CommEvent::from_byte(0x01), CommEvent::Unknown(0x01));
+        assert_eq!(
+            CommEvent::from_byte(0xC2),
+            CommEvent::Receive {
+                comm_error: true,
+                character_overrun: false,
+                listen_only: false,
+                broadcast: true,
+            }
+        );
+        assert_eq!(
+            CommEvent::from_byte(0x51),
+            CommEvent::Send {
+                read_exception: true,
+                abort_exception: false,
+                busy_exception: false,
+                nak_exception: false,
+                write_timeout: true,
+                listen_only: false,
+            }
+        );
+    }
+}
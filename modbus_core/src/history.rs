@@ -0,0 +1,260 @@
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use crate::poller::TagReading;
+
+/// Точка истории: время (мс от эпохи Unix) и числовое значение
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct HistorySample {
+    pub timestamp_ms: i64,
+    pub value: f64,
+}
+
+/// Значения тега за интервал времени после прореживания
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct TrendBucket {
+    /// Начало интервала, мс от эпохи Unix
+    pub start_ms: i64,
+    pub min: f64,
+    pub max: f64,
+    pub avg: f64,
+    /// Число точек в интервале
+    pub count: usize,
+}
+
+/// История значений тегов за последние `retention`.
+/// Для каждого тега хранится не больше `max_samples` точек, старые точки вытесняются.
+#[derive(Debug)]
+pub struct TagHistory {
+    retention_ms: i64,
+    max_samples: usize,
+    series: HashMap<String, VecDeque<HistorySample>>,
+}
+
+impl TagHistory {
+    pub fn new(retention: Duration, max_samples: usize) -> Self {
+        TagHistory {
+            retention_ms: i64::try_from(retention.as_millis()).unwrap_or(i64::MAX),
+            max_samples: max_samples.max(1),
+            series: HashMap::new(),
+        }
+    }
+
+    /// Добавление прочитанного значения; ошибки чтения и нечисловые значения пропускаются
+    pub fn record(&mut self, reading: &TagReading) {
+        let Some(value) = reading.value.as_ref().ok().and_then(|value| value.number) else {
+            return;
+        };
+        self.push(&reading.name, reading.timestamp.timestamp_millis(), value);
+    }
+
+    /// Добавление точки тега `name`; NaN и бесконечности не сохраняются,
+    /// иначе они испортили бы min/max/avg тренда
+    pub fn push(&mut self, name: &str, timestamp_ms: i64, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        let series = match self.series.get_mut(name) {
+            Some(series) => series,
+            None => self.series.entry(name.to_string()).or_default(),
+        };
+        if series.len() == self.max_samples {
+            series.pop_front();
+        }
+        series.push_back(HistorySample { timestamp_ms, value });
+
+        let oldest_allowed = timestamp_ms.saturating_sub(self.retention_ms);
+        while series.front().is_some_and(|sample| sample.timestamp_ms < oldest_allowed) {
+            series.pop_front();
+        }
+    }
+
+    /// Удаление точек старше `retention` на момент `now_ms` у всех тегов.
+    /// При добавлении точки устаревают только точки того же тега, поэтому история тега,
+    /// который перестал читаться, чистится этим обходом (например, после каждого цикла опроса).
+    pub fn prune(&mut self, now_ms: i64) {
+        let oldest_allowed = now_ms.saturating_sub(self.retention_ms);
+        self.series.retain(|_, series| {
+            while series.front().is_some_and(|sample| sample.timestamp_ms < oldest_allowed) {
+                series.pop_front();
+            }
+            !series.is_empty()
+        });
+    }
+
+    /// Имена тегов, для которых есть история
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.series.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Точки тега в интервале [from_ms, to_ms] без прореживания
+    pub fn samples(&self, name: &str, from_ms: i64, to_ms: i64) -> Vec<HistorySample> {
+        let Some(series) = self.series.get(name) else {
+            return Vec::new();
+        };
+        // Точки добавляются по возрастанию времени - ищем границы двоичным поиском
+        let start = series.partition_point(|sample| sample.timestamp_ms < from_ms);
+        let end = series.partition_point(|sample| sample.timestamp_ms <= to_ms);
+        series.range(start..end.max(start)).copied().collect()
+    }
+
+    /// Последние `count` точек тега (не больше), от старых к новым
+    pub fn last(&self, name: &str, count: usize) -> Vec<HistorySample> {
+        let Some(series) = self.series.get(name) else {
+            return Vec::new();
+        };
+        series.range(series.len().saturating_sub(count)..).copied().collect()
+    }
+
+    /// Прореживание истории тега: интервал [from_ms, to_ms] делится на `buckets` равных частей,
+    /// для каждой возвращаются min/max/avg. Части без точек пропускаются.
+    pub fn trend(&self, name: &str, from_ms: i64, to_ms: i64, buckets: usize) -> Vec<TrendBucket> {
+        if buckets == 0 || to_ms < from_ms {
+            return Vec::new();
+        }
+        let span = (to_ms - from_ms + 1) as f64;
+        let width = span / buckets as f64;
+
+        let mut result: Vec<TrendBucket> = Vec::new();
+        let mut current: Option<(usize, TrendBucket, f64)> = None;
+        for sample in self.samples(name, from_ms, to_ms) {
+            let bucket = (((sample.timestamp_ms - from_ms) as f64 / width) as usize).min(buckets - 1);
+            match &mut current {
+                Some((index, acc, sum)) if *index == bucket => {
+                    acc.min = acc.min.min(sample.value);
+                    acc.max = acc.max.max(sample.value);
+                    acc.count += 1;
+                    *sum += sample.value;
+                }
+                _ => {
+                    if let Some((_, acc, sum)) = current.take() {
+                        result.push(TrendBucket { avg: sum / acc.count as f64, ..acc });
+                    }
+                    let start_ms = from_ms + (bucket as f64 * width) as i64;
+                    let acc = TrendBucket {
+                        start_ms,
+                        min: sample.value,
+                        max: sample.value,
+                        avg: sample.value,
+                        count: 1,
+                    };
+                    current = Some((bucket, acc, sample.value));
+                }
+            }
+        }
+        if let Some((_, acc, sum)) = current {
+            result.push(TrendBucket { avg: sum / acc.count as f64, ..acc });
+        }
+        result
+    }
+
+    /// Удаление всей истории
+    pub fn clear(&mut self) {
+        self.series.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(retention_ms: u64, max_samples: usize) -> TagHistory {
+        TagHistory::new(Duration::from_millis(retention_ms), max_samples)
+    }
+
+    fn values(samples: &[HistorySample]) -> Vec<f64> {
+        samples.iter().map(|sample| sample.value).collect()
+    }
+
+    #[test]
+    fn max_samples_evicts_oldest() {
+        let mut history = history(60_000, 3);
+        for (index, value) in [1.0, 2.0, 3.0, 4.0].into_iter().enumerate() {
+            history.push("t", index as i64 * 10, value);
+        }
+        assert_eq!(values(&history.last("t", 10)), [2.0, 3.0, 4.0]);
+        assert_eq!(values(&history.last("t", 2)), [3.0, 4.0]);
+    }
+
+    #[test]
+    fn push_drops_samples_older_than_retention() {
+        let mut history = history(100, 10);
+        history.push("t", 0, 1.0);
+        history.push("t", 100, 2.0);
+        history.push("t", 101, 3.0);
+        // Точка ровно на границе хранения остается
+        assert_eq!(values(&history.last("t", 10)), [2.0, 3.0]);
+    }
+
+    #[test]
+    fn push_ignores_non_finite_values() {
+        let mut history = history(1000, 10);
+        history.push("t", 0, f64::NAN);
+        history.push("t", 1, f64::INFINITY);
+        history.push("t", 2, f64::NEG_INFINITY);
+        assert!(history.names().is_empty());
+        history.push("t", 3, 5.0);
+        assert_eq!(values(&history.last("t", 10)), [5.0]);
+    }
+
+    #[test]
+    fn prune_removes_stale_series() {
+        let mut history = history(100, 10);
+        history.push("stale", 0, 1.0);
+        history.push("fresh", 0, 1.0);
+        history.push("fresh", 150, 2.0);
+        history.prune(200);
+        assert_eq!(history.names(), ["fresh"]);
+        assert_eq!(values(&history.last("fresh", 10)), [2.0]);
+        history.prune(251);
+        assert!(history.names().is_empty());
+    }
+
+    #[test]
+    fn samples_include_window_edges() {
+        let mut history = history(60_000, 100);
+        for timestamp in [0, 10, 20, 30] {
+            history.push("t", timestamp, timestamp as f64);
+        }
+        assert_eq!(values(&history.samples("t", 10, 20)), [10.0, 20.0]);
+        assert_eq!(values(&history.samples("t", 11, 19)), Vec::<f64>::new());
+        assert!(history.samples("t", 20, 10).is_empty());
+        assert!(history.samples("other", 0, 30).is_empty());
+    }
+
+    #[test]
+    fn trend_aggregates_buckets() {
+        let mut history = history(60_000, 100);
+        for (timestamp, value) in [(0, 1.0), (10, 3.0), (50, 10.0), (99, 20.0)] {
+            history.push("t", timestamp, value);
+        }
+        // [0, 99] делится на 4 части по 25 мс; пустые части пропускаются
+        let trend = history.trend("t", 0, 99, 4);
+        assert_eq!(trend.len(), 3);
+        assert_eq!((trend[0].start_ms, trend[0].min, trend[0].max, trend[0].avg, trend[0].count), (0, 1.0, 3.0, 2.0, 2));
+        assert_eq!((trend[1].start_ms, trend[1].avg, trend[1].count), (50, 10.0, 1));
+        assert_eq!((trend[2].start_ms, trend[2].avg, trend[2].count), (75, 20.0, 1));
+        // Точка на правой границе окна попадает в последнюю часть
+        let trend = history.trend("t", 0, 99, 1);
+        assert_eq!(trend.len(), 1);
+        assert_eq!((trend[0].min, trend[0].max, trend[0].count), (1.0, 20.0, 4));
+        assert_eq!(trend[0].avg, 8.5);
+    }
+
+    #[test]
+    fn trend_respects_window_edges() {
+        let mut history = history(60_000, 100);
+        for timestamp in [0, 10, 20, 30] {
+            history.push("t", timestamp, timestamp as f64);
+        }
+        let trend = history.trend("t", 10, 20, 2);
+        assert_eq!(trend.iter().map(|bucket| bucket.count).sum::<usize>(), 2);
+        assert_eq!(trend[0].min, 10.0);
+        assert_eq!(trend[trend.len() - 1].max, 20.0);
+        assert!(history.trend("t", 10, 20, 0).is_empty());
+        assert!(history.trend("t", 20, 10, 4).is_empty());
+    }
+}
@@ -0,0 +1,32 @@
+//! Общее ядро Modbus RTU: настройки подключения, описание тегов (tags.csv),
+//! декодирование значений, поиск портов и асинхронный опрос.
+//! Используется консольным клиентом test_modbus_v1r1 и приложением tauri-app.
+
+pub mod address;
+pub mod connection;
+pub mod device_id;
+pub mod diagnostics;
+pub mod history;
+pub mod modbus_exception;
+pub mod paths;
+pub mod poller;
+pub mod port_binding;
+pub mod ports;
+pub mod raw_request;
+pub mod register_types;
+pub mod rs485;
+pub mod rtu_timing;
+pub mod settings;
+pub mod tags;
+pub mod tags_exchange;
+pub mod tags_validation;
+
+pub use connection::connect_rtu;
+pub use history::{HistorySample, TagHistory, TrendBucket};
+pub use poller::{spawn_poller, PollEvent, PollOptions, Poller, PollerHandle, Quality, ReadError, TagReading, TagValue, WriteError};
+pub use ports::{list_ports, PortDescriptor};
+pub use raw_request::RawClient;
+pub use register_types::{ModbusType, VarType};
+pub use rtu_timing::RtuTiming;
+pub use settings::{Config, ConnectionSettings, ControlLines, DeviceInfo, FlowControl, FrameTiming, Language, Metadata, PinState, RtsMode, UiSettings};
+pub use tags::{RegisterConfig, RegistersConfig};
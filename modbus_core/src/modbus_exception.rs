@@ -0,0 +1,92 @@
+use std::io;
+
+/// Коды исключений Modbus и их описания в формате tokio-modbus
+const EXCEPTIONS: [(u8, &str, &str); 9] = [
+	(0x01, "Illegal function", "Недопустимая функция"),
+	(0x02, "Illegal data address", "Недопустимый адрес данных"),
+	(0x03, "Illegal data value", "Недопустимое значение данных"),
+	(0x04, "Server device failure", "Отказ устройства"),
+	(0x05, "Acknowledge", "Запрос принят, выполняется"),
+	(0x06, "Server device busy", "Устройство занято"),
+	(0x08, "Memory parity error", "Ошибка четности памяти"),
+	(0x0A, "Gateway path unavailable", "Путь шлюза недоступен"),
+	(0x0B, "Gateway target device failed to respond", "Устройство за шлюзом не отвечает"),
+];
+
+/// Код исключения 0x01 - функция не поддерживается устройством
+pub const ILLEGAL_FUNCTION: u8 = 0x01;
+
+/// Код исключения 0x02 - недопустимый адрес данных
+pub const ILLEGAL_DATA_ADDRESS: u8 = 0x02;
+
+/// Извлечение кода исключения Modbus из ошибки tokio-modbus.
+/// tokio-modbus не экспортирует тип исключения, поэтому код определяется по тексту ошибки
+/// ("Modbus function N: <описание>").
+pub fn exception_code(error: &io::Error) -> Option<u8> {
+	if error.kind() != io::ErrorKind::Other {
+		return None;
+	}
+	let text = error.get_ref()?.to_string();
+	let description = text.strip_prefix("Modbus function ")?.split_once(": ")?.1;
+	EXCEPTIONS
+		.iter()
+		.find(|(_, name, _)| *name == description)
+		.map(|(code, _, _)| *code)
+}
+
+/// Описание кода исключения Modbus на русском
+pub fn exception_description(code: u8) -> &'static str {
+	EXCEPTIONS
+		.iter()
+		.find(|(c, _, _)| *c == code)
+		.map(|(_, _, description)| *description)
+		.unwrap_or("Неизвестное исключение")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tokio::io::{AsyncReadExt, AsyncWriteExt};
+	use tokio_modbus::prelude::*;
+
+	use crate::raw_request::rtu_adu;
+
+	/// Ошибка, которую tokio-modbus возвращает на ответ-исключение устройства
+	async fn exception_error(code: u8) -> io::Error {
+		let (client_side, mut slave_side) = tokio::io::duplex(64);
+		let mut ctx = rtu::connect_slave(client_side, Slave(1)).await.unwrap();
+		let slave = async move {
+			let mut request = [0u8; 8];
+			slave_side.read_exact(&mut request).await.unwrap();
+			slave_side.write_all(&rtu_adu(1, &[0x83, code])).await.unwrap();
+			slave_side
+		};
+		let (result, _slave_side) = tokio::join!(ctx.read_holding_registers(0, 1), slave);
+		result.expect_err("ожидался ответ-исключение")
+	}
+
+	#[tokio::test]
+	async fn exception_code_from_tokio_modbus_error() {
+		for (code, _, _) in EXCEPTIONS {
+			let error = exception_error(code).await;
+			assert_eq!(exception_code(&error), Some(code), "{}", error);
+		}
+	}
+
+	#[test]
+	fn exception_code_ignores_other_errors() {
+		let timeout = io::Error::new(io::ErrorKind::TimedOut, "Modbus function 3: Illegal function");
+		assert_eq!(exception_code(&timeout), None);
+		let other = io::Error::other("Modbus function 3: Something else");
+		assert_eq!(exception_code(&other), None);
+		let plain = io::Error::other("broken pipe");
+		assert_eq!(exception_code(&plain), None);
+		assert_eq!(exception_code(&io::Error::from(io::ErrorKind::Other)), None);
+	}
+
+	#[test]
+	fn exception_description_falls_back_for_unknown_code() {
+		assert_eq!(exception_description(ILLEGAL_DATA_ADDRESS), "Недопустимый адрес данных");
+		assert_eq!(exception_description(0x07), "Неизвестное исключение");
+	}
+}
@@ -0,0 +1,27 @@
+/// Файл настроек подключения
+pub const SETTINGS_FILE: &str = "connect_settings.json";
+/// Файл описания тегов
+pub const TAGS_FILE: &str = "tags.csv";
+
+/// Путь к файлу программы.
+/// В режиме разработки (cargo run) - в текущем каталоге, в release - рядом с exe файлом.
+pub fn app_file_path(file_name: &str) -> String {
+    if cfg!(debug_assertions) {
+        return file_name.to_string();
+    }
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe_path| exe_path.parent().map(|exe_dir| exe_dir.join(file_name)))
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_name.to_string())
+}
+
+/// Путь к connect_settings.json
+pub fn settings_path() -> String {
+    app_file_path(SETTINGS_FILE)
+}
+
+/// Путь к tags.csv
+pub fn registers_path() -> String {
+    app_file_path(TAGS_FILE)
+}
@@ -0,0 +1,578 @@
+use serde::Serialize;
+use std::fmt;
+use std::io;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
+use tokio::time::error::Elapsed;
+use tokio_modbus::prelude::*;
+
+use crate::connection::connect_rtu;
+use crate::modbus_exception::{exception_code, exception_description};
+use crate::raw_request::hex_bytes;
+use crate::register_types::{ModbusType, VarType};
+use crate::settings::ConnectionSettings;
+use crate::tags::RegisterConfig;
+
+/// Ошибка чтения тега
+#[derive(Debug, Clone, Serialize)]
+pub enum ReadError {
+    /// Устройство не ответило за отведенное время
+    Timeout,
+    /// Устройство ответило исключением Modbus
+    Exception(u8),
+    /// Ответ с неверной CRC (кадр целиком)
+    Crc(Vec<u8>),
+    /// Ответ не соответствует запросу (PDU ответа)
+    Malformed(Vec<u8>),
+    /// Ошибка порта или кодека tokio-modbus (текст системной ошибки)
+    Io(String),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Timeout => write!(f, "Таймаут"),
+            ReadError::Exception(code) => {
+                write!(f, "исключение 0x{:02X} ({})", code, exception_description(*code))
+            }
+            ReadError::Crc(frame) => write!(f, "ошибка CRC в ответе: {}", hex_bytes(frame)),
+            ReadError::Malformed(pdu) => write!(f, "неверный ответ: {}", hex_bytes(pdu)),
+            ReadError::Io(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+/// Ошибка записи тега
+#[derive(Debug, Clone, Serialize)]
+pub enum WriteError {
+    /// Область только для чтения (input_register, discrete_input)
+    ReadOnly,
+    /// Значение не подходит для типа тега
+    InvalidValue { value: String, var_type: VarType },
+    /// Запись бита не в виде .N=1 или .N=0 (введенный текст)
+    InvalidBitWrite(String),
+    /// Запись бита возможна только в holding_register
+    BitWriteArea(ModbusType),
+    /// Запись бита невозможна для bool и float
+    BitWriteType(VarType),
+    /// Номер бита больше разрядности типа
+    BitOutOfRange { bit: u8, var_type: VarType },
+    /// Нет тега с таким номером в списке опроса
+    UnknownTag,
+    /// Устройство не выполнило запрос
+    Request(ReadError),
+    /// Опрос уже остановлен
+    Stopped,
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::ReadOnly => write!(f, "область только для чтения"),
+            WriteError::InvalidValue { value, var_type } => {
+                write!(f, "значение '{}' не подходит для типа {}", value, var_type)
+            }
+            WriteError::InvalidBitWrite(text) => write!(f, "ожидается .N=1 или .N=0, получено '{}'", text),
+            WriteError::BitWriteArea(_) => write!(f, "запись бита возможна только в holding_register"),
+            WriteError::BitWriteType(var_type) => write!(f, "запись бита невозможна для типа {}", var_type),
+            WriteError::BitOutOfRange { bit, var_type } => write!(
+                f,
+                "бит {} вне диапазона 0..{} для типа {}",
+                bit,
+                var_type.bit_width().unwrap_or(1) - 1,
+                var_type
+            ),
+            WriteError::UnknownTag => write!(f, "тег не найден"),
+            WriteError::Request(e) => write!(f, "{}", e),
+            WriteError::Stopped => write!(f, "опрос остановлен"),
+        }
+    }
+}
+
+/// Качество значения тега по результату последнего чтения
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Quality {
+    Good,
+    Timeout,
+    Exception,
+    Error,
+}
+
+impl ReadError {
+    pub fn quality(&self) -> Quality {
+        match self {
+            ReadError::Timeout => Quality::Timeout,
+            ReadError::Exception(_) => Quality::Exception,
+            ReadError::Crc(_) | ReadError::Malformed(_) | ReadError::Io(_) => Quality::Error,
+        }
+    }
+}
+
+/// Прочитанное значение тега
+#[derive(Debug, Clone, Serialize)]
+pub struct TagValue {
+    /// Регистры в порядке чтения (для битовых областей - 0 или 1)
+    pub raw: Vec<u16>,
+    /// Значение в текстовом виде, как в выводе опроса
+    pub text: String,
+    /// Числовое значение для графиков и статистики
+    pub number: Option<f64>,
+}
+
+/// Результат чтения одного тега
+#[derive(Debug, Clone, Serialize)]
+pub struct TagReading {
+    /// Номер тега в списке опрашиваемых
+    pub index: usize,
+    pub name: String,
+    pub value: Result<TagValue, ReadError>,
+    pub timestamp: chrono::DateTime<chrono::Local>,
+}
+
+impl TagReading {
+    pub fn quality(&self) -> Quality {
+        match &self.value {
+            Ok(_) => Quality::Good,
+            Err(e) => e.quality(),
+        }
+    }
+}
+
+/// Чтение значения тега из устройства
+pub async fn read_tag(
+    ctx: &mut client::Context,
+    register: &RegisterConfig,
+    timeout: Duration,
+) -> Result<Vec<u16>, ReadError> {
+    let bits = |data: Vec<bool>| data.into_iter().map(u16::from).collect::<Vec<u16>>();
+    let quantity = register.var_type.quantity();
+    let result = match register.modbus_type {
+        ModbusType::InputRegister => {
+            tokio::time::timeout(timeout, ctx.read_input_registers(register.address, quantity)).await
+        }
+        ModbusType::HoldingRegister => {
+            tokio::time::timeout(timeout, ctx.read_holding_registers(register.address, quantity)).await
+        }
+        ModbusType::Coil => tokio::time::timeout(timeout, ctx.read_coils(register.address, 1))
+            .await
+            .map(|result| result.map(bits)),
+        ModbusType::DiscreteInput => tokio::time::timeout(timeout, ctx.read_discrete_inputs(register.address, 1))
+            .await
+            .map(|result| result.map(bits)),
+    };
+    request_result(result)
+}
+
+/// Разбор результата запроса с таймаутом
+fn request_result<T>(result: Result<io::Result<T>, Elapsed>) -> Result<T, ReadError> {
+    match result {
+        Ok(Ok(data)) => Ok(data),
+        Ok(Err(e)) => Err(match exception_code(&e) {
+            Some(code) => ReadError::Exception(code),
+            None => ReadError::Io(format!("{:?}", e)),
+        }),
+        Err(_) => Err(ReadError::Timeout),
+    }
+}
+
+/// Разбор записи одного бита: `.N=1` или `.N=0` (N - номер бита, 0 - младший).
+/// None - текст задает значение тега целиком.
+pub fn parse_bit_write(text: &str) -> Option<Result<(u8, bool), WriteError>> {
+    let rest = text.trim().strip_prefix('.')?;
+    let invalid = || WriteError::InvalidBitWrite(text.trim().to_string());
+    let Some((bit, value)) = rest.split_once('=') else {
+        return Some(Err(invalid()));
+    };
+    let value = match value.trim().to_lowercase().as_str() {
+        "1" | "true" | "on" => true,
+        "0" | "false" | "off" => false,
+        _ => return Some(Err(invalid())),
+    };
+    Some(bit.trim().parse::<u8>().map(|bit| (bit, value)).map_err(|_| invalid()))
+}
+
+/// Маски функции 0x16 (Mask Write Register) для записи бита целого тега:
+/// адрес регистра с этим битом, маска AND и маска OR
+pub fn bit_write_mask(register: &RegisterConfig, bit: u8, value: bool) -> Result<(u16, u16, u16), WriteError> {
+    if register.modbus_type != ModbusType::HoldingRegister {
+        return Err(WriteError::BitWriteArea(register.modbus_type));
+    }
+    let Some(width) = register.var_type.bit_width() else {
+        return Err(WriteError::BitWriteType(register.var_type));
+    };
+    if bit >= width {
+        return Err(WriteError::BitOutOfRange {
+            bit,
+            var_type: register.var_type,
+        });
+    }
+    // Для 32-битных значений младшее слово идет первым
+    let address = register.address + u16::from(bit / 16);
+    let mask = 1u16 << (bit % 16);
+    Ok((address, !mask, if value { mask } else { 0 }))
+}
+
+/// Запись значения тега в устройство; значение задается так же, как выводится при опросе.
+/// Текст `.N=1` / `.N=0` меняет один бит целого тега функцией 0x16, не затрагивая остальные.
+pub async fn write_tag(
+    ctx: &mut client::Context,
+    register: &RegisterConfig,
+    text: &str,
+    timeout: Duration,
+) -> Result<(), WriteError> {
+    if !register.modbus_type.is_writable() {
+        return Err(WriteError::ReadOnly);
+    }
+    if let Some(bit_write) = parse_bit_write(text) {
+        let (bit, value) = bit_write?;
+        let (address, and_mask, or_mask) = bit_write_mask(register, bit, value)?;
+        let result = tokio::time::timeout(timeout, ctx.masked_write_register(address, and_mask, or_mask)).await;
+        return request_result(result).map_err(WriteError::Request);
+    }
+    let words = register.var_type.encode(text).map_err(|_| WriteError::InvalidValue {
+        value: text.trim().to_string(),
+        var_type: register.var_type,
+    })?;
+    let result = match register.modbus_type {
+        ModbusType::InputRegister | ModbusType::DiscreteInput => return Err(WriteError::ReadOnly),
+        ModbusType::Coil => {
+            tokio::time::timeout(timeout, ctx.write_single_coil(register.address, words[0] != 0)).await
+        }
+        ModbusType::HoldingRegister if words.len() == 1 => {
+            tokio::time::timeout(timeout, ctx.write_single_register(register.address, words[0])).await
+        }
+        ModbusType::HoldingRegister => {
+            tokio::time::timeout(timeout, ctx.write_multiple_registers(register.address, &words)).await
+        }
+    };
+    request_result(result).map_err(WriteError::Request)
+}
+
+/// Опрос списка тегов через открытое подключение Modbus RTU
+pub struct Poller {
+    ctx: client::Context,
+    registers: Vec<RegisterConfig>,
+    timeout: Duration,
+}
+
+impl Poller {
+    /// Открытие порта и установка адреса устройства
+    pub async fn connect(conn: &ConnectionSettings, registers: Vec<RegisterConfig>, timeout: Duration) -> io::Result<Self> {
+        let mut ctx = connect_rtu(conn).await?;
+        ctx.set_slave(Slave(conn.device_address));
+        Ok(Poller { ctx, registers, timeout })
+    }
+
+    /// Опрашиваемые теги
+    pub fn registers(&self) -> &[RegisterConfig] {
+        &self.registers
+    }
+
+    /// Чтение одного тега по номеру в списке
+    pub async fn read(&mut self, index: usize) -> TagReading {
+        let register = &self.registers[index];
+        let value = read_tag(&mut self.ctx, register, self.timeout)
+            .await
+            .and_then(|raw| match register.var_type.decode(&raw) {
+                Some(text) => Ok(TagValue {
+                    text,
+                    number: register.var_type.to_f64(&raw),
+                    raw,
+                }),
+                None => Err(ReadError::Malformed(raw.iter().flat_map(|word| word.to_be_bytes()).collect())),
+            });
+        TagReading {
+            index,
+            name: register.name.clone(),
+            value,
+            timestamp: chrono::Local::now(),
+        }
+    }
+
+    /// Запись значения тега по номеру в списке
+    pub async fn write(&mut self, index: usize, text: &str) -> Result<(), WriteError> {
+        let register = self.registers.get(index).ok_or(WriteError::UnknownTag)?;
+        write_tag(&mut self.ctx, register, text, self.timeout).await
+    }
+
+    /// Один цикл опроса всех тегов
+    pub async fn poll_cycle(&mut self) -> Vec<TagReading> {
+        let mut readings = Vec::with_capacity(self.registers.len());
+        for index in 0..self.registers.len() {
+            readings.push(self.read(index).await);
+        }
+        readings
+    }
+
+    /// Закрытие подключения
+    pub async fn disconnect(mut self) -> io::Result<()> {
+        self.ctx.disconnect().await
+    }
+}
+
+/// Параметры фонового опроса
+#[derive(Debug, Clone, Copy)]
+pub struct PollOptions {
+    /// Пауза между циклами
+    pub interval: Duration,
+    /// Таймаут ответа на каждый запрос
+    pub timeout: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        PollOptions {
+            interval: Duration::from_secs(1),
+            timeout: Duration::from_millis(1000),
+        }
+    }
+}
+
+/// Событие фонового опроса
+#[derive(Debug, Clone, Serialize)]
+pub enum PollEvent {
+    /// Порт открыт, опрос начат
+    Connected { port: String },
+    Reading(TagReading),
+    /// Цикл завершен; `failed` - число тегов с ошибкой в цикле
+    CycleComplete { cycle: u64, failed: usize },
+    /// Порт не открыт, опрос не запущен; `error` - текст системной ошибки
+    ConnectFailed { port: String, error: String },
+    /// Опрос остановлен, порт закрыт
+    Stopped,
+}
+
+/// Запрос записи в фоновый опрос
+struct WriteRequest {
+    index: usize,
+    value: String,
+    reply: oneshot::Sender<Result<(), WriteError>>,
+}
+
+impl WriteRequest {
+    async fn execute(self, poller: &mut Poller) {
+        let result = poller.write(self.index, &self.value).await;
+        let _ = self.reply.send(result);
+    }
+}
+
+/// Управление фоновым опросом. Удаление handle тоже останавливает опрос.
+pub struct PollerHandle {
+    stop: watch::Sender<bool>,
+    paused: watch::Sender<bool>,
+    writes: mpsc::UnboundedSender<WriteRequest>,
+    task: JoinHandle<()>,
+}
+
+impl PollerHandle {
+    /// Запрос остановки после текущего запроса
+    pub fn stop(&self) {
+        let _ = self.stop.send(true);
+    }
+
+    /// Приостановка опроса после текущего запроса; порт остается открытым, запись выполняется
+    pub fn pause(&self) {
+        let _ = self.paused.send(true);
+    }
+
+    /// Продолжение опроса с нового цикла
+    pub fn resume(&self) {
+        let _ = self.paused.send(false);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Запись значения тега между запросами опроса
+    pub async fn write(&self, index: usize, value: &str) -> Result<(), WriteError> {
+        let (reply, result) = oneshot::channel();
+        let request = WriteRequest {
+            index,
+            value: value.to_string(),
+            reply,
+        };
+        self.writes.send(request).map_err(|_| WriteError::Stopped)?;
+        result.await.unwrap_or(Err(WriteError::Stopped))
+    }
+
+    /// Завершился ли опрос
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Остановка и ожидание закрытия порта
+    pub async fn shutdown(self) {
+        self.stop();
+        let _ = self.task.await;
+    }
+}
+
+/// Запуск фонового опроса; результаты приходят в канал в порядке чтения
+pub fn spawn_poller(
+    conn: ConnectionSettings,
+    registers: Vec<RegisterConfig>,
+    options: PollOptions,
+) -> (PollerHandle, mpsc::UnboundedReceiver<PollEvent>) {
+    let (events, receiver) = mpsc::unbounded_channel();
+    let (stop, mut stop_requested) = watch::channel(false);
+    let (paused, mut pause_requested) = watch::channel(false);
+    let (writes, mut write_requests) = mpsc::unbounded_channel::<WriteRequest>();
+
+    let task = tokio::spawn(async move {
+        let mut poller = match Poller::connect(&conn, registers, options.timeout).await {
+            Ok(poller) => poller,
+            Err(e) => {
+                let _ = events.send(PollEvent::ConnectFailed {
+                    port: conn.port.clone(),
+                    error: e.to_string(),
+                });
+                let _ = events.send(PollEvent::Stopped);
+                return;
+            }
+        };
+        let _ = events.send(PollEvent::Connected { port: conn.port.clone() });
+
+        let mut cycle: u64 = 0;
+        'polling: loop {
+            // Пауза: порт открыт, выполняются только запросы записи
+            while *pause_requested.borrow_and_update() {
+                tokio::select! {
+                    changed = pause_requested.changed() => if changed.is_err() { break 'polling },
+                    _ = stop_requested.changed() => break 'polling,
+                    Some(request) = write_requests.recv() => request.execute(&mut poller).await,
+                }
+            }
+
+            let mut failed = 0;
+            for index in 0..poller.registers().len() {
+                if *stop_requested.borrow() {
+                    break 'polling;
+                }
+                if *pause_requested.borrow() {
+                    continue 'polling;
+                }
+                while let Ok(request) = write_requests.try_recv() {
+                    request.execute(&mut poller).await;
+                }
+                let reading = poller.read(index).await;
+                if reading.value.is_err() {
+                    failed += 1;
+                }
+                // Получатель закрыт - результаты больше никому не нужны
+                if events.send(PollEvent::Reading(reading)).is_err() {
+                    break 'polling;
+                }
+            }
+            cycle += 1;
+            let _ = events.send(PollEvent::CycleComplete { cycle, failed });
+
+            let next_cycle = tokio::time::Instant::now() + options.interval;
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep_until(next_cycle) => break,
+                    _ = stop_requested.changed() => break 'polling,
+                    changed = pause_requested.changed() => if changed.is_err() { break 'polling } else { break },
+                    Some(request) = write_requests.recv() => request.execute(&mut poller).await,
+                }
+            }
+        }
+
+        let _ = poller.disconnect().await;
+        let _ = events.send(PollEvent::Stopped);
+    });
+
+    let handle = PollerHandle {
+        stop,
+        paused,
+        writes,
+        task,
+    };
+    (handle, receiver)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register(var_type: VarType, modbus_type: ModbusType) -> RegisterConfig {
+        RegisterConfig {
+            name: "tag".to_string(),
+            description: String::new(),
+            address: 100,
+            var_type,
+            modbus_type,
+            enabled: true,
+            unit: String::new(),
+        }
+    }
+
+    #[test]
+    fn parse_bit_write_cases() {
+        assert!(parse_bit_write("42").is_none());
+        assert!(parse_bit_write("-1.5").is_none());
+        let valid = [
+            (".0=1", 0, true),
+            (".15=0", 15, false),
+            (" .3 = on ", 3, true),
+            (".31=false", 31, false),
+            (".7=TRUE", 7, true),
+        ];
+        for (text, bit, value) in valid {
+            assert!(
+                matches!(parse_bit_write(text), Some(Ok(parsed)) if parsed == (bit, value)),
+                "{}",
+                text
+            );
+        }
+        for text in [".", ".3", ".3=2", ".x=1", ".-1=1", ".256=1"] {
+            assert!(
+                matches!(parse_bit_write(text), Some(Err(WriteError::InvalidBitWrite(_)))),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn bit_write_mask_cases() {
+        let holding = |var_type| register(var_type, ModbusType::HoldingRegister);
+        let ok = [
+            (VarType::U16, 0, true, (100, 0xFFFE, 0x0001)),
+            (VarType::I16, 15, false, (100, 0x7FFF, 0x0000)),
+            // Старшее слово 32-битного значения - следующий регистр
+            (VarType::U32, 16, true, (101, 0xFFFE, 0x0001)),
+            (VarType::I32, 31, true, (101, 0x7FFF, 0x8000)),
+        ];
+        for (var_type, bit, value, expected) in ok {
+            assert_eq!(bit_write_mask(&holding(var_type), bit, value).ok(), Some(expected), "{} .{}", var_type, bit);
+        }
+
+        assert!(matches!(
+            bit_write_mask(&holding(VarType::U16), 16, true),
+            Err(WriteError::BitOutOfRange { bit: 16, var_type: VarType::U16 })
+        ));
+        assert!(matches!(
+            bit_write_mask(&holding(VarType::U32), 32, true),
+            Err(WriteError::BitOutOfRange { bit: 32, var_type: VarType::U32 })
+        ));
+        assert!(matches!(
+            bit_write_mask(&holding(VarType::Float), 0, true),
+            Err(WriteError::BitWriteType(VarType::Float))
+        ));
+        assert!(matches!(
+            bit_write_mask(&holding(VarType::Bool), 0, true),
+            Err(WriteError::BitWriteType(VarType::Bool))
+        ));
+        assert!(matches!(
+            bit_write_mask(&register(VarType::U16, ModbusType::InputRegister), 0, true),
+            Err(WriteError::BitWriteArea(ModbusType::InputRegister))
+        ));
+        assert!(matches!(
+            bit_write_mask(&register(VarType::Bool, ModbusType::Coil), 0, true),
+            Err(WriteError::BitWriteArea(ModbusType::Coil))
+        ));
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::ports::{list_ports, PortDescriptor};
+use crate::settings::ConnectionSettings;
+
+/// Привязка настроек к конкретному USB-адаптеру, а не к имени порта.
+/// Все заданные поля должны совпасть; пустые поля не проверяются.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PortBinding {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vid: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u16>,
+    /// Постоянный путь /dev/serial/by-id/... (Linux)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_id_path: Option<String>,
+}
+
+impl PortBinding {
+    /// Привязка к адаптеру, выбранному в списке портов; None - порт не USB
+    pub fn from_descriptor(port: &PortDescriptor) -> Option<Self> {
+        if port.vid.is_none() && port.serial_number.is_none() && port.by_id_path.is_none() {
+            return None;
+        }
+        Some(PortBinding {
+            serial_number: port.serial_number.clone(),
+            vid: port.vid,
+            pid: port.pid,
+            by_id_path: port.by_id_path.clone(),
+        })
+    }
+
+    /// Описание привязки для вывода пользователю
+    pub fn describe(&self) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        if let (Some(vid), Some(pid)) = (self.vid, self.pid) {
+            parts.push(format!("VID:PID {:04x}:{:04x}", vid, pid));
+        }
+        if let Some(serial_number) = &self.serial_number {
+            parts.push(format!("S/N {}", serial_number));
+        }
+        if let Some(by_id_path) = &self.by_id_path {
+            parts.push(by_id_path.clone());
+        }
+        parts.join(", ")
+    }
+
+    /// Подходит ли порт под привязку по USB-идентификаторам
+    fn matches(&self, port: &PortDescriptor) -> bool {
+        if self.serial_number.is_none() && self.vid.is_none() && self.pid.is_none() {
+            return false;
+        }
+        (self.serial_number.is_none() || self.serial_number == port.serial_number)
+            && (self.vid.is_none() || self.vid == port.vid)
+            && (self.pid.is_none() || self.pid == port.pid)
+    }
+}
+
+/// Результат поиска привязанного адаптера
+#[derive(Debug, Clone)]
+pub enum BindingResolution {
+    /// Привязки нет, используется сохраненное имя порта
+    NotBound,
+    /// Адаптер найден; `previous` - сохраненное имя, если порт изменился
+    Found { previous: Option<String> },
+    /// Адаптер не найден, остается сохраненное имя порта
+    NotFound,
+    /// Подходят несколько адаптеров; выбран сохраненный порт или первый из найденных
+    Ambiguous { candidates: Vec<PortDescriptor> },
+    /// Ошибка получения списка портов
+    ScanFailed(String),
+}
+
+/// Определение текущего имени порта по привязке к адаптеру.
+/// Если адаптер не найден, остается сохраненное имя порта.
+pub fn resolve_bound_port(conn: &mut ConnectionSettings) -> BindingResolution {
+    let Some(binding) = conn.port_binding.clone() else {
+        return BindingResolution::NotBound;
+    };
+
+    // Постоянный путь by-id однозначно указывает на адаптер
+    if let Some(by_id_path) = &binding.by_id_path
+        && let Ok(target) = Path::new(by_id_path).canonicalize()
+    {
+        let previous = set_resolved_port(conn, target.to_string_lossy().to_string());
+        return BindingResolution::Found { previous };
+    }
+
+    let ports = match list_ports() {
+        Ok(ports) => ports,
+        Err(e) => return BindingResolution::ScanFailed(format!("{:?}", e)),
+    };
+    let matching: Vec<PortDescriptor> = ports.into_iter().filter(|port| binding.matches(port)).collect();
+
+    match matching.as_slice() {
+        [] => BindingResolution::NotFound,
+        [port] => {
+            let previous = set_resolved_port(conn, port.path.clone());
+            BindingResolution::Found { previous }
+        }
+        _ => {
+            // Предпочитаем сохраненный порт, если он среди подходящих
+            let chosen = matching
+                .iter()
+                .find(|port| port.path == conn.port)
+                .unwrap_or(&matching[0])
+                .path
+                .clone();
+            set_resolved_port(conn, chosen);
+            BindingResolution::Ambiguous { candidates: matching }
+        }
+    }
+}
+
+/// Замена имени порта; возвращает прежнее имя, если адаптер переехал
+fn set_resolved_port(conn: &mut ConnectionSettings, port: String) -> Option<String> {
+    if port == conn.port {
+        return None;
+    }
+    Some(std::mem::replace(&mut conn.port, port))
+}
@@ -0,0 +1,89 @@
+use serde::Serialize;
+use serialport::SerialPortType;
+use std::path::Path;
+
+/// Каталог с постоянными именами USB-адаптеров в Linux
+const SERIAL_BY_ID_DIR: &str = "/dev/serial/by-id";
+
+/// Описание найденного последовательного порта
+#[derive(Serialize, Debug, Clone)]
+pub struct PortDescriptor {
+    /// Полный путь или имя порта (COM4, /dev/ttyUSB0)
+    pub path: String,
+    /// Тип подключения: usb, pci, bluetooth, unknown
+    pub port_type: String,
+    pub vid: Option<u16>,
+    pub pid: Option<u16>,
+    pub manufacturer: Option<String>,
+    pub serial_number: Option<String>,
+    pub product: Option<String>,
+    /// Постоянный путь /dev/serial/by-id/... (только Linux)
+    pub by_id_path: Option<String>,
+}
+
+impl PortDescriptor {
+    /// Краткое описание USB-адаптера для вывода в списке
+    pub fn details(&self) -> String {
+        let mut parts: Vec<String> = vec![self.port_type.clone()];
+        if let (Some(vid), Some(pid)) = (self.vid, self.pid) {
+            parts.push(format!("{:04x}:{:04x}", vid, pid));
+        }
+        if let Some(manufacturer) = &self.manufacturer {
+            parts.push(manufacturer.clone());
+        }
+        if let Some(product) = &self.product {
+            parts.push(product.clone());
+        }
+        if let Some(serial_number) = &self.serial_number {
+            parts.push(format!("S/N {}", serial_number));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Поиск постоянного пути /dev/serial/by-id/..., указывающего на данный порт
+fn find_by_id_path(port_path: &str) -> Option<String> {
+    let target = Path::new(port_path).canonicalize().ok()?;
+    std::fs::read_dir(SERIAL_BY_ID_DIR)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|link| link.canonicalize().ok().as_ref() == Some(&target))
+        .map(|link| link.to_string_lossy().to_string())
+}
+
+/// Описание порта по данным serialport
+fn describe_port(port: serialport::SerialPortInfo) -> PortDescriptor {
+    let mut descriptor = PortDescriptor {
+        by_id_path: find_by_id_path(&port.port_name),
+        path: port.port_name,
+        port_type: "unknown".to_string(),
+        vid: None,
+        pid: None,
+        manufacturer: None,
+        serial_number: None,
+        product: None,
+    };
+    match port.port_type {
+        SerialPortType::UsbPort(info) => {
+            descriptor.port_type = "usb".to_string();
+            descriptor.vid = Some(info.vid);
+            descriptor.pid = Some(info.pid);
+            descriptor.manufacturer = info.manufacturer;
+            descriptor.serial_number = info.serial_number;
+            descriptor.product = info.product;
+        }
+        SerialPortType::PciPort => descriptor.port_type = "pci".to_string(),
+        SerialPortType::BluetoothPort => descriptor.port_type = "bluetooth".to_string(),
+        SerialPortType::Unknown => {}
+    }
+    descriptor
+}
+
+/// Список доступных последовательных портов
+pub fn list_ports() -> serialport::Result<Vec<PortDescriptor>> {
+    Ok(serialport::available_ports()?
+        .into_iter()
+        .map(describe_port)
+        .collect())
+}
@@ -0,0 +1,260 @@
+use std::io;
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+use tokio::time::{timeout_at, Instant};
+
+use crate::connection::{open_serial_port, rtu_transport, SerialIo};
+use crate::poller::ReadError;
+use crate::rtu_timing::TimedTransport;
+use crate::settings::ConnectionSettings;
+
+/// Адрес широковещательного запроса: устройства не отвечают
+pub const BROADCAST_ADDRESS: u8 = 0;
+/// Сколько ждать продолжения кадра с неверной CRC: USB-адаптер отдает байты пачками
+/// по таймеру задержки (FTDI - 16 мс), и пауза внутри ответа может превысить t3.5
+const SPLIT_FRAME_WAIT: Duration = Duration::from_millis(20);
+
+/// CRC-16 Modbus (полином 0xA001, начальное значение 0xFFFF)
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for byte in data {
+        crc ^= u16::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xA001 } else { crc >> 1 };
+        }
+    }
+    crc
+}
+
+/// Кадр RTU: адрес устройства, PDU и CRC (младший байт первым)
+pub fn rtu_adu(slave: u8, pdu: &[u8]) -> Vec<u8> {
+    let mut adu = Vec::with_capacity(pdu.len() + 3);
+    adu.push(slave);
+    adu.extend_from_slice(pdu);
+    adu.extend_from_slice(&crc16(&adu).to_le_bytes());
+    adu
+}
+
+/// Достаточно ли байт в кадре, чтобы проверять CRC (адрес, функция, CRC)
+fn crc_checked(frame: &[u8]) -> bool {
+    frame.len() >= 4
+}
+
+/// Кадр RTU с верной CRC
+fn crc_valid(frame: &[u8]) -> bool {
+    crc_checked(frame) && crc16(&frame[..frame.len() - 2]).to_le_bytes() == frame[frame.len() - 2..]
+}
+
+/// Байты в шестнадцатеричном виде через пробел
+pub fn hex_bytes(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<_>>().join(" ")
+}
+
+/// Запросы Modbus RTU с произвольным PDU.
+///
+/// Кодек tokio-modbus определяет длину ответа по коду функции и не знает
+/// функций 0x08 и 0x2B; здесь конец ответа определяется паузой t3.5
+/// (`TimedTransport`), поэтому подходит любая функция.
+pub struct RawClient {
+    transport: TimedTransport<Box<dyn SerialIo>>,
+    timeout: Duration,
+}
+
+impl RawClient {
+    /// Открытие порта по настройкам подключения
+    pub fn connect(conn: &ConnectionSettings, timeout: Duration) -> io::Result<Self> {
+        let port = open_serial_port(conn)?;
+        Ok(RawClient {
+            transport: rtu_transport(port, conn)?,
+            timeout,
+        })
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Число принятых кадров с паузой между символами больше t1.5
+    pub fn broken_frames(&self) -> u64 {
+        self.transport.broken_frames()
+    }
+
+    /// Отправка PDU и ожидание ответа того же устройства на ту же функцию.
+    /// Возвращает PDU ответа (код функции и данные); для широковещательного
+    /// запроса - пустой PDU сразу после передачи.
+    pub async fn request(&mut self, slave: u8, pdu: &[u8]) -> Result<Vec<u8>, ReadError> {
+        let Some(&function) = pdu.first() else {
+            return Err(ReadError::Io(io::Error::from(io::ErrorKind::InvalidInput).to_string()));
+        };
+        let io_error = |e: io::Error| ReadError::Io(e.to_string());
+        self.transport.write_all(&rtu_adu(slave, pdu)).await.map_err(io_error)?;
+        self.transport.flush().await.map_err(io_error)?;
+        if slave == BROADCAST_ADDRESS {
+            return Ok(Vec::new());
+        }
+
+        let deadline = Instant::now() + self.timeout;
+        // Кадр с неверной CRC может оказаться началом ответа, разорванного адаптером:
+        // его продолжение ждем не дольше SPLIT_FRAME_WAIT
+        let mut pending: Vec<u8> = Vec::new();
+        loop {
+            let wait_until = if crc_checked(&pending) { deadline.min(Instant::now() + SPLIT_FRAME_WAIT) } else { deadline };
+            let next = match timeout_at(wait_until, self.transport.next_frame()).await {
+                Ok(Ok(next)) => next,
+                Ok(Err(e)) => return Err(io_error(e)),
+                Err(_) if crc_checked(&pending) => {
+                    return Err(ReadError::Crc(pending));
+                }
+                Err(_) => return Err(ReadError::Timeout),
+            };
+            pending.extend_from_slice(&next);
+            let frame = if crc_valid(&pending) {
+                std::mem::take(&mut pending)
+            } else if crc_valid(&next) {
+                // Перед ответом были помехи или чужой оборванный кадр
+                pending.clear();
+                next
+            } else {
+                continue;
+            };
+            let data = &frame[..frame.len() - 2];
+            // Кадры других устройств и ответы на другие функции пропускаются
+            if data[0] != slave || data[1] & 0x7F != function {
+                continue;
+            }
+            if data[1] & 0x80 != 0 {
+                // Ответ-исключение без кода исключения
+                if data.len() < 3 {
+                    return Err(ReadError::Malformed(frame));
+                }
+                return Err(ReadError::Exception(data[2]));
+            }
+            return Ok(data[1..].to_vec());
+        }
+    }
+
+    /// Закрытие порта
+    pub async fn disconnect(mut self) -> io::Result<()> {
+        self.transport.shutdown().await
+    }
+}
+
+#[cfg(test)]
+impl RawClient {
+    /// Клиент на линии в памяти (9600 8N1); второй конец линии - сторона устройства
+    pub(crate) fn in_memory(timeout: Duration) -> (Self, tokio::io::DuplexStream) {
+        use crate::rtu_timing::RtuTiming;
+
+        let (port, line) = tokio::io::duplex(1024);
+        let port: Box<dyn SerialIo> = Box::new(port);
+        let timing = RtuTiming::computed(&ConnectionSettings::for_tests(9600, "None"));
+        let client = RawClient {
+            transport: TimedTransport::new(port, timing),
+            timeout,
+        };
+        (client, line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, DuplexStream};
+    use tokio::time::sleep;
+
+    const READ_ONE_REGISTER: [u8; 5] = [0x03, 0x00, 0x00, 0x00, 0x01];
+
+    #[test]
+    fn crc16_known_vectors() {
+        let cases: [(&[u8], u16); 5] = [
+            (&[0x01, 0x03, 0x00, 0x00, 0x00, 0x01], 0x0A84),
+            (&[0x01, 0x06, 0x00, 0x01, 0x00, 0x03], 0x0B98),
+            (&[0x11, 0x03, 0x00, 0x6B, 0x00, 0x03], 0x8776),
+            (b"123456789", 0x4B37),
+            (&[], 0xFFFF),
+        ];
+        for (data, expected) in cases {
+            assert_eq!(crc16(data), expected, "{}", hex_bytes(data));
+        }
+    }
+
+    #[test]
+    fn rtu_adu_appends_crc_low_byte_first() {
+        let cases: [(u8, &[u8], &[u8]); 3] = [
+            (1, &READ_ONE_REGISTER, &[0x01, 0x03, 0x00, 0x00, 0x00, 0x01, 0x84, 0x0A]),
+            (1, &[0x06, 0x00, 0x01, 0x00, 0x03], &[0x01, 0x06, 0x00, 0x01, 0x00, 0x03, 0x98, 0x0B]),
+            (1, &[0x83, 0x02], &[0x01, 0x83, 0x02, 0xC0, 0xF1]),
+        ];
+        for (slave, pdu, expected) in cases {
+            let adu = rtu_adu(slave, pdu);
+            assert_eq!(adu, expected, "{}", hex_bytes(pdu));
+            // CRC кадра вместе с контрольной суммой равна нулю
+            assert_eq!(crc16(&adu), 0);
+        }
+    }
+
+    /// Прием запроса на стороне устройства и ответ кадром, переданным частями с паузами
+    async fn reply_in_parts(line: &mut DuplexStream, parts: &[(Duration, &[u8])]) {
+        let mut request = [0u8; 8];
+        line.read_exact(&mut request).await.unwrap();
+        for (pause, part) in parts {
+            sleep(*pause).await;
+            line.write_all(part).await.unwrap();
+        }
+    }
+
+    async fn request_with_reply(parts: &[(Duration, &[u8])]) -> Result<Vec<u8>, ReadError> {
+        let (mut client, mut line) = RawClient::in_memory(Duration::from_millis(200));
+        let (result, _) = tokio::join!(client.request(1, &READ_ONE_REGISTER), reply_in_parts(&mut line, parts));
+        result
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn response_split_by_adapter_latency_is_joined() {
+        let response = rtu_adu(1, &[0x03, 0x02, 0x00, 0x2A]);
+        let (head, tail) = response.split_at(3);
+        // Пауза 16 мс (таймер задержки FTDI) больше t3.5 на 9600
+        let result = request_with_reply(&[(Duration::ZERO, head), (Duration::from_millis(16), tail)]).await;
+        assert_eq!(result.unwrap(), [0x03, 0x02, 0x00, 0x2A]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn noise_before_response_is_skipped() {
+        let response = rtu_adu(1, &[0x03, 0x02, 0x00, 0x2A]);
+        let result = request_with_reply(&[(Duration::ZERO, &[0x00, 0xFF, 0x13, 0x37, 0x00]), (Duration::from_millis(10), &response)]).await;
+        assert_eq!(result.unwrap(), [0x03, 0x02, 0x00, 0x2A]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn corrupted_response_is_crc_error() {
+        let mut response = rtu_adu(1, &[0x03, 0x02, 0x00, 0x2A]);
+        response[3] ^= 0x01;
+        let started = Instant::now();
+        let result = request_with_reply(&[(Duration::ZERO, &response)]).await;
+        assert!(matches!(result, Err(ReadError::Crc(ref frame)) if *frame == response), "{:?}", result);
+        // Ошибка - после ожидания продолжения кадра, а не по общему таймауту
+        assert!(started.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn other_slave_and_exception() {
+        let other = rtu_adu(2, &[0x03, 0x02, 0x00, 0x01]);
+        let exception = rtu_adu(1, &[0x83, 0x02]);
+        let result = request_with_reply(&[(Duration::ZERO, &other), (Duration::from_millis(10), &exception)]).await;
+        assert!(matches!(result, Err(ReadError::Exception(0x02))), "{:?}", result);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exception_without_code_is_malformed() {
+        let exception = rtu_adu(1, &[0x83]);
+        let result = request_with_reply(&[(Duration::ZERO, &exception)]).await;
+        assert!(matches!(&result, Err(ReadError::Malformed(frame)) if *frame == exception), "{:?}", result);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn silence_is_timeout() {
+        let result = request_with_reply(&[]).await;
+        assert!(matches!(result, Err(ReadError::Timeout)), "{:?}", result);
+    }
+}
@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Тип значения тега (столбец var_type в tags.csv).
+/// Новый тип добавляется только здесь: имя, количество регистров и декодирование.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VarType {
+    Bool,
+    U16,
+    I16,
+    U32,
+    I32,
+    Float,
+}
+
+impl VarType {
+    pub const ALL: [VarType; 6] = [
+        VarType::Bool,
+        VarType::U16,
+        VarType::I16,
+        VarType::U32,
+        VarType::I32,
+        VarType::Float,
+    ];
+
+    /// Написание в tags.csv
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VarType::Bool => "bool",
+            VarType::U16 => "u16",
+            VarType::I16 => "i16",
+            VarType::U32 => "u32",
+            VarType::I32 => "i32",
+            VarType::Float => "float",
+        }
+    }
+
+    /// Количество 16-битных регистров, занимаемых значением
+    pub fn quantity(self) -> u16 {
+        match self {
+            VarType::Bool | VarType::U16 | VarType::I16 => 1,
+            VarType::U32 | VarType::I32 | VarType::Float => 2,
+        }
+    }
+
+    /// Количество битов целого значения, доступных для побитовой записи; None - bool и float
+    pub fn bit_width(self) -> Option<u8> {
+        match self {
+            VarType::U16 | VarType::I16 => Some(16),
+            VarType::U32 | VarType::I32 => Some(32),
+            VarType::Bool | VarType::Float => None,
+        }
+    }
+
+    /// Преобразование прочитанных регистров в строковое значение; None - недостаточно данных
+    pub fn decode(self, data: &[u16]) -> Option<String> {
+        if data.len() < usize::from(self.quantity()) {
+            return None;
+        }
+        // Для 32-битных значений младшее слово идет первым
+        let combined = || (u32::from(data[1]) << 16) | u32::from(data[0]);
+        Some(match self {
+            VarType::Bool => {
+                if data[0] != 0 { "true".to_string() } else { "false".to_string() }
+            }
+            VarType::U16 => format!("{}", data[0]),
+            VarType::I16 => format!("{}", data[0] as i16),
+            VarType::U32 => format!("{}", combined()),
+            VarType::I32 => format!("{}", combined() as i32),
+            VarType::Float => format!("{:.3}", f32::from_bits(combined())),
+        })
+    }
+
+    /// Числовое значение прочитанных регистров (bool - 0 или 1); None - недостаточно данных
+    pub fn to_f64(self, data: &[u16]) -> Option<f64> {
+        if data.len() < usize::from(self.quantity()) {
+            return None;
+        }
+        let combined = || (u32::from(data[1]) << 16) | u32::from(data[0]);
+        Some(match self {
+            VarType::Bool => f64::from(u8::from(data[0] != 0)),
+            VarType::U16 => f64::from(data[0]),
+            VarType::I16 => f64::from(data[0] as i16),
+            VarType::U32 => f64::from(combined()),
+            VarType::I32 => f64::from(combined() as i32),
+            VarType::Float => f64::from(f32::from_bits(combined())),
+        })
+    }
+
+    /// Преобразование строкового значения в регистры для записи (младшее слово первым)
+    pub fn encode(self, text: &str) -> Result<Vec<u16>, String> {
+        let text = text.trim();
+        let invalid = || format!("значение '{}' не подходит для типа {}", text, self);
+        let split = |value: u32| vec![(value & 0xFFFF) as u16, (value >> 16) as u16];
+        match self {
+            VarType::Bool => match text.to_lowercase().as_str() {
+                "1" | "true" | "on" => Ok(vec![1]),
+                "0" | "false" | "off" => Ok(vec![0]),
+                _ => Err(invalid()),
+            },
+            VarType::U16 => text.parse::<u16>().map(|v| vec![v]).map_err(|_| invalid()),
+            VarType::I16 => text.parse::<i16>().map(|v| vec![v as u16]).map_err(|_| invalid()),
+            VarType::U32 => text.parse::<u32>().map(split).map_err(|_| invalid()),
+            VarType::I32 => text.parse::<i32>().map(|v| split(v as u32)).map_err(|_| invalid()),
+            VarType::Float => text
+                .replace(',', ".")
+                .parse::<f32>()
+                .map(|v| split(v.to_bits()))
+                .map_err(|_| invalid()),
+        }
+    }
+}
+
+impl fmt::Display for VarType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Область данных Modbus (столбец modbus_type в tags.csv)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModbusType {
+    InputRegister,
+    HoldingRegister,
+    Coil,
+    DiscreteInput,
+}
+
+impl ModbusType {
+    pub const ALL: [ModbusType; 4] = [
+        ModbusType::InputRegister,
+        ModbusType::HoldingRegister,
+        ModbusType::Coil,
+        ModbusType::DiscreteInput,
+    ];
+
+    /// Написание в tags.csv
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ModbusType::InputRegister => "input_register",
+            ModbusType::HoldingRegister => "holding_register",
+            ModbusType::Coil => "coil",
+            ModbusType::DiscreteInput => "discrete_input",
+        }
+    }
+
+    /// Функция чтения Modbus для области
+    pub fn read_function(self) -> u8 {
+        match self {
+            ModbusType::Coil => 0x01,
+            ModbusType::DiscreteInput => 0x02,
+            ModbusType::HoldingRegister => 0x03,
+            ModbusType::InputRegister => 0x04,
+        }
+    }
+
+    /// Битовая область (coil, discrete_input) - хранит только bool
+    pub fn is_bit_area(self) -> bool {
+        matches!(self, ModbusType::Coil | ModbusType::DiscreteInput)
+    }
+
+    /// Можно ли записывать в область (coil, holding_register)
+    pub fn is_writable(self) -> bool {
+        matches!(self, ModbusType::Coil | ModbusType::HoldingRegister)
+    }
+
+    /// Допустим ли тип значения в этой области
+    pub fn supports(self, var_type: VarType) -> bool {
+        !self.is_bit_area() || var_type == VarType::Bool
+    }
+
+    /// Первая цифра адреса в нотации Modicon (0xxxx, 1xxxx, 3xxxx, 4xxxx)
+    pub fn modicon_digit(self) -> char {
+        match self {
+            ModbusType::Coil => '0',
+            ModbusType::DiscreteInput => '1',
+            ModbusType::InputRegister => '3',
+            ModbusType::HoldingRegister => '4',
+        }
+    }
+
+    fn from_modicon_digit(digit: char) -> Option<Self> {
+        ModbusType::ALL.into_iter().find(|area| area.modicon_digit() == digit)
+    }
+}
+
+/// Разбор адреса в нотации Modicon: 5 цифр (40001-49999) или 6 цифр (400001-465536).
+/// Возвращает область и смещение от нуля.
+pub fn parse_modicon_reference(text: &str) -> Option<(ModbusType, u16)> {
+    let text = text.trim();
+    if !(text.len() == 5 || text.len() == 6) || !text.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let area = ModbusType::from_modicon_digit(text.chars().next()?)?;
+    let number: u32 = text[1..].parse().ok()?;
+    if number == 0 {
+        return None;
+    }
+    let offset = u16::try_from(number - 1).ok()?;
+    Some((area, offset))
+}
+
+impl fmt::Display for ModbusType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let cases = [
+            (VarType::Bool, "true", "true"),
+            (VarType::Bool, "off", "false"),
+            (VarType::U16, "65535", "65535"),
+            (VarType::I16, "-1", "-1"),
+            (VarType::I16, "-32768", "-32768"),
+            (VarType::U32, "4294967295", "4294967295"),
+            (VarType::U32, "65536", "65536"),
+            (VarType::I32, "-2147483648", "-2147483648"),
+            (VarType::I32, "-70000", "-70000"),
+            (VarType::Float, "1.5", "1.500"),
+            (VarType::Float, "-0,25", "-0.250"),
+        ];
+        for (var_type, text, decoded) in cases {
+            let registers = var_type.encode(text).unwrap();
+            assert_eq!(registers.len(), usize::from(var_type.quantity()), "{} {}", var_type, text);
+            assert_eq!(var_type.decode(&registers).as_deref(), Some(decoded), "{} {}", var_type, text);
+        }
+        assert_eq!(VarType::U32.decode(&[1]), None);
+    }
+
+    #[test]
+    fn encode_puts_low_word_first() {
+        assert_eq!(VarType::U32.encode("65536").unwrap(), [0x0000, 0x0001]);
+        assert_eq!(VarType::I32.encode("-2").unwrap(), [0xFFFE, 0xFFFF]);
+        assert_eq!(VarType::Float.encode("1").unwrap(), [0x0000, 0x3F80]);
+        assert_eq!(VarType::I16.encode("-2").unwrap(), [0xFFFE]);
+    }
+
+    #[test]
+    fn encode_rejects_out_of_range_values() {
+        assert!(VarType::U16.encode("65536").is_err());
+        assert!(VarType::U16.encode("-1").is_err());
+        assert!(VarType::I16.encode("32768").is_err());
+        assert!(VarType::U32.encode("4294967296").is_err());
+        assert!(VarType::Bool.encode("2").is_err());
+        assert!(VarType::Float.encode("abc").is_err());
+    }
+
+    #[test]
+    fn to_f64_matches_decode() {
+        assert_eq!(VarType::I16.to_f64(&[0xFFFF]), Some(-1.0));
+        assert_eq!(VarType::U32.to_f64(&[0x0000, 0x0001]), Some(65536.0));
+        assert_eq!(VarType::Float.to_f64(&[0x0000, 0x3FC0]), Some(1.5));
+        assert_eq!(VarType::Bool.to_f64(&[5]), Some(1.0));
+        assert_eq!(VarType::U32.to_f64(&[1]), None);
+    }
+
+    #[test]
+    fn modicon_reference() {
+        assert_eq!(parse_modicon_reference("40001"), Some((ModbusType::HoldingRegister, 0)));
+        assert_eq!(parse_modicon_reference("30011"), Some((ModbusType::InputRegister, 10)));
+        assert_eq!(parse_modicon_reference("00001"), Some((ModbusType::Coil, 0)));
+        assert_eq!(parse_modicon_reference("165536"), Some((ModbusType::DiscreteInput, 65535)));
+        assert_eq!(parse_modicon_reference("40000"), None);
+        assert_eq!(parse_modicon_reference("465537"), None);
+        assert_eq!(parse_modicon_reference("20001"), None);
+        assert_eq!(parse_modicon_reference("4001"), None);
+    }
+
+    #[test]
+    fn bit_areas_support_only_bool() {
+        for area in ModbusType::ALL {
+            for var_type in VarType::ALL {
+                assert_eq!(area.supports(var_type), !area.is_bit_area() || var_type == VarType::Bool);
+            }
+        }
+        assert!(!ModbusType::Coil.supports(VarType::U16));
+        assert!(ModbusType::HoldingRegister.supports(VarType::Float));
+    }
+}
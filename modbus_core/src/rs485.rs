@@ -0,0 +1,106 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::time::{sleep, Sleep};
+use tokio_serial::{SerialPort, SerialStream};
+
+use crate::settings::ControlLines;
+
+/// Порт с переключением направления полудуплексного RS-485 линией RTS.
+///
+/// Перед отправкой запроса RTS переводится в уровень передачи, после сброса
+/// буфера (драйвер дожидается выхода последнего символа) и паузы
+/// `delay_after_send_ms` - обратно в уровень приема.
+#[derive(Debug)]
+pub struct DirectionControl {
+    port: SerialStream,
+    transmit_level: bool,
+    delay_before: Duration,
+    delay_after: Duration,
+    state: Direction,
+}
+
+#[derive(Debug)]
+enum Direction {
+    Receiving,
+    /// Передатчик включен, пауза перед отправкой
+    Starting(Pin<Box<Sleep>>),
+    Sending,
+    /// Запрос передан, пауза перед переключением на прием
+    Finishing(Pin<Box<Sleep>>),
+}
+
+impl DirectionControl {
+    /// Порт переводится на прием сразу, чтобы не занимать линию до первого запроса
+    pub fn new(mut port: SerialStream, transmit_level: bool, lines: &ControlLines) -> io::Result<Self> {
+        port.write_request_to_send(!transmit_level)?;
+        Ok(DirectionControl {
+            port,
+            transmit_level,
+            delay_before: Duration::from_millis(lines.delay_before_send_ms.into()),
+            delay_after: Duration::from_millis(lines.delay_after_send_ms.into()),
+            state: Direction::Receiving,
+        })
+    }
+
+    fn set_transmit(&mut self, transmit: bool) -> io::Result<()> {
+        let level = if transmit { self.transmit_level } else { !self.transmit_level };
+        self.port.write_request_to_send(level).map_err(io::Error::from)
+    }
+}
+
+impl AsyncRead for DirectionControl {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.port).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for DirectionControl {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        loop {
+            match &mut self.state {
+                Direction::Receiving => {
+                    self.set_transmit(true)?;
+                    self.state = if self.delay_before.is_zero() {
+                        Direction::Sending
+                    } else {
+                        Direction::Starting(Box::pin(sleep(self.delay_before)))
+                    };
+                }
+                Direction::Starting(delay) => {
+                    ready!(delay.as_mut().poll(cx));
+                    self.state = Direction::Sending;
+                }
+                // Новый запрос до переключения на прием: передатчик еще включен
+                Direction::Finishing(_) => self.state = Direction::Sending,
+                Direction::Sending => return Pin::new(&mut self.port).poll_write(cx, buf),
+            }
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(Pin::new(&mut self.port).poll_flush(cx))?;
+        loop {
+            match &mut self.state {
+                Direction::Sending => {
+                    self.state = Direction::Finishing(Box::pin(sleep(self.delay_after)));
+                }
+                Direction::Finishing(delay) => {
+                    ready!(delay.as_mut().poll(cx));
+                    self.set_transmit(false)?;
+                    self.state = Direction::Receiving;
+                    return Poll::Ready(Ok(()));
+                }
+                Direction::Receiving | Direction::Starting(_) => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_flush(cx))?;
+        Pin::new(&mut self.port).poll_shutdown(cx)
+    }
+}
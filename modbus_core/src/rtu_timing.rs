@@ -0,0 +1,450 @@
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::time::{sleep_until, Instant, Sleep};
+
+use crate::settings::ConnectionSettings;
+
+/// Скорость, выше которой спецификация задает фиксированные интервалы
+const FIXED_TIMING_BAUD_RATE: u32 = 19200;
+/// t1.5 и t3.5 для скоростей выше 19200 бод, мкс
+const FIXED_INTER_CHAR_US: u64 = 750;
+const FIXED_SILENT_INTERVAL_US: u64 = 1750;
+/// Запас на задержку доставки байт драйвером и планировщиком: время прихода
+/// измеряется в момент чтения, а не на линии
+const RECEIVE_TOLERANCE: Duration = Duration::from_millis(1);
+
+/// Интервалы кадра Modbus RTU для параметров линии
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RtuTiming {
+    /// Время передачи одного символа (старт, данные, четность, стоп)
+    pub char_time: Duration,
+    /// Допустимая пауза между символами кадра (t1.5); ноль - без проверки
+    pub inter_char_timeout: Duration,
+    /// Пауза тишины между кадрами (t3.5)
+    pub silent_interval: Duration,
+    /// Отбрасывать кадры с паузой между символами больше t1.5 (иначе - только считать их)
+    pub discard_broken_frames: bool,
+}
+
+impl RtuTiming {
+    /// Интервалы по скорости и формату кадра (Modbus over Serial Line, 2.5.1.1)
+    /// с учетом ручных значений из настроек
+    pub fn for_settings(conn: &ConnectionSettings) -> Self {
+        let computed = Self::computed(conn);
+        let manual = conn.frame_timing;
+        RtuTiming {
+            inter_char_timeout: manual
+                .inter_char_timeout_us
+                .map_or(computed.inter_char_timeout, |us| Duration::from_micros(us.into())),
+            silent_interval: manual
+                .silent_interval_us
+                .map_or(computed.silent_interval, |us| Duration::from_micros(us.into())),
+            discard_broken_frames: manual.discard_broken_frames,
+            ..computed
+        }
+    }
+
+    /// Интервалы, вычисленные только по скорости и формату кадра
+    pub fn computed(conn: &ConnectionSettings) -> Self {
+        let parity_bits = if conn.parity == "None" { 0 } else { 1 };
+        let char_bits = 1 + u64::from(conn.data_bits) + parity_bits + u64::from(conn.stop_bits);
+        let baud_rate = u64::from(conn.baud_rate.max(1));
+        let char_time = Duration::from_nanos(char_bits * 1_000_000_000 / baud_rate);
+
+        let (inter_char_timeout, silent_interval) = if conn.baud_rate > FIXED_TIMING_BAUD_RATE {
+            (
+                Duration::from_micros(FIXED_INTER_CHAR_US),
+                Duration::from_micros(FIXED_SILENT_INTERVAL_US),
+            )
+        } else {
+            (char_time * 3 / 2, char_time * 7 / 2)
+        };
+        RtuTiming {
+            char_time,
+            inter_char_timeout,
+            silent_interval,
+            discard_broken_frames: true,
+        }
+    }
+}
+
+/// Транспорт RTU с соблюдением интервалов кадра.
+///
+/// Перед каждым запросом отбрасывает пришедшие с линии байты (опоздавшие
+/// ответы, помехи) и выдерживает паузу t3.5 после последней активности.
+/// Принятые байты передаются кодеку целым кадром после паузы t3.5. Кадр с
+/// паузой между символами больше t1.5 подсчитывается (`broken_frames`) и, как
+/// требует спецификация, отбрасывается - запрос завершается по таймауту; без
+/// `RtuTiming::discard_broken_frames` он только подсчитывается и передается дальше.
+/// Время прихода учитывает, что драйвер
+/// отдает символы пачками: пачка из n байт началась на n символов раньше;
+/// к обоим интервалам приема добавляется `RECEIVE_TOLERANCE`.
+#[derive(Debug)]
+pub struct TimedTransport<T> {
+    inner: T,
+    timing: RtuTiming,
+    /// Окончание последнего принятого или переданного символа
+    last_activity: Instant,
+    /// Принимаемый кадр
+    frame: Vec<u8>,
+    /// В кадре была пауза больше t1.5
+    frame_broken: bool,
+    /// Число принятых кадров с паузой больше t1.5
+    broken_frames: u64,
+    /// Окончание кадра: пауза t3.5 после последнего символа
+    frame_end: Option<Pin<Box<Sleep>>>,
+    /// Принятые кадры, еще не прочитанные кодеком; `received_pos` - позиция в первом
+    received: VecDeque<Vec<u8>>,
+    received_pos: usize,
+    /// Пауза тишины перед запросом
+    silence: Option<Pin<Box<Sleep>>>,
+    sending: bool,
+    chunk: [u8; 256],
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> TimedTransport<T> {
+    pub fn new(inner: T, timing: RtuTiming) -> Self {
+        TimedTransport {
+            inner,
+            timing,
+            last_activity: Instant::now(),
+            frame: Vec::new(),
+            frame_broken: false,
+            broken_frames: 0,
+            frame_end: None,
+            received: VecDeque::new(),
+            received_pos: 0,
+            silence: None,
+            sending: false,
+            chunk: [0; 256],
+        }
+    }
+
+    /// Число принятых кадров с паузой между символами больше t1.5
+    pub fn broken_frames(&self) -> u64 {
+        self.broken_frames
+    }
+
+    /// Чтение очередной пачки байт с линии; Ready(Ok(0)) - порт закрыт
+    fn poll_chunk(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<usize>> {
+        let mut buf = ReadBuf::new(&mut self.chunk);
+        ready!(Pin::new(&mut self.inner).poll_read(cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+
+    /// Время начала пачки из `len` байт, пришедшей сейчас
+    fn arrival(&self, len: usize) -> (Instant, Instant) {
+        let now = Instant::now();
+        let started = now.checked_sub(self.timing.char_time * len as u32).unwrap_or(now);
+        (started, now)
+    }
+
+    fn receive_chunk(&mut self, len: usize) {
+        let (started, now) = self.arrival(len);
+        if !self.frame.is_empty() {
+            let gap = started.saturating_duration_since(self.last_activity);
+            if gap >= self.timing.silent_interval + RECEIVE_TOLERANCE {
+                // Предыдущий кадр закончился до этой пачки
+                self.finish_frame();
+            } else if !self.timing.inter_char_timeout.is_zero()
+                && gap > self.timing.inter_char_timeout + RECEIVE_TOLERANCE
+            {
+                self.frame_broken = true;
+            }
+        }
+        self.frame.extend_from_slice(&self.chunk[..len]);
+        self.last_activity = now;
+        let end = now + self.timing.silent_interval + RECEIVE_TOLERANCE;
+        match &mut self.frame_end {
+            Some(timer) => timer.as_mut().reset(end),
+            None => self.frame_end = Some(Box::pin(sleep_until(end))),
+        }
+    }
+
+    /// Передача принятого кадра кодеку; разорванный кадр учитывается
+    /// и отбрасывается, если так задано в настройках
+    fn finish_frame(&mut self) {
+        self.frame_end = None;
+        if std::mem::take(&mut self.frame_broken) {
+            self.broken_frames += 1;
+            if self.timing.discard_broken_frames {
+                self.frame.clear();
+                return;
+            }
+        }
+        self.received.push_back(std::mem::take(&mut self.frame));
+    }
+
+    /// Следующий принятый кадр целиком (для запросов мимо кодека tokio-modbus)
+    pub async fn next_frame(&mut self) -> io::Result<Vec<u8>> {
+        poll_fn(|cx| self.poll_frame(cx)).await
+    }
+
+    fn poll_frame(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Vec<u8>>> {
+        loop {
+            if let Some(mut frame) = self.received.pop_front() {
+                frame.drain(..std::mem::take(&mut self.received_pos));
+                return Poll::Ready(Ok(frame));
+            }
+            if !ready!(self.poll_receive(cx))? {
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+        }
+    }
+
+    /// Прием до окончания очередного кадра; Ready(Ok(false)) - порт закрыт
+    fn poll_receive(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<bool>> {
+        loop {
+            match self.poll_chunk(cx)? {
+                Poll::Ready(0) => return Poll::Ready(Ok(false)),
+                Poll::Ready(len) => self.receive_chunk(len),
+                Poll::Pending => {
+                    let Some(timer) = self.frame_end.as_mut() else {
+                        return Poll::Pending;
+                    };
+                    ready!(timer.as_mut().poll(cx));
+                    self.finish_frame();
+                    return Poll::Ready(Ok(true));
+                }
+            }
+            if !self.received.is_empty() {
+                return Poll::Ready(Ok(true));
+            }
+        }
+    }
+
+    /// Сброс непрочитанного и пауза t3.5 перед новым запросом
+    fn poll_silence(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            while let Poll::Ready(len) = self.poll_chunk(cx)? {
+                if len == 0 {
+                    break;
+                }
+                self.last_activity = self.arrival(len).1;
+            }
+            let quiet_until = self.last_activity + self.timing.silent_interval;
+            if Instant::now() >= quiet_until {
+                self.silence = None;
+                return Poll::Ready(Ok(()));
+            }
+            let timer = self.silence.get_or_insert_with(|| Box::pin(sleep_until(quiet_until)));
+            if timer.deadline() != quiet_until {
+                timer.as_mut().reset(quiet_until);
+            }
+            ready!(timer.as_mut().poll(cx));
+        }
+    }
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> AsyncRead for TimedTransport<T> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        loop {
+            if let Some(frame) = this.received.front() {
+                let available = &frame[this.received_pos..];
+                let len = available.len().min(buf.remaining());
+                buf.put_slice(&available[..len]);
+                this.received_pos += len;
+                if this.received_pos == frame.len() {
+                    this.received.pop_front();
+                    this.received_pos = 0;
+                }
+                return Poll::Ready(Ok(()));
+            }
+            if !ready!(this.poll_receive(cx))? {
+                return Poll::Ready(Ok(()));
+            }
+        }
+    }
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> AsyncWrite for TimedTransport<T> {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        if !this.sending {
+            // Новый запрос: все непрочитанное относится к прошлым обменам
+            this.frame.clear();
+            this.frame_broken = false;
+            this.frame_end = None;
+            this.received.clear();
+            this.received_pos = 0;
+            ready!(this.poll_silence(cx))?;
+            this.sending = true;
+        }
+        Pin::new(&mut this.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(Pin::new(&mut self.inner).poll_flush(cx))?;
+        if self.sending {
+            // Сброс завершается после выхода последнего символа
+            self.sending = false;
+            self.last_activity = Instant::now();
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt, DuplexStream};
+    use tokio::time::sleep;
+
+    /// Транспорт на 9600 8N1 (символ ~1.04 мс, t1.5 ~1.56 мс, t3.5 ~3.65 мс) и второй конец линии
+    fn transport(discard_broken_frames: bool) -> (TimedTransport<DuplexStream>, DuplexStream) {
+        let (port, line) = duplex(256);
+        let timing = RtuTiming {
+            discard_broken_frames,
+            ..RtuTiming::computed(&ConnectionSettings::for_tests(9600, "None"))
+        };
+        (TimedTransport::new(port, timing), line)
+    }
+
+    #[test]
+    fn computed_at_9600() {
+        let timing = RtuTiming::computed(&ConnectionSettings::for_tests(9600, "None"));
+        assert_eq!(timing.char_time.as_nanos(), 1_041_666);
+        assert_eq!(timing.inter_char_timeout.as_micros(), 1562);
+        assert_eq!(timing.silent_interval.as_micros(), 3645);
+        assert!(timing.discard_broken_frames);
+
+        // Бит четности удлиняет символ до 11 бит
+        let timing = RtuTiming::computed(&ConnectionSettings::for_tests(9600, "Even"));
+        assert_eq!(timing.char_time.as_nanos(), 1_145_833);
+        assert_eq!(timing.inter_char_timeout.as_micros(), 1718);
+        assert_eq!(timing.silent_interval.as_micros(), 4010);
+    }
+
+    #[test]
+    fn computed_at_38400_uses_fixed_intervals() {
+        let timing = RtuTiming::computed(&ConnectionSettings::for_tests(38400, "None"));
+        assert_eq!(timing.char_time.as_nanos(), 260_416);
+        assert_eq!(timing.inter_char_timeout, Duration::from_micros(750));
+        assert_eq!(timing.silent_interval, Duration::from_micros(1750));
+    }
+
+    #[test]
+    fn manual_values_override_computed() {
+        let mut conn = ConnectionSettings::for_tests(9600, "None");
+        conn.frame_timing.inter_char_timeout_us = Some(0);
+        conn.frame_timing.silent_interval_us = Some(20_000);
+        conn.frame_timing.discard_broken_frames = false;
+        let timing = RtuTiming::for_settings(&conn);
+        assert_eq!(timing.char_time, RtuTiming::computed(&conn).char_time);
+        assert!(timing.inter_char_timeout.is_zero());
+        assert_eq!(timing.silent_interval, Duration::from_millis(20));
+        assert!(!timing.discard_broken_frames);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn frame_completes_after_silent_interval() {
+        let (mut transport, mut line) = transport(false);
+        let started = Instant::now();
+        line.write_all(&[0x01, 0x03, 0x02, 0x00, 0x05]).await.unwrap();
+        let frame = transport.next_frame().await.unwrap();
+        assert_eq!(frame, [0x01, 0x03, 0x02, 0x00, 0x05]);
+        // Таймеры tokio округляют срок вверх до миллисекунды
+        let end = transport.timing.silent_interval + RECEIVE_TOLERANCE;
+        assert!((end..end + Duration::from_millis(1)).contains(&started.elapsed()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn chunks_within_frame_are_joined() {
+        let (mut transport, mut line) = transport(false);
+        let line_side = async {
+            line.write_all(&[1, 2, 3]).await.unwrap();
+            sleep(Duration::from_millis(2)).await;
+            line.write_all(&[4, 5]).await.unwrap();
+            line
+        };
+        let (frame, _line) = tokio::join!(transport.next_frame(), line_side);
+        assert_eq!(frame.unwrap(), [1, 2, 3, 4, 5]);
+        assert_eq!(transport.broken_frames(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn silence_separates_frames() {
+        let (mut transport, mut line) = transport(false);
+        let line_side = async {
+            line.write_all(&[1, 2, 3]).await.unwrap();
+            sleep(Duration::from_millis(10)).await;
+            line.write_all(&[4, 5]).await.unwrap();
+            line
+        };
+        let frames = async { (transport.next_frame().await.unwrap(), transport.next_frame().await.unwrap()) };
+        let ((first, second), _line) = tokio::join!(frames, line_side);
+        assert_eq!(first, [1, 2, 3]);
+        assert_eq!(second, [4, 5]);
+    }
+
+    /// Пачка [4] приходит через 4 мс после [1, 2, 3]: пауза на линии ~2.96 мс -
+    /// больше t1.5 с запасом, но кадр по t3.5 еще не закончился
+    async fn send_broken_frame(line: &mut DuplexStream) {
+        line.write_all(&[1, 2, 3]).await.unwrap();
+        sleep(Duration::from_millis(4)).await;
+        line.write_all(&[4]).await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn broken_frame_is_delivered_when_count_only() {
+        let (mut transport, mut line) = transport(false);
+        let (frame, _) = tokio::join!(transport.next_frame(), send_broken_frame(&mut line));
+        assert_eq!(frame.unwrap(), [1, 2, 3, 4]);
+        assert_eq!(transport.broken_frames(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn broken_frame_is_discarded_by_default() {
+        let (mut transport, mut line) = transport(true);
+        let line_side = async {
+            send_broken_frame(&mut line).await;
+            sleep(Duration::from_millis(10)).await;
+            line.write_all(&[7, 8]).await.unwrap();
+        };
+        let (frame, _) = tokio::join!(transport.next_frame(), line_side);
+        assert_eq!(frame.unwrap(), [7, 8]);
+        assert_eq!(transport.broken_frames(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_waits_for_silence_and_drops_stale_bytes() {
+        let (mut transport, mut line) = transport(false);
+        // Опоздавший ответ на прошлый запрос
+        let stale_at = Instant::now();
+        line.write_all(&[0xFF, 0xFE]).await.unwrap();
+
+        transport.write_all(&[1, 3, 0, 0, 0, 1]).await.unwrap();
+        transport.flush().await.unwrap();
+        let silence = transport.timing.silent_interval;
+        assert!((silence..silence + Duration::from_millis(1)).contains(&stale_at.elapsed()));
+
+        let mut request = [0u8; 6];
+        line.read_exact(&mut request).await.unwrap();
+        assert_eq!(request, [1, 3, 0, 0, 0, 1]);
+        line.write_all(&[1, 3, 2, 0, 5]).await.unwrap();
+        assert_eq!(transport.next_frame().await.unwrap(), [1, 3, 2, 0, 5]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn request_on_quiet_line_is_sent_at_once() {
+        let (mut transport, mut line) = transport(false);
+        sleep(Duration::from_millis(10)).await;
+        let started = Instant::now();
+        transport.write_all(&[1, 3, 0, 0, 0, 1]).await.unwrap();
+        transport.flush().await.unwrap();
+        assert!(started.elapsed().is_zero());
+        let mut request = [0u8; 6];
+        line.read_exact(&mut request).await.unwrap();
+    }
+}
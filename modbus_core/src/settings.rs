@@ -0,0 +1,363 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+
+use crate::address::AddressNotation;
+use crate::port_binding::PortBinding;
+
+/// Допустимые значения четности: None, Even, Odd, а также Mark (бит всегда 1) и Space (бит всегда 0)
+pub const PARITY_VALUES: [&str; 5] = ["None", "Even", "Odd", "Mark", "Space"];
+
+/// Структура для хранения настроек подключения
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConnectionSettings {
+    pub port: String,
+    pub device_address: u8,
+    pub baud_rate: u32,
+    pub parity: String,
+    pub stop_bits: u8,
+    /// Число бит данных (5-8); Modbus RTU использует 8, 7 встречается у шлюзов Modbus ASCII
+    #[serde(default = "default_data_bits")]
+    pub data_bits: u8,
+    #[serde(default)]
+    pub flow_control: FlowControl,
+    /// Линии RTS/DTR и переключение направления RS-485
+    #[serde(default)]
+    pub control_lines: ControlLines,
+    /// Ручные значения интервалов t1.5/t3.5 (для медленных шлюзов); по умолчанию - по скорости
+    #[serde(default, skip_serializing_if = "FrameTiming::is_default")]
+    pub frame_timing: FrameTiming,
+    /// Необязательная привязка к USB-адаптеру (порт определяется при запуске опроса)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port_binding: Option<PortBinding>,
+}
+
+fn default_data_bits() -> u8 {
+    8
+}
+
+#[cfg(test)]
+impl ConnectionSettings {
+    /// Настройки линии без порта: 8 бит данных, 1 стоп-бит, остальное по умолчанию
+    pub(crate) fn for_tests(baud_rate: u32, parity: &str) -> Self {
+        ConnectionSettings {
+            port: String::new(),
+            device_address: 1,
+            baud_rate,
+            parity: parity.to_string(),
+            stop_bits: 1,
+            data_bits: 8,
+            flow_control: FlowControl::default(),
+            control_lines: ControlLines::default(),
+            frame_timing: FrameTiming::default(),
+            port_binding: None,
+        }
+    }
+}
+
+impl ConnectionSettings {
+    /// Формат кадра в привычной записи: 8N1, 7E1, 8M2...
+    pub fn frame_format(&self) -> String {
+        let parity = self.parity.chars().next().unwrap_or('N');
+        format!("{}{}{}", self.data_bits, parity, self.stop_bits)
+    }
+}
+
+/// Управление потоком
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FlowControl {
+    #[default]
+    None,
+    /// XON/XOFF
+    Software,
+    /// RTS/CTS
+    Hardware,
+}
+
+impl FlowControl {
+    pub const ALL: [FlowControl; 3] = [FlowControl::None, FlowControl::Software, FlowControl::Hardware];
+}
+
+/// Состояние линии RTS
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RtsMode {
+    /// Линия не изменяется (адаптеры с автоматическим переключением направления)
+    #[default]
+    Unchanged,
+    On,
+    Off,
+    /// RTS включается на время передачи запроса (полудуплексный RS-485)
+    Transmit,
+    /// RTS выключается на время передачи запроса (инверсное управление направлением)
+    TransmitInverted,
+}
+
+impl RtsMode {
+    pub const ALL: [RtsMode; 5] = [
+        RtsMode::Unchanged,
+        RtsMode::On,
+        RtsMode::Off,
+        RtsMode::Transmit,
+        RtsMode::TransmitInverted,
+    ];
+
+    /// Уровень RTS на время передачи, если RTS управляет направлением линии
+    pub fn transmit_level(self) -> Option<bool> {
+        match self {
+            RtsMode::Transmit => Some(true),
+            RtsMode::TransmitInverted => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Состояние линии DTR
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PinState {
+    #[default]
+    Unchanged,
+    On,
+    Off,
+}
+
+impl PinState {
+    pub const ALL: [PinState; 3] = [PinState::Unchanged, PinState::On, PinState::Off];
+}
+
+/// Управление линиями RTS/DTR и задержки переключения направления RS-485
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ControlLines {
+    #[serde(default)]
+    pub rts: RtsMode,
+    #[serde(default)]
+    pub dtr: PinState,
+    /// Пауза между включением передатчика и отправкой запроса, мс
+    #[serde(default)]
+    pub delay_before_send_ms: u16,
+    /// Пауза между окончанием передачи и переключением на прием, мс
+    #[serde(default)]
+    pub delay_after_send_ms: u16,
+}
+
+/// Ручные интервалы Modbus RTU, мкс; None - вычисляются по скорости и формату кадра
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FrameTiming {
+    /// Допустимая пауза между символами кадра (t1.5); 0 - без проверки
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inter_char_timeout_us: Option<u32>,
+    /// Пауза тишины между кадрами (t3.5)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub silent_interval_us: Option<u32>,
+    /// Отбрасывать кадры с паузой между символами больше t1.5, как требует спецификация.
+    /// Отключается для медленных шлюзов и USB-адаптеров, которые отдают байты пачками
+    /// по своему таймеру задержки (FTDI - 16 мс): тогда такие кадры только подсчитываются.
+    #[serde(default = "default_discard_broken_frames")]
+    pub discard_broken_frames: bool,
+}
+
+fn default_discard_broken_frames() -> bool {
+    true
+}
+
+impl Default for FrameTiming {
+    fn default() -> Self {
+        FrameTiming {
+            inter_char_timeout_us: None,
+            silent_interval_us: None,
+            discard_broken_frames: default_discard_broken_frames(),
+        }
+    }
+}
+
+impl FrameTiming {
+    /// Интервалы вычисляются по скорости
+    pub fn is_auto(&self) -> bool {
+        self.inter_char_timeout_us.is_none() && self.silent_interval_us.is_none()
+    }
+
+    /// Настройки по умолчанию (не сохраняются в файл)
+    pub fn is_default(&self) -> bool {
+        self.is_auto() && self.discard_broken_frames
+    }
+}
+
+/// Диапазон адресов устройств Modbus RTU: 0 - широковещательный, 248-255 зарезервированы
+pub const MIN_SLAVE_ADDRESS: u8 = 1;
+pub const MAX_SLAVE_ADDRESS: u8 = 247;
+
+/// Допустим ли адрес устройства
+pub fn is_valid_slave_address(address: u8) -> bool {
+    (MIN_SLAVE_ADDRESS..=MAX_SLAVE_ADDRESS).contains(&address)
+}
+
+/// Проверка параметров линии; ошибка - описание первого неверного параметра
+pub fn check_line_settings(settings: &ConnectionSettings) -> Result<(), String> {
+    if settings.baud_rate == 0 {
+        return Err("Скорость должна быть больше нуля".to_string());
+    }
+    if settings.frame_timing.silent_interval_us == Some(0) {
+        return Err("Пауза между кадрами (t3.5) должна быть больше нуля".to_string());
+    }
+    if !PARITY_VALUES.contains(&settings.parity.as_str()) {
+        return Err(format!(
+            "Неизвестная четность '{}' ({})",
+            settings.parity,
+            PARITY_VALUES.join(", ")
+        ));
+    }
+    if !matches!(settings.stop_bits, 1 | 2) {
+        return Err("Число стоп-бит должно быть 1 или 2".to_string());
+    }
+    if !(5..=8).contains(&settings.data_bits) {
+        return Err("Число бит данных должно быть от 5 до 8".to_string());
+    }
+    if settings.flow_control == FlowControl::Hardware && settings.control_lines.rts != RtsMode::Unchanged {
+        return Err("При управлении потоком RTS/CTS линией RTS управляет драйвер порта".to_string());
+    }
+    Ok(())
+}
+
+/// Структура для метаданных
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Metadata {
+    pub last_updated: String,
+    pub version: String,
+    pub description: String,
+    /// Идентификация устройства (функция 0x2B/0x0E), сохраненная пользователем
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<DeviceInfo>,
+}
+
+/// Сохраненная идентификация устройства
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeviceInfo {
+    /// Адрес устройства, у которого прочитана идентификация
+    pub device_address: u8,
+    pub read_at: String,
+    pub conformity_level: u8,
+    pub objects: Vec<DeviceInfoObject>,
+}
+
+/// Объект идентификации: номер, имя по спецификации и значение
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeviceInfoObject {
+    pub id: u8,
+    pub name: String,
+    pub value: String,
+}
+
+/// Язык интерфейса
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Ru,
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Ru, Language::En];
+
+    /// Код языка в настройках и в командной строке (`--lang ru|en`)
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Ru => "ru",
+            Language::En => "en",
+        }
+    }
+
+    /// Название языка на нем самом
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::Ru => "Русский",
+            Language::En => "English",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().as_str() {
+            "ru" | "rus" | "russian" => Some(Language::Ru),
+            "en" | "eng" | "english" => Some(Language::En),
+            _ => None,
+        }
+    }
+
+    /// Язык по значению переменной локали (`ru_RU.UTF-8`, `en_US`, `C`...).
+    /// Русская локаль дает русский язык, любая другая, кроме C/POSIX, - английский.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let locale = locale.trim();
+        if locale.is_empty() || locale == "C" || locale.starts_with("C.") || locale == "POSIX" {
+            return None;
+        }
+        if locale.to_lowercase().starts_with("ru") {
+            Some(Language::Ru)
+        } else {
+            Some(Language::En)
+        }
+    }
+}
+
+/// Настройки интерфейса
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UiSettings {
+    /// Запись адресов в списке регистров
+    #[serde(default)]
+    pub address_notation: AddressNotation,
+    /// Язык интерфейса; если не задан - определяется по локали системы
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+}
+
+/// Основная структура конфигурации (connect_settings.json)
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Config {
+    pub connection: ConnectionSettings,
+    pub metadata: Metadata,
+    #[serde(default)]
+    pub ui: UiSettings,
+}
+
+/// Загрузка настроек из JSON файла
+pub fn load_config(path: &str) -> io::Result<Config> {
+    let file_content = fs::read_to_string(path)?;
+    serde_json::from_str(&file_content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Запись файла настроек. Сохраненная идентификация устройства остается,
+/// если порт и адрес устройства не изменились.
+pub fn save_config(path: &str, connection: ConnectionSettings, ui: UiSettings) -> io::Result<()> {
+    let device = load_config(path).ok().and_then(|previous| {
+        let same_device = previous.connection.port == connection.port
+            && previous.connection.device_address == connection.device_address;
+        previous.metadata.device.filter(|_| same_device)
+    });
+    write_config(path, connection, ui, device)
+}
+
+/// Сохранение идентификации устройства в файле настроек
+pub fn save_device_info(path: &str, device: DeviceInfo) -> io::Result<()> {
+    let config = load_config(path)?;
+    write_config(path, config.connection, config.ui, Some(device))
+}
+
+fn write_config(path: &str, connection: ConnectionSettings, ui: UiSettings, device: Option<DeviceInfo>) -> io::Result<()> {
+    let metadata = Metadata {
+        last_updated: chrono::Utc::now().to_rfc3339(),
+        version: "1.0".to_string(),
+        description: "Настройки подключения для Modbus RTU через RS-485".to_string(),
+        device,
+    };
+
+    let config = Config {
+        connection,
+        metadata,
+        ui,
+    };
+
+    let json_content = serde_json::to_string_pretty(&config)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(path, json_content)
+}
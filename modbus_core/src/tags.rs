@@ -0,0 +1,268 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+
+use crate::address::max_file_offset;
+use crate::register_types::{ModbusType, VarType};
+use crate::settings::Metadata;
+use crate::tags_validation::{IssueKind, TagIssue};
+
+/// Первая строка tags.csv, включающая нумерацию адресов с единицы.
+/// Внутри программы адрес всегда хранится как смещение от нуля; пересчет - только при чтении и записи файла.
+pub const ONE_BASED_DIRECTIVE: &str = "# addressing=1";
+
+/// Структура для описания регистра
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RegisterConfig {
+    pub name: String,
+    pub description: String,
+    pub address: u16,
+    pub var_type: VarType,
+    pub modbus_type: ModbusType,
+    pub enabled: bool,
+    /// Единица измерения для вывода значения (необязательный столбец tags.csv)
+    #[serde(default)]
+    pub unit: String,
+}
+
+/// Структура для хранения всех регистров
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RegistersConfig {
+    pub registers: Vec<RegisterConfig>,
+    pub metadata: Metadata,
+}
+
+impl RegistersConfig {
+    /// Конфигурация из тегов, прочитанных `read_registers_csv` (номера строк отбрасываются)
+    pub fn from_rows(rows: Vec<(usize, RegisterConfig)>) -> Self {
+        let metadata = Metadata {
+            last_updated: chrono::Utc::now().to_rfc3339(),
+            version: "csv-1.0".to_string(),
+            description: "Конфигурация регистров из CSV (tags.csv)".to_string(),
+            device: None,
+        };
+        RegistersConfig {
+            registers: rows.into_iter().map(|(_, register)| register).collect(),
+            metadata,
+        }
+    }
+}
+
+/// Порядок сортировки списка тегов
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    /// По адресу без учета области (как в меню консольного клиента)
+    Address,
+    /// По области Modbus, внутри области - по адресу
+    Area,
+    Name,
+}
+
+/// Сортировка тегов; порядок тегов с равным ключом сохраняется
+pub fn sort_registers(registers: &mut [RegisterConfig], key: SortKey) {
+    match key {
+        SortKey::Address => registers.sort_by_key(|reg| reg.address),
+        SortKey::Area => registers.sort_by_key(|reg| (reg.modbus_type.modicon_digit(), reg.address)),
+        SortKey::Name => registers.sort_by(|a, b| a.name.cmp(&b.name)),
+    }
+}
+
+/// Включена ли в файле тегов нумерация адресов с единицы
+pub fn file_is_one_based(path: &str) -> bool {
+    fs::read_to_string(path)
+        .map(|content| content.lines().next().map(str::trim) == Some(ONE_BASED_DIRECTIVE))
+        .unwrap_or(false)
+}
+
+/// Поля тега в tags.csv (для привязки ошибки разбора к полю)
+const TAG_FIELDS: [&str; 7] = ["name", "description", "address", "var_type", "modbus_type", "enabled", "unit"];
+
+/// Чтение тегов из CSV-файла формата tags.csv вместе с номерами строк.
+/// Первая строка, которую не удалось разобрать, - ошибка загрузки.
+pub fn read_registers_csv(path: &str) -> io::Result<Vec<(usize, RegisterConfig)>> {
+    let (registers, issues) = read_registers_csv_lenient(path)?;
+    match issues.first() {
+        Some(issue) => Err(io::Error::new(io::ErrorKind::InvalidData, issue.to_string())),
+        None => Ok(registers),
+    }
+}
+
+/// Теги файла с номерами строк и проблемы разбора строк, которые пришлось пропустить
+pub type LenientRows = (Vec<(usize, RegisterConfig)>, Vec<TagIssue>);
+
+/// Чтение тегов для проверки файла: строки, которые не удалось разобрать,
+/// возвращаются как проблемы (`IssueKind::Parse`), чтение продолжается
+pub fn read_registers_csv_lenient(path: &str) -> io::Result<LenientRows> {
+    let file = fs::File::open(path)?;
+    let one_based = file_is_one_based(path);
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(b';')
+        .has_headers(true)
+        .comment(Some(b'#'))
+        .from_reader(file);
+
+    let headers = reader
+        .headers()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        .clone();
+    let name_column = headers.iter().position(|header| header == "name");
+
+    let mut registers: Vec<(usize, RegisterConfig)> = Vec::new();
+    let mut issues: Vec<TagIssue> = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) if e.is_io_error() => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) => {
+                // Неверное число столбцов и т.п.: строка пропускается
+                let line = e.position().map(|pos| pos.line() as usize).unwrap_or(0);
+                let kind = match e.kind() {
+                    csv::ErrorKind::UnequalLengths { expected_len, len, .. } => IssueKind::ColumnCount {
+                        found: *len,
+                        expected: *expected_len,
+                    },
+                    _ => IssueKind::Parse {
+                        field: "row",
+                        message: e.to_string(),
+                    },
+                };
+                issues.push(TagIssue {
+                    line,
+                    name: String::new(),
+                    kind,
+                });
+                continue;
+            }
+        };
+        let line = record.position().map(|pos| pos.line() as usize).unwrap_or(0);
+        let name = name_column.and_then(|column| record.get(column)).unwrap_or_default().to_string();
+        match record.deserialize::<RegisterConfig>(Some(&headers)) {
+            Ok(mut register) => {
+                if one_based {
+                    let Some(offset) = register.address.checked_sub(1) else {
+                        issues.push(TagIssue {
+                            line,
+                            name,
+                            kind: IssueKind::ZeroAddressOneBased,
+                        });
+                        continue;
+                    };
+                    register.address = offset;
+                }
+                registers.push((line, register));
+            }
+            Err(e) => {
+                let (field, message) = match e.kind() {
+                    csv::ErrorKind::Deserialize { err, .. } => {
+                        (failed_field(&headers, &record, err.field()), err.kind().to_string())
+                    }
+                    _ => ("row", e.to_string()),
+                };
+                issues.push(parse_issue(line, name, field, message));
+            }
+        }
+    }
+
+    Ok((registers, issues))
+}
+
+/// Столбец, значение которого не удалось разобрать. Для перечислений csv не сообщает
+/// номер поля, поэтому типы переменной и области проверяются отдельно.
+fn failed_field(headers: &csv::StringRecord, record: &csv::StringRecord, column: Option<u64>) -> &'static str {
+    let known = |header: &str| TAG_FIELDS.iter().find(|field| **field == header).copied();
+    if let Some(field) = column.and_then(|column| headers.get(column as usize)).and_then(known) {
+        return field;
+    }
+    let value = |field: &str| {
+        let value = headers.iter().position(|header| header == field).and_then(|column| record.get(column));
+        csv::StringRecord::from(vec![value.unwrap_or_default()])
+    };
+    if value("var_type").deserialize::<VarType>(None).is_err() {
+        "var_type"
+    } else if value("modbus_type").deserialize::<ModbusType>(None).is_err() {
+        "modbus_type"
+    } else {
+        "row"
+    }
+}
+
+fn parse_issue(line: usize, name: String, field: &'static str, message: String) -> TagIssue {
+    TagIssue {
+        line,
+        name,
+        kind: IssueKind::Parse { field, message },
+    }
+}
+
+/// Загрузка регистров из CSV файла без проверки содержимого
+pub fn load_registers_file(path: &str) -> io::Result<RegistersConfig> {
+    Ok(RegistersConfig::from_rows(read_registers_csv(path)?))
+}
+
+/// Ошибка записи tags.csv
+#[derive(Debug)]
+pub enum TagsWriteError {
+    /// Смещение 65535 нельзя записать при нумерации с 1 (имя тега); файл не изменен
+    AddressTooLarge(String),
+    /// Ошибка создания или записи файла
+    Io(io::Error),
+}
+
+impl fmt::Display for TagsWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagsWriteError::AddressTooLarge(name) => {
+                write!(f, "тег '{}': адрес 65535 нельзя записать при нумерации с 1", name)
+            }
+            TagsWriteError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TagsWriteError {}
+
+impl From<io::Error> for TagsWriteError {
+    fn from(e: io::Error) -> Self {
+        TagsWriteError::Io(e)
+    }
+}
+
+/// Запись регистров в CSV-файл формата tags.csv по указанному пути
+pub fn write_registers_csv(path: &str, registers: &[RegisterConfig], one_based: bool) -> Result<(), TagsWriteError> {
+    // Проверка до открытия: иначе файл будет обрезан и не загрузится
+    if let Some(register) = registers.iter().find(|reg| reg.address > max_file_offset(one_based)) {
+        return Err(TagsWriteError::AddressTooLarge(register.name.clone()));
+    }
+    let mut file = fs::File::create(path)?;
+    if one_based {
+        writeln!(file, "{}", ONE_BASED_DIRECTIVE)?;
+    }
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(b';')
+        .from_writer(file);
+
+    // Заголовок
+    writer
+        .write_record(["name", "description", "address", "var_type", "modbus_type", "enabled", "unit"])
+        .map_err(io::Error::other)?;
+
+    for reg in registers {
+        writer
+            .write_record([
+                reg.name.as_str(),
+                reg.description.as_str(),
+                &(u32::from(reg.address) + u32::from(one_based)).to_string(),
+                reg.var_type.as_str(),
+                reg.modbus_type.as_str(),
+                if reg.enabled { "true" } else { "false" },
+                reg.unit.as_str(),
+            ])
+            .map_err(io::Error::other)?;
+    }
+
+    writer.flush().map_err(io::Error::other)?;
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::tags::read_registers_csv;
+use crate::RegisterConfig;
+
+/// Формат файла для обмена списком тегов с другими программами
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExchangeFormat {
+    Json,
+    Yaml,
+    Toml,
+    /// CSV с разделителем-запятой (при импорте допускается и формат tags.csv)
+    Csv,
+}
+
+impl ExchangeFormat {
+    pub const ALL: [ExchangeFormat; 4] = [
+        ExchangeFormat::Json,
+        ExchangeFormat::Yaml,
+        ExchangeFormat::Toml,
+        ExchangeFormat::Csv,
+    ];
+
+    /// Определение формата по расширению файла
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "json" => Some(ExchangeFormat::Json),
+            "yaml" | "yml" => Some(ExchangeFormat::Yaml),
+            "toml" => Some(ExchangeFormat::Toml),
+            "csv" => Some(ExchangeFormat::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExchangeFormat::Json => "json",
+            ExchangeFormat::Yaml => "yaml",
+            ExchangeFormat::Toml => "toml",
+            ExchangeFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Режим объединения импортированных тегов с текущим списком
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeMode {
+    /// Текущий список заменяется импортированным
+    Replace,
+    /// Импортированные теги добавляются в конец списка
+    Append,
+    /// Теги с совпадающим именем заменяются, остальные добавляются в конец
+    UpdateByName,
+}
+
+impl MergeMode {
+    pub const ALL: [MergeMode; 3] = [MergeMode::Replace, MergeMode::Append, MergeMode::UpdateByName];
+
+    /// Имя режима в командной строке
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "replace" => Some(MergeMode::Replace),
+            "append" => Some(MergeMode::Append),
+            "update" => Some(MergeMode::UpdateByName),
+            _ => None,
+        }
+    }
+}
+
+/// Документ JSON/YAML/TOML: список тегов под ключом `registers`.
+/// Поля тега берутся из RegisterConfig, поэтому новые столбцы попадают в файлы автоматически.
+#[derive(Serialize, Deserialize)]
+struct TagsDocument {
+    registers: Vec<RegisterConfig>,
+}
+
+/// Для JSON и YAML при импорте допускается и просто массив тегов
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ImportedDocument {
+    Document(TagsDocument),
+    List(Vec<RegisterConfig>),
+}
+
+impl ImportedDocument {
+    fn into_registers(self) -> Vec<RegisterConfig> {
+        match self {
+            ImportedDocument::Document(document) => document.registers,
+            ImportedDocument::List(registers) => registers,
+        }
+    }
+}
+
+/// Итог объединения списков
+#[derive(Debug, Default)]
+pub struct MergeSummary {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+}
+
+/// Запись списка тегов в файл указанного формата
+pub fn export_registers(path: &str, format: ExchangeFormat, registers: &[RegisterConfig]) -> io::Result<()> {
+    let document = TagsDocument {
+        registers: registers.to_vec(),
+    };
+    let content = match format {
+        ExchangeFormat::Json => serde_json::to_string_pretty(&document).map_err(io::Error::other)?,
+        ExchangeFormat::Yaml => serde_yaml::to_string(&document).map_err(io::Error::other)?,
+        ExchangeFormat::Toml => toml::to_string_pretty(&document).map_err(io::Error::other)?,
+        ExchangeFormat::Csv => {
+            let mut writer = csv::WriterBuilder::new().delimiter(b',').from_writer(Vec::new());
+            for register in registers {
+                writer.serialize(register).map_err(io::Error::other)?;
+            }
+            let bytes = writer.into_inner().map_err(io::Error::other)?;
+            String::from_utf8(bytes).map_err(io::Error::other)?
+        }
+    };
+    fs::write(path, content)
+}
+
+/// Чтение списка тегов из файла указанного формата
+pub fn import_registers(path: &str, format: ExchangeFormat) -> io::Result<Vec<RegisterConfig>> {
+    let content = fs::read_to_string(path)?;
+    let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+    match format {
+        ExchangeFormat::Json => serde_json::from_str::<ImportedDocument>(&content)
+            .map(ImportedDocument::into_registers)
+            .map_err(|e| invalid(e.to_string())),
+        ExchangeFormat::Yaml => serde_yaml::from_str::<ImportedDocument>(&content)
+            .map(ImportedDocument::into_registers)
+            .map_err(|e| invalid(e.to_string())),
+        ExchangeFormat::Toml => toml::from_str::<TagsDocument>(&content)
+            .map(|document| document.registers)
+            .map_err(|e| invalid(e.to_string())),
+        ExchangeFormat::Csv => {
+            // Файл формата tags.csv (точка с запятой) читается как tags.csv, с учетом нумерации адресов
+            let header = content.lines().find(|line| !line.starts_with('#')).unwrap_or_default();
+            if header.contains(';') && !header.contains(',') {
+                return Ok(read_registers_csv(path)?.into_iter().map(|(_, register)| register).collect());
+            }
+            let mut reader = csv::ReaderBuilder::new()
+                .delimiter(b',')
+                .has_headers(true)
+                .from_reader(content.as_bytes());
+            let mut registers = Vec::new();
+            for record in reader.deserialize::<RegisterConfig>() {
+                registers.push(record.map_err(|e| invalid(e.to_string()))?);
+            }
+            Ok(registers)
+        }
+    }
+}
+
+/// Объединение текущего списка с импортированным
+pub fn merge_registers(
+    current: &mut Vec<RegisterConfig>,
+    imported: Vec<RegisterConfig>,
+    mode: MergeMode,
+) -> MergeSummary {
+    let mut summary = MergeSummary::default();
+    match mode {
+        MergeMode::Replace => {
+            summary.removed = current.len();
+            summary.added = imported.len();
+            *current = imported;
+        }
+        MergeMode::Append => {
+            summary.added = imported.len();
+            current.extend(imported);
+        }
+        MergeMode::UpdateByName => {
+            for register in imported {
+                match current.iter_mut().find(|existing| existing.name == register.name) {
+                    Some(existing) => {
+                        *existing = register;
+                        summary.updated += 1;
+                    }
+                    None => {
+                        current.push(register);
+                        summary.added += 1;
+                    }
+                }
+            }
+        }
+    }
+    summary
+}
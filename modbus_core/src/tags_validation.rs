@@ -0,0 +1,299 @@
+use std::fmt;
+
+use crate::address::max_file_offset;
+use crate::register_types::{ModbusType, VarType};
+use crate::RegisterConfig;
+
+/// Вид проблемы в описании тега
+#[derive(Debug, Clone)]
+pub enum IssueKind {
+    EmptyName,
+    DuplicateName { first_line: usize },
+    InvalidCombination { var_type: VarType, modbus_type: ModbusType },
+    AddressOutOfRange { last_address: u32 },
+    /// Смещение 65535 в файле с нумерацией с 1
+    OneBasedOverflow,
+    /// Адрес 0 в файле с нумерацией с 1
+    ZeroAddressOneBased,
+    Overlap { other_name: String, other_line: usize },
+    /// Строку не удалось разобрать: неизвестный тип, неверное число и т.п.
+    /// (`field` - столбец tags.csv или "row" для строки целиком)
+    Parse { field: &'static str, message: String },
+    /// Число столбцов в строке не совпадает с заголовком
+    ColumnCount { found: u64, expected: u64 },
+}
+
+/// Проблема в конкретной строке tags.csv
+#[derive(Debug, Clone)]
+pub struct TagIssue {
+    /// Номер строки в файле (заголовок - строка 1)
+    pub line: usize,
+    pub name: String,
+    pub kind: IssueKind,
+}
+
+impl IssueKind {
+    /// Поле тега, к которому относится проблема (для подсветки в форме)
+    pub fn field(&self) -> &'static str {
+        match self {
+            IssueKind::EmptyName | IssueKind::DuplicateName { .. } => "name",
+            IssueKind::InvalidCombination { .. } => "var_type",
+            IssueKind::AddressOutOfRange { .. }
+            | IssueKind::OneBasedOverflow
+            | IssueKind::ZeroAddressOneBased
+            | IssueKind::Overlap { .. } => "address",
+            IssueKind::Parse { field, .. } => field,
+            IssueKind::ColumnCount { .. } => "row",
+        }
+    }
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueKind::EmptyName => write!(f, "пустое имя"),
+            IssueKind::DuplicateName { first_line } => {
+                write!(f, "имя повторяется (впервые в строке {})", first_line)
+            }
+            IssueKind::InvalidCombination { var_type, modbus_type } => write!(
+                f,
+                "тип '{}' недопустим для '{}' (для coil и discrete_input допустим только bool)",
+                var_type, modbus_type
+            ),
+            IssueKind::AddressOutOfRange { last_address } => write!(
+                f,
+                "значение занимает адреса до {}, что больше 65535",
+                last_address
+            ),
+            IssueKind::OneBasedOverflow => {
+                write!(f, "адрес 65535 нельзя записать в tags.csv с нумерацией с 1")
+            }
+            IssueKind::ZeroAddressOneBased => write!(f, "адрес 0 недопустим при нумерации с 1"),
+            IssueKind::Overlap { other_name, other_line } => write!(
+                f,
+                "адреса пересекаются с тегом '{}' (строка {})",
+                other_name, other_line
+            ),
+            IssueKind::Parse { field, message } => write!(f, "ошибка разбора ({}): {}", field, message),
+            IssueKind::ColumnCount { found, expected } => {
+                write!(f, "столбцов {}, ожидалось {}", found, expected)
+            }
+        }
+    }
+}
+
+impl fmt::Display for TagIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "строка {}, тег '{}': {}", self.line, self.name, self.kind)
+    }
+}
+
+/// Диапазон адресов, занимаемый тегом: (первый, последний)
+fn address_span(register: &RegisterConfig) -> (u32, u32) {
+    let first = u32::from(register.address);
+    let quantity = if register.modbus_type.is_bit_area() {
+        1
+    } else {
+        u32::from(register.var_type.quantity())
+    };
+    (first, first + quantity - 1)
+}
+
+/// Проверка списка тегов. Каждый тег передается с номером строки в файле.
+pub fn validate_registers(registers: &[(usize, RegisterConfig)]) -> Vec<TagIssue> {
+    let mut issues: Vec<TagIssue> = Vec::new();
+    let issue = |line: usize, register: &RegisterConfig, kind: IssueKind| TagIssue {
+        line,
+        name: register.name.clone(),
+        kind,
+    };
+
+    for (index, (line, register)) in registers.iter().enumerate() {
+        if register.name.trim().is_empty() {
+            issues.push(issue(*line, register, IssueKind::EmptyName));
+        } else if let Some((first_line, _)) = registers[..index]
+            .iter()
+            .find(|(_, other)| other.name == register.name)
+        {
+            issues.push(issue(*line, register, IssueKind::DuplicateName { first_line: *first_line }));
+        }
+
+        if !register.modbus_type.supports(register.var_type) {
+            issues.push(issue(
+                *line,
+                register,
+                IssueKind::InvalidCombination {
+                    var_type: register.var_type,
+                    modbus_type: register.modbus_type,
+                },
+            ));
+        }
+
+        let (first, last) = address_span(register);
+        if last > u32::from(u16::MAX) {
+            issues.push(issue(*line, register, IssueKind::AddressOutOfRange { last_address: last }));
+        }
+
+        // Пересечение с предыдущими тегами той же области
+        for (other_line, other) in &registers[..index] {
+            if other.modbus_type != register.modbus_type {
+                continue;
+            }
+            let (other_first, other_last) = address_span(other);
+            if first <= other_last && other_first <= last {
+                issues.push(issue(
+                    *line,
+                    register,
+                    IssueKind::Overlap {
+                        other_name: other.name.clone(),
+                        other_line: *other_line,
+                    },
+                ));
+            }
+        }
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Проверка тега перед добавлением или изменением: возвращаются только проблемы этого тега.
+/// `replace` - индекс изменяемого тега в `registers` (None - тег добавляется в конец),
+/// `one_based` - нумерация адресов в tags.csv, куда будет записан тег.
+pub fn validate_candidate(
+    registers: &[RegisterConfig],
+    replace: Option<usize>,
+    candidate: &RegisterConfig,
+    one_based: bool,
+) -> Vec<TagIssue> {
+    // Строки нумеруются как в tags.csv (заголовок - строка 1); кандидат проверяется последним,
+    // поэтому все его конфликты с другими тегами будут отнесены к нему
+    let line = replace.unwrap_or(registers.len()) + 2;
+    let mut numbered: Vec<(usize, RegisterConfig)> = registers
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| Some(*index) != replace)
+        .map(|(index, register)| (index + 2, register.clone()))
+        .collect();
+    numbered.push((usize::MAX, candidate.clone()));
+
+    let mut issues: Vec<TagIssue> = validate_registers(&numbered)
+        .into_iter()
+        .filter(|issue| issue.line == usize::MAX)
+        .map(|issue| TagIssue { line, ..issue })
+        .collect();
+    if candidate.address > max_file_offset(one_based) {
+        issues.push(TagIssue {
+            line,
+            name: candidate.name.clone(),
+            kind: IssueKind::OneBasedOverflow,
+        });
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str, address: u16, var_type: VarType, modbus_type: ModbusType) -> RegisterConfig {
+        RegisterConfig {
+            name: name.to_string(),
+            description: String::new(),
+            address,
+            var_type,
+            modbus_type,
+            enabled: true,
+            unit: String::new(),
+        }
+    }
+
+    fn numbered(registers: &[RegisterConfig]) -> Vec<(usize, RegisterConfig)> {
+        registers.iter().cloned().enumerate().map(|(index, register)| (index + 2, register)).collect()
+    }
+
+    #[test]
+    fn valid_list_has_no_issues() {
+        let registers = [
+            tag("a", 0, VarType::U32, ModbusType::HoldingRegister),
+            tag("b", 2, VarType::U16, ModbusType::HoldingRegister),
+            // Та же адресная область у другой таблицы Modbus - не пересечение
+            tag("c", 0, VarType::Float, ModbusType::InputRegister),
+            tag("d", 0, VarType::Bool, ModbusType::Coil),
+        ];
+        assert!(validate_registers(&numbered(&registers)).is_empty());
+    }
+
+    #[test]
+    fn reports_names_combinations_and_range() {
+        let registers = [
+            tag("a", 0, VarType::U16, ModbusType::HoldingRegister),
+            tag("a", 1, VarType::U16, ModbusType::HoldingRegister),
+            tag(" ", 2, VarType::U16, ModbusType::HoldingRegister),
+            tag("c", 0, VarType::U16, ModbusType::Coil),
+            tag("f", 65535, VarType::Float, ModbusType::HoldingRegister),
+        ];
+        let issues = validate_registers(&numbered(&registers));
+        assert_eq!(issues.len(), 4);
+        assert!(matches!(issues[0], TagIssue { line: 3, kind: IssueKind::DuplicateName { first_line: 2 }, .. }));
+        assert!(matches!(issues[1], TagIssue { line: 4, kind: IssueKind::EmptyName, .. }));
+        assert!(matches!(issues[2].kind, IssueKind::InvalidCombination { var_type: VarType::U16, modbus_type: ModbusType::Coil }));
+        assert!(matches!(issues[3], TagIssue { line: 6, kind: IssueKind::AddressOutOfRange { last_address: 65536 }, .. }));
+        assert_eq!(issues[2].kind.field(), "var_type");
+    }
+
+    #[test]
+    fn reports_overlap_with_earlier_tag() {
+        let registers = [
+            tag("wide", 10, VarType::U32, ModbusType::HoldingRegister),
+            tag("inner", 11, VarType::U16, ModbusType::HoldingRegister),
+            tag("next", 12, VarType::U16, ModbusType::HoldingRegister),
+        ];
+        let issues = validate_registers(&numbered(&registers));
+        assert_eq!(issues.len(), 1);
+        match &issues[0].kind {
+            IssueKind::Overlap { other_name, other_line } => {
+                assert_eq!((issues[0].line, other_name.as_str(), *other_line), (3, "wide", 2));
+            }
+            kind => panic!("unexpected issue {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn candidate_reports_only_its_own_issues() {
+        let registers = [
+            tag("a", 0, VarType::U16, ModbusType::HoldingRegister),
+            tag("a", 1, VarType::U16, ModbusType::HoldingRegister),
+        ];
+        // Существующий дубликат в списке не относится к новому тегу
+        let issues = validate_candidate(&registers, None, &tag("b", 5, VarType::U16, ModbusType::HoldingRegister), false);
+        assert!(issues.is_empty());
+
+        let issues = validate_candidate(&registers, None, &tag("b", 0, VarType::U32, ModbusType::HoldingRegister), false);
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|issue| issue.line == 4 && matches!(issue.kind, IssueKind::Overlap { .. })));
+    }
+
+    #[test]
+    fn candidate_replacing_itself_is_not_a_duplicate() {
+        let registers = [
+            tag("a", 0, VarType::U16, ModbusType::HoldingRegister),
+            tag("b", 1, VarType::U16, ModbusType::HoldingRegister),
+        ];
+        let edited = tag("b", 1, VarType::I16, ModbusType::HoldingRegister);
+        assert!(validate_candidate(&registers, Some(1), &edited, false).is_empty());
+
+        let renamed = tag("a", 1, VarType::U16, ModbusType::HoldingRegister);
+        let issues = validate_candidate(&registers, Some(1), &renamed, false);
+        assert!(matches!(issues[..], [TagIssue { line: 3, kind: IssueKind::DuplicateName { first_line: 2 }, .. }]));
+    }
+
+    #[test]
+    fn candidate_offset_65535_needs_zero_based_file() {
+        let last = tag("last", 65535, VarType::U16, ModbusType::HoldingRegister);
+        assert!(validate_candidate(&[], None, &last, false).is_empty());
+        let issues = validate_candidate(&[], None, &last, true);
+        assert!(matches!(issues[..], [TagIssue { kind: IssueKind::OneBasedOverflow, .. }]));
+        assert_eq!(issues[0].kind.field(), "address");
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::State;
+
+use modbus_core::{TagHistory, TrendBucket};
+
+/// Сколько хранится история, если не задано при запуске опроса
+pub const DEFAULT_RETENTION_MINUTES: u64 = 10;
+
+/// История значений, которую пополняет опрос
+pub struct HistoryState {
+    history: Mutex<TagHistory>,
+}
+
+impl Default for HistoryState {
+    fn default() -> Self {
+        HistoryState {
+            history: Mutex::new(history_for(DEFAULT_RETENTION_MINUTES, Duration::from_secs(1))),
+        }
+    }
+}
+
+/// История на `minutes` минут. Число точек тега ограничено по паузе между циклами опроса:
+/// за цикл добавляется не больше одной точки.
+fn history_for(minutes: u64, interval: Duration) -> TagHistory {
+    let retention = Duration::from_secs(minutes * 60);
+    let interval_ms = interval.as_millis().max(1);
+    let max_samples = usize::try_from(retention.as_millis() / interval_ms + 1).unwrap_or(usize::MAX);
+    TagHistory::new(retention, max_samples)
+}
+
+impl HistoryState {
+    /// Очистка истории перед новым опросом
+    pub fn reset(&self, minutes: u64, interval: Duration) {
+        *self.lock() = history_for(minutes, interval);
+    }
+
+    pub fn lock(&self) -> std::sync::MutexGuard<'_, TagHistory> {
+        self.history.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Запрос тренда за последние `window_secs` секунд
+#[derive(Deserialize)]
+pub struct TrendRequest {
+    /// Теги; пустой список - все теги с историей
+    #[serde(default)]
+    pub names: Vec<String>,
+    pub window_secs: u64,
+    /// Число интервалов прореживания (обычно ширина графика в точках)
+    pub buckets: usize,
+}
+
+/// Прореженный ряд значений тега
+#[derive(Serialize)]
+pub struct TrendSeries {
+    pub name: String,
+    pub buckets: Vec<TrendBucket>,
+}
+
+/// Тренды тегов: min/max/avg по интервалам за окно времени, заканчивающееся сейчас
+#[tauri::command]
+pub fn tag_trend(state: State<'_, HistoryState>, request: TrendRequest) -> Vec<TrendSeries> {
+    let mut history = state.lock();
+    let to_ms = chrono::Utc::now().timestamp_millis();
+    history.prune(to_ms);
+    let window_ms = i64::try_from(request.window_secs.saturating_mul(1000)).unwrap_or(i64::MAX);
+    let from_ms = to_ms.saturating_sub(window_ms);
+    let names = if request.names.is_empty() {
+        history.names()
+    } else {
+        request.names
+    };
+    names
+        .into_iter()
+        .map(|name| TrendSeries {
+            buckets: history.trend(&name, from_ms, to_ms, request.buckets),
+            name,
+        })
+        .collect()
+}
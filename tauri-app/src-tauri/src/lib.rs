@@ -1,11 +1,19 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
+use modbus_core::{list_ports, PortDescriptor};
 use serde::Serialize;
 
-mod scan_available_ports;
+mod history;
+mod polling;
+mod settings;
+mod tags;
+
+use history::HistoryState;
+use polling::PollingState;
+use tags::TagsState;
 
 #[derive(Serialize)]
 struct PortsResult {
-    ports: Vec<u8>,
+    ports: Vec<PortDescriptor>,
 }
 
 #[tauri::command]
@@ -14,19 +22,37 @@ fn greet(name: &str) -> String {
 }
 
 #[tauri::command]
-fn scan_available_ports_cmd() -> PortsResult {
-    let mut buffer: [u8; 10] = [0; 10];
-    let count = scan_available_ports::scan_available_ports(&mut buffer);
-    PortsResult {
-        ports: buffer[..count].to_vec(),
-    }
+fn scan_available_ports_cmd() -> Result<PortsResult, String> {
+    list_ports()
+        .map(|ports| PortsResult { ports })
+        .map_err(|e| format!("Ошибка сканирования портов: {}", e))
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet, scan_available_ports_cmd])
+        .manage(HistoryState::default())
+        .manage(PollingState::default())
+        .manage(TagsState::default())
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            scan_available_ports_cmd,
+            polling::start_polling,
+            polling::stop_polling,
+            polling::is_polling,
+            history::tag_trend,
+            settings::load_connection_settings,
+            settings::save_connection_settings,
+            settings::test_connection,
+            tags::list_tags,
+            tags::add_tag,
+            tags::update_tag,
+            tags::delete_tag,
+            tags::set_tag_enabled,
+            tags::sort_tags,
+            tags::validate_tag
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use modbus_core::port_binding::resolve_bound_port;
+use modbus_core::{spawn_poller, ConnectionSettings, PollEvent, PollOptions, PollerHandle, Quality, RegisterConfig, TagReading};
+
+use crate::history::{HistoryState, DEFAULT_RETENTION_MINUTES};
+
+/// Событие с результатами цикла опроса
+pub const POLL_CYCLE_EVENT: &str = "poll-cycle";
+/// Событие изменения состояния опроса (подключение, ошибка, остановка)
+pub const POLL_STATUS_EVENT: &str = "poll-status";
+
+/// Активный опрос приложения (одновременно опрашивается один порт)
+#[derive(Default)]
+pub struct PollingState {
+    handle: Mutex<Option<PollerHandle>>,
+}
+
+impl PollingState {
+    fn take(&self) -> Option<PollerHandle> {
+        self.handle.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+/// Параметры запуска опроса из интерфейса
+#[derive(Deserialize)]
+pub struct StartPollingRequest {
+    pub settings: ConnectionSettings,
+    pub tags: Vec<RegisterConfig>,
+    /// Пауза между циклами, мс
+    #[serde(default)]
+    pub interval_ms: Option<u64>,
+    /// Таймаут ответа на запрос, мс
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Сколько минут хранить историю значений для трендов
+    #[serde(default)]
+    pub history_minutes: Option<u64>,
+}
+
+/// Значение тега для интерфейса
+#[derive(Serialize, Clone)]
+pub struct TagSample {
+    pub index: usize,
+    pub name: String,
+    pub quality: Quality,
+    pub value: Option<String>,
+    pub number: Option<f64>,
+    pub raw: Vec<u16>,
+    pub error: Option<String>,
+    pub timestamp: String,
+}
+
+impl From<TagReading> for TagSample {
+    fn from(reading: TagReading) -> Self {
+        let quality = reading.quality();
+        let timestamp = reading.timestamp.to_rfc3339();
+        let (value, number, raw, error) = match reading.value {
+            Ok(value) => (Some(value.text), value.number, value.raw, None),
+            Err(e) => (None, None, Vec::new(), Some(e.to_string())),
+        };
+        TagSample {
+            index: reading.index,
+            name: reading.name,
+            quality,
+            value,
+            number,
+            raw,
+            error,
+            timestamp,
+        }
+    }
+}
+
+/// Результаты одного цикла опроса
+#[derive(Serialize, Clone)]
+pub struct PollCycle {
+    pub cycle: u64,
+    pub failed: usize,
+    pub samples: Vec<TagSample>,
+}
+
+/// Состояние опроса
+#[derive(Serialize, Clone)]
+#[serde(tag = "state", rename_all = "lowercase")]
+pub enum PollStatus {
+    Connected { port: String },
+    Error { message: String },
+    Stopped,
+}
+
+/// Пересылка событий опроса в интерфейс; значения отправляются одним событием на цикл
+/// и попадают в историю для трендов
+async fn forward_events(app: AppHandle, mut events: tokio::sync::mpsc::UnboundedReceiver<PollEvent>) {
+    let history = app.state::<HistoryState>();
+    let mut samples: Vec<TagSample> = Vec::new();
+    while let Some(event) = events.recv().await {
+        match event {
+            PollEvent::Connected { port } => {
+                let _ = app.emit(POLL_STATUS_EVENT, PollStatus::Connected { port });
+            }
+            PollEvent::Reading(reading) => {
+                history.lock().record(&reading);
+                samples.push(reading.into());
+            }
+            PollEvent::CycleComplete { cycle, failed } => {
+                let cycle = PollCycle {
+                    cycle,
+                    failed,
+                    samples: std::mem::take(&mut samples),
+                };
+                let _ = app.emit(POLL_CYCLE_EVENT, cycle);
+            }
+            PollEvent::ConnectFailed { port, error } => {
+                let message = format!("Ошибка открытия порта {}: {}", port, error);
+                let _ = app.emit(POLL_STATUS_EVENT, PollStatus::Error { message });
+            }
+            PollEvent::Stopped => {
+                let _ = app.emit(POLL_STATUS_EVENT, PollStatus::Stopped);
+            }
+        }
+    }
+}
+
+/// Запуск опроса; предыдущий опрос останавливается
+#[tauri::command]
+pub async fn start_polling(
+    app: AppHandle,
+    state: State<'_, PollingState>,
+    history: State<'_, HistoryState>,
+    request: StartPollingRequest,
+) -> Result<(), String> {
+    if let Some(previous) = state.take() {
+        previous.shutdown().await;
+    }
+
+    let registers: Vec<RegisterConfig> = request.tags.into_iter().filter(|tag| tag.enabled).collect();
+    if registers.is_empty() {
+        return Err("Нет активных тегов для опроса".to_string());
+    }
+
+    let mut settings = request.settings;
+    resolve_bound_port(&mut settings);
+
+    let defaults = PollOptions::default();
+    let options = PollOptions {
+        interval: request.interval_ms.map(Duration::from_millis).unwrap_or(defaults.interval),
+        timeout: request.timeout_ms.map(Duration::from_millis).unwrap_or(defaults.timeout),
+    };
+
+    history.reset(request.history_minutes.unwrap_or(DEFAULT_RETENTION_MINUTES), options.interval);
+
+    let (handle, events) = spawn_poller(settings, registers, options);
+    tauri::async_runtime::spawn(forward_events(app, events));
+    *state.handle.lock().unwrap_or_else(|e| e.into_inner()) = Some(handle);
+    Ok(())
+}
+
+/// Остановка опроса и закрытие порта
+#[tauri::command]
+pub async fn stop_polling(state: State<'_, PollingState>) -> Result<(), String> {
+    if let Some(handle) = state.take() {
+        handle.shutdown().await;
+    }
+    Ok(())
+}
+
+/// Идет ли опрос
+#[tauri::command]
+pub fn is_polling(state: State<'_, PollingState>) -> bool {
+    state
+        .handle
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .is_some_and(|handle| !handle.is_finished())
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::time::{Duration, Instant};
+
+use modbus_core::paths::settings_path;
+use modbus_core::port_binding::resolve_bound_port;
+use modbus_core::settings::{
+    check_line_settings, is_valid_slave_address, load_config, save_config, MAX_SLAVE_ADDRESS, MIN_SLAVE_ADDRESS,
+};
+use modbus_core::{ConnectionSettings, Poller, ReadError, RegisterConfig};
+
+use crate::polling::TagSample;
+
+/// Таймаут ответа при проверке связи, если не задан
+const DEFAULT_TEST_TIMEOUT_MS: u64 = 1000;
+
+/// Проверка настроек перед сохранением
+fn check_settings(settings: &ConnectionSettings) -> Result<(), String> {
+    if settings.port.trim().is_empty() {
+        return Err("Не указан порт".to_string());
+    }
+    if !is_valid_slave_address(settings.device_address) {
+        return Err(format!(
+            "Адрес устройства должен быть в диапазоне {}..{}",
+            MIN_SLAVE_ADDRESS, MAX_SLAVE_ADDRESS
+        ));
+    }
+    check_line_settings(settings)
+}
+
+/// Настройки подключения из connect_settings.json; None - файла еще нет
+#[tauri::command]
+pub fn load_connection_settings() -> Result<Option<ConnectionSettings>, String> {
+    match load_config(&settings_path()) {
+        Ok(config) => Ok(Some(config.connection)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Ошибка чтения настроек: {}", e)),
+    }
+}
+
+/// Сохранение настроек подключения; настройки интерфейса в файле сохраняются
+#[tauri::command]
+pub fn save_connection_settings(settings: ConnectionSettings) -> Result<(), String> {
+    check_settings(&settings)?;
+    let path = settings_path();
+    let ui = load_config(&path).map(|config| config.ui).unwrap_or_default();
+    save_config(&path, settings, ui).map_err(|e| format!("Ошибка сохранения настроек: {}", e))
+}
+
+/// Причина неудачной проверки связи
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ConnectionError {
+    /// Порт не открылся (занят, не существует, нет прав)
+    PortOpen { port: String, message: String },
+    /// Устройство не ответило
+    Timeout,
+    /// Устройство ответило исключением Modbus
+    Exception { code: u8, message: String },
+    /// Ошибка обмена (искаженный ответ, CRC)
+    Io { message: String },
+}
+
+impl From<&ReadError> for ConnectionError {
+    fn from(e: &ReadError) -> Self {
+        match e {
+            ReadError::Timeout => ConnectionError::Timeout,
+            ReadError::Exception(code) => ConnectionError::Exception {
+                code: *code,
+                message: e.to_string(),
+            },
+            ReadError::Crc(_) | ReadError::Malformed(_) => ConnectionError::Io { message: e.to_string() },
+            ReadError::Io(message) => ConnectionError::Io { message: message.clone() },
+        }
+    }
+}
+
+/// Результат проверки связи
+#[derive(Serialize)]
+pub struct ConnectionTestResult {
+    /// Фактический порт (с учетом привязки к адаптеру)
+    pub port: String,
+    /// Время от запроса до ответа, мс; None - порт не открылся
+    pub latency_ms: Option<f64>,
+    pub sample: Option<TagSample>,
+    pub error: Option<ConnectionError>,
+}
+
+/// Параметры проверки связи
+#[derive(Deserialize)]
+pub struct ConnectionTestRequest {
+    pub settings: ConnectionSettings,
+    /// Тег, который читается при проверке
+    pub tag: RegisterConfig,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+/// Однократное чтение тега с замером времени ответа
+#[tauri::command]
+pub async fn test_connection(request: ConnectionTestRequest) -> Result<ConnectionTestResult, String> {
+    check_settings(&request.settings)?;
+    let mut settings = request.settings;
+    resolve_bound_port(&mut settings);
+    let timeout = Duration::from_millis(request.timeout_ms.unwrap_or(DEFAULT_TEST_TIMEOUT_MS));
+
+    let mut poller = match Poller::connect(&settings, vec![request.tag], timeout).await {
+        Ok(poller) => poller,
+        Err(e) => {
+            return Ok(ConnectionTestResult {
+                error: Some(ConnectionError::PortOpen {
+                    port: settings.port.clone(),
+                    message: e.to_string(),
+                }),
+                port: settings.port,
+                latency_ms: None,
+                sample: None,
+            });
+        }
+    };
+
+    let started = Instant::now();
+    let reading = poller.read(0).await;
+    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+    let _ = poller.disconnect().await;
+
+    let error = reading.value.as_ref().err().map(ConnectionError::from);
+    Ok(ConnectionTestResult {
+        port: settings.port,
+        latency_ms: Some(latency_ms),
+        sample: error.is_none().then(|| reading.into()),
+        error,
+    })
+}
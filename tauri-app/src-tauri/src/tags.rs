@@ -0,0 +1,227 @@
+use serde::Serialize;
+use std::io;
+use std::sync::Mutex;
+use tauri::State;
+
+use modbus_core::paths::registers_path;
+use modbus_core::tags::{
+    file_is_one_based, load_registers_file, sort_registers, write_registers_csv, SortKey, TagsWriteError,
+};
+use modbus_core::tags_validation::{validate_candidate, validate_registers, IssueKind, TagIssue};
+use modbus_core::RegisterConfig;
+
+/// Блокировка tags.csv: команды читают и перезаписывают файл целиком
+#[derive(Default)]
+pub struct TagsState {
+    file: Mutex<()>,
+}
+
+/// Ошибка в поле тега для подсветки в форме
+#[derive(Serialize, Clone)]
+pub struct TagFieldError {
+    /// Номер тега в списке (с нуля)
+    pub index: usize,
+    pub name: String,
+    /// name, address или var_type
+    pub field: &'static str,
+    pub message: String,
+}
+
+/// Строка tags.csv с первым тегом (заголовок - строка 1), так же нумерует validate_candidate
+const FIRST_TAG_LINE: usize = 2;
+
+impl TagFieldError {
+    fn from_issue(issue: TagIssue) -> Self {
+        TagFieldError {
+            index: issue.line - FIRST_TAG_LINE,
+            name: issue.name,
+            field: issue.kind.field(),
+            message: issue_message(&issue.kind),
+        }
+    }
+}
+
+/// Текст проблемы для формы: вместо строк tags.csv указываются номера тегов в списке, как в `index`
+fn issue_message(kind: &IssueKind) -> String {
+    match kind {
+        IssueKind::DuplicateName { first_line } => {
+            format!("имя повторяется (впервые у тега №{})", first_line - FIRST_TAG_LINE)
+        }
+        IssueKind::Overlap { other_name, other_line } => format!(
+            "адреса пересекаются с тегом '{}' (№{})",
+            other_name,
+            other_line - FIRST_TAG_LINE
+        ),
+        _ => kind.to_string(),
+    }
+}
+
+/// Ошибка команды работы с тегами
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum TagsError {
+    /// Ошибка чтения или записи tags.csv
+    Io { message: String },
+    /// Тег не прошел проверку; список не изменен
+    Invalid { errors: Vec<TagFieldError> },
+    /// Нет тега с таким номером
+    NotFound { index: usize },
+}
+
+impl From<io::Error> for TagsError {
+    fn from(e: io::Error) -> Self {
+        TagsError::Io { message: e.to_string() }
+    }
+}
+
+impl From<TagsWriteError> for TagsError {
+    fn from(e: TagsWriteError) -> Self {
+        TagsError::Io { message: e.to_string() }
+    }
+}
+
+/// Список тегов с результатом проверки
+#[derive(Serialize)]
+pub struct TagList {
+    pub tags: Vec<RegisterConfig>,
+    /// Адреса в tags.csv нумеруются с единицы (в `tags` адреса всегда от нуля)
+    pub one_based: bool,
+    pub errors: Vec<TagFieldError>,
+}
+
+/// Проверка всего списка
+fn check_list(tags: &[RegisterConfig]) -> Vec<TagFieldError> {
+    let numbered: Vec<(usize, RegisterConfig)> = tags
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(index, tag)| (index + FIRST_TAG_LINE, tag))
+        .collect();
+    validate_registers(&numbered)
+        .into_iter()
+        .map(TagFieldError::from_issue)
+        .collect()
+}
+
+/// Проверка добавляемого (`index` = None) или изменяемого тега с учетом нумерации адресов tags.csv
+fn check_candidate(tags: &[RegisterConfig], index: Option<usize>, tag: &RegisterConfig) -> Vec<TagFieldError> {
+    let one_based = file_is_one_based(&registers_path());
+    validate_candidate(tags, index, tag, one_based)
+        .into_iter()
+        .map(TagFieldError::from_issue)
+        .collect()
+}
+
+fn read_tags(path: &str) -> Result<Vec<RegisterConfig>, TagsError> {
+    Ok(load_registers_file(path)?.registers)
+}
+
+/// Изменение списка тегов с сохранением в tags.csv (нумерация адресов файла сохраняется)
+fn modify_tags<F>(state: &TagsState, change: F) -> Result<TagList, TagsError>
+where
+    F: FnOnce(&mut Vec<RegisterConfig>) -> Result<(), TagsError>,
+{
+    let _guard = state.file.lock().unwrap_or_else(|e| e.into_inner());
+    let path = registers_path();
+    let mut tags = read_tags(&path)?;
+    change(&mut tags)?;
+    let one_based = file_is_one_based(&path);
+    write_registers_csv(&path, &tags, one_based)?;
+    Ok(TagList {
+        errors: check_list(&tags),
+        tags,
+        one_based,
+    })
+}
+
+fn ensure_index(tags: &[RegisterConfig], index: usize) -> Result<(), TagsError> {
+    if index < tags.len() {
+        Ok(())
+    } else {
+        Err(TagsError::NotFound { index })
+    }
+}
+
+/// Список тегов из tags.csv
+#[tauri::command]
+pub fn list_tags(state: State<'_, TagsState>) -> Result<TagList, TagsError> {
+    let _guard = state.file.lock().unwrap_or_else(|e| e.into_inner());
+    let path = registers_path();
+    let tags = read_tags(&path)?;
+    Ok(TagList {
+        errors: check_list(&tags),
+        one_based: file_is_one_based(&path),
+        tags,
+    })
+}
+
+/// Добавление тега в конец списка
+#[tauri::command]
+pub fn add_tag(state: State<'_, TagsState>, tag: RegisterConfig) -> Result<TagList, TagsError> {
+    modify_tags(&state, |tags| {
+        let errors = check_candidate(tags, None, &tag);
+        if !errors.is_empty() {
+            return Err(TagsError::Invalid { errors });
+        }
+        tags.push(tag);
+        Ok(())
+    })
+}
+
+/// Замена тега с номером `index`
+#[tauri::command]
+pub fn update_tag(state: State<'_, TagsState>, index: usize, tag: RegisterConfig) -> Result<TagList, TagsError> {
+    modify_tags(&state, |tags| {
+        ensure_index(tags, index)?;
+        let errors = check_candidate(tags, Some(index), &tag);
+        if !errors.is_empty() {
+            return Err(TagsError::Invalid { errors });
+        }
+        tags[index] = tag;
+        Ok(())
+    })
+}
+
+/// Удаление тега с номером `index`
+#[tauri::command]
+pub fn delete_tag(state: State<'_, TagsState>, index: usize) -> Result<TagList, TagsError> {
+    modify_tags(&state, |tags| {
+        ensure_index(tags, index)?;
+        tags.remove(index);
+        Ok(())
+    })
+}
+
+/// Включение или отключение опроса тега
+#[tauri::command]
+pub fn set_tag_enabled(state: State<'_, TagsState>, index: usize, enabled: bool) -> Result<TagList, TagsError> {
+    modify_tags(&state, |tags| {
+        ensure_index(tags, index)?;
+        tags[index].enabled = enabled;
+        Ok(())
+    })
+}
+
+/// Сортировка списка тегов с сохранением
+#[tauri::command]
+pub fn sort_tags(state: State<'_, TagsState>, key: SortKey) -> Result<TagList, TagsError> {
+    modify_tags(&state, |tags| {
+        sort_registers(tags, key);
+        Ok(())
+    })
+}
+
+/// Проверка тега из формы без сохранения (`index` - номер изменяемого тега, None - новый тег)
+#[tauri::command]
+pub fn validate_tag(
+    state: State<'_, TagsState>,
+    index: Option<usize>,
+    tag: RegisterConfig,
+) -> Result<Vec<TagFieldError>, TagsError> {
+    let _guard = state.file.lock().unwrap_or_else(|e| e.into_inner());
+    let tags = read_tags(&registers_path())?;
+    if let Some(index) = index {
+        ensure_index(&tags, index)?;
+    }
+    Ok(check_candidate(&tags, index, &tag))
+}
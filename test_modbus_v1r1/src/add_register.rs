@@ -1,8 +1,16 @@
 use colored::*;
 use std::io::{self, Write};
 
+use modbus_core::register_types::{ModbusType, VarType};
+use modbus_core::tags::file_is_one_based;
+use modbus_core::tags_validation::validate_candidate;
+
+use crate::address_notation::read_address;
+use crate::i18n::{fill, msg};
 use crate::{
 	clear_screen,
+	get_registers_path,
+	print_issues,
 	wait_for_continue,
 	load_registers_or_warn,
 	save_registers_to_csv,
@@ -11,95 +19,90 @@ use crate::{
 
 /// Добавление нового регистра (интерактивно)
 pub fn add_register() -> io::Result<()> {
+	let m = msg();
 	clear_screen();
-	println!("{}", "=== Добавление регистра ===".cyan().bold());
+	println!("{}", m.add_register_title.cyan().bold());
 
 	// Имя
-	print!("{} ", "Какое имя регистра? (name):".yellow());
+	print!("{} ", m.prompt_name.yellow());
 	io::stdout().flush()?;
 	let mut name = String::new();
 	io::stdin().read_line(&mut name)?;
 	let name = name.trim().to_string();
 	if name.is_empty() {
-		println!("{}", "Имя не может быть пустым".red());
+		println!("{}", m.name_empty.red());
 		wait_for_continue()?;
 		return Ok(());
 	}
 
 	// Описание
-	print!("{} ", "Описание? (description):".yellow());
+	print!("{} ", m.prompt_description.yellow());
 	io::stdout().flush()?;
 	let mut description = String::new();
 	io::stdin().read_line(&mut description)?;
 	let description = description.trim().to_string();
 
-	// Адрес
-	print!("{} ", "Адрес в десятичном формате? (address):".yellow());
+	// Единица измерения
+	print!("{} ", m.prompt_unit.yellow());
 	io::stdout().flush()?;
-	let mut address_str = String::new();
-	io::stdin().read_line(&mut address_str)?;
-	let address = match address_str.trim().parse::<u16>() {
-		Ok(v) => v,
-		Err(_) => {
-			println!("{}", "Неверный адрес. Ожидалось число 0..65535".red());
-			wait_for_continue()?;
-			return Ok(());
-		}
+	let mut unit = String::new();
+	io::stdin().read_line(&mut unit)?;
+	let unit = unit.trim().to_string();
+
+	// Адрес: десятичный, 0x... или Modicon (тогда область берется из адреса)
+	let one_based = file_is_one_based(&get_registers_path());
+	let Some(address) = read_address(m.prompt_address, one_based, None)? else {
+		wait_for_continue()?;
+		return Ok(());
 	};
 
 	// Тип переменной
-	println!("{}", "Тип переменной? (var_type)".yellow());
-	println!("  1. bool");
-	println!("  2. u16");
-	println!("  3. i16");
-	println!("  4. u32");
-	println!("  5. i32");
-	println!("  6. float");
-	print!("Введите номер (1-6): ");
+	println!("{}", m.prompt_var_type.yellow());
+	for (index, var_type) in VarType::ALL.iter().enumerate() {
+		println!("  {}. {}", index + 1, var_type);
+	}
+	print!("{}", fill(m.enter_number_range, &[&VarType::ALL.len()]));
 	io::stdout().flush()?;
 	let mut var_choice = String::new();
 	io::stdin().read_line(&mut var_choice)?;
-	let var_type = match var_choice.trim() {
-		"1" => "bool".to_string(),
-		"2" => "u16".to_string(),
-		"3" => "i16".to_string(),
-		"4" => "u32".to_string(),
-		"5" => "i32".to_string(),
-		"6" => "float".to_string(),
+	let var_type = match var_choice.trim().parse::<usize>() {
+		Ok(n) if (1..=VarType::ALL.len()).contains(&n) => VarType::ALL[n - 1],
 		_ => {
-			println!("{}", "Неверный выбор var_type".red());
+			println!("{}", m.invalid_var_type.red());
 			wait_for_continue()?;
 			return Ok(());
 		}
 	};
 
     // Тип Modbus регистра
-    println!("{}", "Тип Modbus регистра? (modbus_type)".yellow());
-    println!("  1. input_register (ф-ция чтения 0x04)");
-    println!("  2. holding_register (ф-ция чтения 0x03)");
-    println!("  3. coil (ф-ция чтения 0x01)");
-    println!("  4. discrete_input (ф-ция чтения 0x02)");
-    print!("Введите номер (1-4): ");
-    io::stdout().flush()?;
-    let mut modbus_choice = String::new();
-    io::stdin().read_line(&mut modbus_choice)?;
-    let modbus_type = match modbus_choice.trim() {
-        "1" => "input_register".to_string(),
-        "2" => "holding_register".to_string(),
-        "3" => "coil".to_string(),
-        "4" => "discrete_input".to_string(),
-        _ => {
-            println!("{}", "Неверный выбор modbus_type".red());
-            wait_for_continue()?;
-            return Ok(());
+    let modbus_type = if let Some(area) = address.area {
+        println!("{} {}", m.modbus_type_from_modicon.yellow(), area);
+        area
+    } else {
+        println!("{}", m.prompt_modbus_type.yellow());
+        for (index, modbus_type) in ModbusType::ALL.iter().enumerate() {
+            let read_function = format!("{:02X}", modbus_type.read_function());
+            println!("  {}. {} ({})", index + 1, modbus_type, fill(m.read_function, &[&read_function]));
+        }
+        print!("{}", fill(m.enter_number_range, &[&ModbusType::ALL.len()]));
+        io::stdout().flush()?;
+        let mut modbus_choice = String::new();
+        io::stdin().read_line(&mut modbus_choice)?;
+        match modbus_choice.trim().parse::<usize>() {
+            Ok(n) if (1..=ModbusType::ALL.len()).contains(&n) => ModbusType::ALL[n - 1],
+            _ => {
+                println!("{}", m.invalid_modbus_type.red());
+                wait_for_continue()?;
+                return Ok(());
+            }
         }
     };
 
 	// enabled
-	println!("{}", "Разрешено ли запрашивать этот регистр? (enabled)".yellow());
-	println!("  1. Да (True)");
-	println!("  2. Нет (False)");
-	print!("Введите номер (1-2): ");
+	println!("{}", m.prompt_enabled.yellow());
+	println!("  1. {}", m.enabled_yes);
+	println!("  2. {}", m.enabled_no);
+	print!("{}", fill(m.enter_number_range, &[&2]));
 	io::stdout().flush()?;
 	let mut enabled_choice = String::new();
 	io::stdin().read_line(&mut enabled_choice)?;
@@ -107,7 +110,7 @@ pub fn add_register() -> io::Result<()> {
 		"1" | "да" | "y" | "yes" | "true" => true,
 		"2" | "нет" | "n" | "no" | "false" => false,
 		_ => {
-			println!("{}", "Неверный выбор для enabled".red());
+			println!("{}", m.invalid_enabled.red());
 			wait_for_continue()?;
 			return Ok(());
 		}
@@ -125,14 +128,22 @@ pub fn add_register() -> io::Result<()> {
 	let new_reg = RegisterConfig {
 		name,
 		description,
-		address,
+		address: address.offset,
 		var_type,
 		modbus_type,
 		enabled,
+		unit,
 	};
+	let issues = validate_candidate(&cfg.registers, None, &new_reg, one_based);
+	if !issues.is_empty() {
+		print_issues(&issues);
+		println!("{}", m.register_not_added.red());
+		wait_for_continue()?;
+		return Ok(());
+	}
 	cfg.registers.push(new_reg);
 	save_registers_to_csv(&cfg.registers)?;
-	println!("{}", "Регистр добавлен".green());
+	println!("{}", m.register_added.green());
 	wait_for_continue()?;
 	Ok(())
 }
@@ -0,0 +1,75 @@
+use colored::*;
+use std::io::{self, Write};
+
+use modbus_core::address::{max_file_offset, parse_plain_address, AddressNotation, ParsedAddress};
+use modbus_core::register_types::{parse_modicon_reference, ModbusType};
+
+use crate::i18n::{fill, msg};
+
+/// Ввод адреса в десятичной, шестнадцатеричной записи или в записи Modicon.
+/// None - адрес не распознан (сообщение уже выведено), пустой ввод возвращает `current`.
+pub fn read_address(prompt: &str, one_based: bool, current: Option<(u16, ModbusType)>) -> io::Result<Option<ParsedAddress>> {
+    let m = msg();
+    let base_hint = if one_based { m.address_numbering_from_one } else { m.address_numbering_from_zero };
+    let hint = fill(m.address_input_hint, &[&prompt, &base_hint]);
+    match current {
+        Some((offset, modbus_type)) => print!(
+            "{} [{}]: ",
+            hint.yellow(),
+            AddressNotation::Decimal.format(offset, modbus_type, one_based).bright_white()
+        ),
+        None => print!("{} ", format!("{}:", hint).yellow()),
+    }
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let text = input.trim();
+
+    if text.is_empty()
+        && let Some((offset, _)) = current
+    {
+        return Ok(Some(ParsedAddress { offset, area: None }));
+    }
+
+    // 5-6 цифр с первой цифрой 0/1/3/4 могут быть и обычным адресом - уточняем у пользователя
+    if let Some((area, offset)) = parse_modicon_reference(text) {
+        let plain = parse_plain_address(text, one_based);
+        let as_modicon = match plain {
+            None => true,
+            Some(_) => {
+                print!(
+                    "{} ",
+                    fill(m.modicon_address_confirm, &[&text, &area, &offset]).yellow()
+                );
+                io::stdout().flush()?;
+                let mut answer = String::new();
+                io::stdin().read_line(&mut answer)?;
+                !matches!(answer.trim().to_lowercase().as_str(), "n" | "no" | "нет" | "2")
+            }
+        };
+        if as_modicon {
+            return checked_offset(ParsedAddress { offset, area: Some(area) }, one_based);
+        }
+    }
+
+    match parse_plain_address(text, one_based) {
+        Some(offset) => checked_offset(ParsedAddress { offset, area: None }, one_based),
+        None => {
+            let range = if one_based { "1..65535" } else { "0..65535" };
+            println!(
+                "{}",
+                fill(m.invalid_address_input, &[&range]).red()
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Смещение должно записываться в tags.csv с текущей нумерацией
+fn checked_offset(address: ParsedAddress, one_based: bool) -> io::Result<Option<ParsedAddress>> {
+    if address.offset > max_file_offset(one_based) {
+        println!("{}", msg().offset_65535_one_based.red());
+        return Ok(None);
+    }
+    Ok(Some(address))
+}
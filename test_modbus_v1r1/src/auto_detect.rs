@@ -0,0 +1,170 @@
+use colored::*;
+use std::io::{self, Write};
+use std::time::Duration;
+use tokio_modbus::prelude::*;
+
+use crate::bus_scan::{probe, read_probe_function, ProbeResponse};
+use crate::i18n::{exception_title, fill, msg};
+use crate::poll_session::{self, InterruptScope};
+use crate::port_binding::resolve_bound_port;
+use crate::{
+	clear_screen,
+	connect_rtu,
+	load_settings,
+	read_value_or_default,
+	save_settings,
+	ConnectionSettings,
+	AVAILABLE_BAUD_RATES,
+	PARITY_OPTIONS,
+};
+
+/// Количество попыток запроса на каждой комбинации параметров
+const ATTEMPTS_PER_COMBINATION: usize = 2;
+
+/// Комбинация параметров линии, на которой устройство ответило
+struct DetectedFraming {
+	baud_rate: u32,
+	parity: &'static str,
+	stop_bits: u8,
+	response: ProbeResponse,
+}
+
+/// Автоопределение скорости, четности и стоп-битов для известного адреса устройства (интерактивно)
+pub async fn auto_detect_line_settings() -> io::Result<()> {
+	let m = msg();
+	clear_screen();
+	println!("{}", m.auto_detect_title.cyan().bold());
+
+	let mut config = match load_settings() {
+		Ok(config) => config,
+		Err(e) => {
+			eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+			println!("{}", m.save_settings_first.yellow());
+			return Ok(());
+		}
+	};
+
+	let mut connection = config.connection.clone();
+	resolve_bound_port(&mut connection);
+	println!("{}", fill(m.port_value, &[&connection.port.bright_white()]));
+	let slave = read_value_or_default::<u8>(m.prompt_device_address, config.connection.device_address)?;
+	let Some(function) = read_probe_function()? else {
+		return Ok(());
+	};
+	let address = read_value_or_default::<u16>(m.probe_register_address, 0)?;
+	let timeout_ms = read_value_or_default::<u64>(m.response_timeout_prompt, 200)?;
+	let timeout = Duration::from_millis(timeout_ms);
+
+	// Перебор от большей скорости к меньшей, как в меню выбора скорости
+	let mut baud_rates = AVAILABLE_BAUD_RATES;
+	baud_rates.reverse();
+	let parities = [PARITY_OPTIONS.0, PARITY_OPTIONS.1, PARITY_OPTIONS.2];
+	let stop_bits_options: [u8; 2] = [1, 2];
+
+	println!(
+		"\n{}",
+		fill(m.auto_detect_started, &[&(baud_rates.len() * parities.len() * stop_bits_options.len()), &slave]).cyan()
+	);
+
+	let mut detected: Vec<DetectedFraming> = Vec::new();
+	let scope = InterruptScope::enter();
+
+	'search: for &baud_rate in &baud_rates {
+		for &parity in &parities {
+			for &stop_bits in &stop_bits_options {
+				if poll_session::stop_requested() {
+					println!("\n{}", m.auto_detect_stopped.yellow());
+					break 'search;
+				}
+				let framing = fill(m.framing, &[&format!("{:>6}", baud_rate), &format!("{:<4}", parity), &stop_bits]);
+				print!("\r  {}...   ", framing);
+				io::stdout().flush()?;
+
+				let candidate = ConnectionSettings {
+					baud_rate,
+					parity: parity.to_string(),
+					stop_bits,
+					..connection.clone()
+				};
+				let mut ctx = match connect_rtu(&candidate).await {
+					Ok(ctx) => ctx,
+					Err(e) => {
+						println!();
+						eprintln!("{}", fill(m.port_open_error, &[&candidate.port, &e]).red());
+						break 'search;
+					}
+				};
+				ctx.set_slave(Slave(slave));
+
+				for _ in 0..ATTEMPTS_PER_COMBINATION {
+					// Любой ответ с верной CRC (данные или исключение) подтверждает параметры линии
+					if let Some(response) = probe(&mut ctx, function, address, timeout).await {
+						println!(
+							"\r  {} {}",
+							m.response_received.green(),
+							fill(m.framing, &[&baud_rate, &parity, &stop_bits])
+						);
+						detected.push(DetectedFraming {
+							baud_rate,
+							parity,
+							stop_bits,
+							response,
+						});
+						break;
+					}
+				}
+				let _ = ctx.disconnect().await;
+			}
+		}
+	}
+	drop(scope);
+	println!();
+
+	if detected.is_empty() {
+		println!("{}", m.no_framing_found.red());
+		println!("{}", m.check_address_line_hint.yellow());
+		return Ok(());
+	}
+
+	println!("\n{}", m.matching_framings.yellow());
+	for (index, item) in detected.iter().enumerate() {
+		let response = match &item.response {
+			ProbeResponse::Data(value) => fill(m.probe_data, &[value]),
+			ProbeResponse::Exception(code) => {
+				fill(m.read_exception, &[&format!("0x{:02X}", code), &exception_title(*code)])
+			}
+		};
+		let framing = fill(
+			m.framing,
+			&[
+				&item.baud_rate.to_string().cyan(),
+				&item.parity.cyan(),
+				&item.stop_bits.to_string().cyan(),
+			],
+		);
+		println!("  {}. {} - {}", index + 1, framing, response);
+	}
+	if detected.len() > 1 {
+		println!("{}", m.stop_bits_ambiguous.bright_black());
+	}
+
+	let choice = read_value_or_default::<usize>(&fill(m.save_framing_prompt, &[&detected.len()]), 1)?;
+	if choice == 0 {
+		return Ok(());
+	}
+	let Some(item) = detected.get(choice - 1) else {
+		println!("{}", m.out_of_range_unchanged.yellow());
+		return Ok(());
+	};
+
+	config.connection.device_address = slave;
+	config.connection.baud_rate = item.baud_rate;
+	config.connection.parity = item.parity.to_string();
+	config.connection.stop_bits = item.stop_bits;
+	match save_settings(config.connection) {
+		Ok(()) => println!("{}", m.framing_saved.green()),
+		Err(e) => eprintln!("{}", fill(m.settings_save_error, &[&e]).red()),
+	}
+
+	Ok(())
+}
@@ -0,0 +1,224 @@
+use colored::*;
+use std::io::{self, Write};
+use std::time::Duration;
+use tokio_modbus::prelude::*;
+
+use modbus_core::modbus_exception::exception_code;
+use modbus_core::settings::{MAX_SLAVE_ADDRESS, MIN_SLAVE_ADDRESS};
+
+use crate::i18n::{exception_title, fill, msg};
+use crate::poll_session::{self, InterruptScope};
+use crate::port_binding::resolve_bound_port;
+use crate::{
+	clear_screen,
+	load_settings,
+	open_modbus_context,
+	read_value_or_default,
+	save_settings,
+};
+
+/// Функция Modbus, которой опрашивается каждый адрес при сканировании
+#[derive(Clone, Copy)]
+pub enum ProbeFunction {
+	Coils,
+	DiscreteInputs,
+	HoldingRegisters,
+	InputRegisters,
+}
+
+impl ProbeFunction {
+	pub fn name(self) -> &'static str {
+		match self {
+			ProbeFunction::Coils => "0x01 Read Coils",
+			ProbeFunction::DiscreteInputs => "0x02 Read Discrete Inputs",
+			ProbeFunction::HoldingRegisters => "0x03 Read Holding Registers",
+			ProbeFunction::InputRegisters => "0x04 Read Input Registers",
+		}
+	}
+}
+
+/// Ответ устройства на пробный запрос
+pub enum ProbeResponse {
+	Data(String),
+	Exception(u8),
+}
+
+/// Устройство, ответившее при сканировании
+struct ScanHit {
+	slave: u8,
+	response: ProbeResponse,
+}
+
+/// Пробный запрос к текущему ведомому устройству.
+/// None - устройство не ответило (таймаут или ошибка связи).
+pub async fn probe(
+	ctx: &mut client::Context,
+	function: ProbeFunction,
+	address: u16,
+	timeout: Duration,
+) -> Option<ProbeResponse> {
+	let result = match function {
+		ProbeFunction::Coils => tokio::time::timeout(timeout, ctx.read_coils(address, 1))
+			.await
+			.map(|r| r.map(|v| format!("{:?}", v))),
+		ProbeFunction::DiscreteInputs => tokio::time::timeout(timeout, ctx.read_discrete_inputs(address, 1))
+			.await
+			.map(|r| r.map(|v| format!("{:?}", v))),
+		ProbeFunction::HoldingRegisters => tokio::time::timeout(timeout, ctx.read_holding_registers(address, 1))
+			.await
+			.map(|r| r.map(|v| format!("{:?}", v))),
+		ProbeFunction::InputRegisters => tokio::time::timeout(timeout, ctx.read_input_registers(address, 1))
+			.await
+			.map(|r| r.map(|v| format!("{:?}", v))),
+	};
+
+	match result {
+		Ok(Ok(value)) => Some(ProbeResponse::Data(value)),
+		// Исключение означает, что устройство с этим адресом есть на линии
+		Ok(Err(e)) => exception_code(&e).map(ProbeResponse::Exception),
+		Err(_) => None,
+	}
+}
+
+/// Выбор функции пробного запроса (по умолчанию 0x03).
+/// None - пользователь ввел неверный номер.
+pub fn read_probe_function() -> io::Result<Option<ProbeFunction>> {
+	let m = msg();
+	println!("\n{}", m.probe_function_title.yellow());
+	println!("  1. {}", ProbeFunction::Coils.name());
+	println!("  2. {}", ProbeFunction::DiscreteInputs.name());
+	println!("  3. {}", ProbeFunction::HoldingRegisters.name());
+	println!("  4. {}", ProbeFunction::InputRegisters.name());
+	let function = match read_value_or_default::<u8>(&fill(m.choose_number, &[&4]), 3)? {
+		1 => Some(ProbeFunction::Coils),
+		2 => Some(ProbeFunction::DiscreteInputs),
+		3 => Some(ProbeFunction::HoldingRegisters),
+		4 => Some(ProbeFunction::InputRegisters),
+		_ => {
+			println!("{}", m.invalid_probe_function.red());
+			None
+		}
+	};
+	Ok(function)
+}
+
+/// Сканирование шины RS-485: поиск адресов ведомых устройств (интерактивно)
+pub async fn scan_bus() -> io::Result<()> {
+	let m = msg();
+	clear_screen();
+	println!("{}", m.bus_scan_title.cyan().bold());
+
+	let mut config = match load_settings() {
+		Ok(config) => config,
+		Err(e) => {
+			eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+			println!("{}", m.save_settings_first.yellow());
+			return Ok(());
+		}
+	};
+
+	let mut conn = config.connection.clone();
+	resolve_bound_port(&mut conn);
+	println!(
+		"{}",
+		fill(
+			m.line_summary_parity,
+			&[
+				&conn.port.bright_white(),
+				&conn.baud_rate.to_string().bright_white(),
+				&conn.parity.bright_white(),
+				&conn.stop_bits.to_string().bright_white(),
+			]
+		)
+	);
+
+	// Параметры пробного запроса
+	let Some(function) = read_probe_function()? else {
+		return Ok(());
+	};
+	let address = read_value_or_default::<u16>(m.probe_register_address, 0)?;
+	let timeout_ms = read_value_or_default::<u64>(m.response_timeout_prompt, 100)?;
+	let first = read_value_or_default::<u8>(m.first_slave_prompt, MIN_SLAVE_ADDRESS)?;
+	let last = read_value_or_default::<u8>(m.last_slave_prompt, MAX_SLAVE_ADDRESS)?;
+	if first < MIN_SLAVE_ADDRESS || last > MAX_SLAVE_ADDRESS || first > last {
+		println!("{}", fill(m.invalid_slave_range, &[&MIN_SLAVE_ADDRESS, &MAX_SLAVE_ADDRESS]).red());
+		return Ok(());
+	}
+
+	println!();
+	let mut ctx = open_modbus_context(&conn).await?;
+	println!(
+		"{}",
+		fill(m.bus_scan_started, &[&first, &last, &function.name()]).cyan()
+	);
+
+	let timeout = Duration::from_millis(timeout_ms);
+	let mut hits: Vec<ScanHit> = Vec::new();
+	let scope = InterruptScope::enter();
+
+	for slave in first..=last {
+		if poll_session::stop_requested() {
+			println!("\n{}", m.scan_stopped.yellow());
+			break;
+		}
+		print!("\r  {}", fill(m.scan_progress, &[&format!("{:>3}", slave)]));
+		io::stdout().flush()?;
+
+		ctx.set_slave(Slave(slave));
+		if let Some(response) = probe(&mut ctx, function, address, timeout).await {
+			match &response {
+				ProbeResponse::Data(value) => {
+					println!("\r  {} {:>3}: {}", m.response_from.green(), slave, value.bright_white());
+				}
+				ProbeResponse::Exception(code) => {
+					println!(
+						"\r  {} {:>3}: {}",
+						m.response_from.green(),
+						slave,
+						fill(m.read_exception, &[&format!("0x{:02X}", code), &exception_title(*code).yellow()])
+					);
+				}
+			}
+			hits.push(ScanHit { slave, response });
+		}
+	}
+	drop(scope);
+	println!();
+
+	if let Err(e) = ctx.disconnect().await {
+		eprintln!("{}", fill(m.port_close_error, &[&conn.port, &e]).red());
+	}
+	drop(ctx);
+
+	if hits.is_empty() {
+		println!("{}", m.no_device_responded.red());
+		println!("{}", m.check_line_hint.yellow());
+		return Ok(());
+	}
+
+	println!("\n{}", m.found_devices.yellow());
+	for (index, hit) in hits.iter().enumerate() {
+		let response = match &hit.response {
+			ProbeResponse::Data(value) => fill(m.probe_data, &[value]),
+			ProbeResponse::Exception(code) => fill(m.probe_exception, &[&format!("0x{:02X}", code)]),
+		};
+		println!("  {}. {} {} - {}", index + 1, m.word_address, hit.slave.to_string().cyan(), response);
+	}
+
+	let choice = read_value_or_default::<usize>(&fill(m.save_address_prompt, &[&hits.len()]), 0)?;
+	if choice == 0 {
+		return Ok(());
+	}
+	let Some(hit) = hits.get(choice - 1) else {
+		println!("{}", m.out_of_range_unchanged.yellow());
+		return Ok(());
+	};
+
+	config.connection.device_address = hit.slave;
+	match save_settings(config.connection) {
+		Ok(()) => println!("{}", fill(m.device_address_saved, &[&hit.slave]).green()),
+		Err(e) => eprintln!("{}", fill(m.settings_save_error, &[&e]).red()),
+	}
+
+	Ok(())
+}
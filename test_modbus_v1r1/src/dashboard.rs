@@ -0,0 +1,714 @@
+use colored::*;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph, Row, Table, TableState};
+use ratatui::{Frame, Terminal};
+use std::io;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use modbus_core::{spawn_poller, ModbusType, PollEvent, PollOptions, PollerHandle, Quality, TagHistory, VarType};
+
+use crate::i18n::{fill, msg, read_error_text, write_error_text};
+use crate::poll_session::{self, PollSession};
+use crate::port_binding::resolve_bound_port;
+use crate::{clear_screen, load_registers, load_settings, ConnectionSettings, RegisterConfig};
+
+/// Как часто перерисовывается экран и проверяется клавиатура
+const REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Число последних значений в мини-графике строки
+const SPARKLINE_SAMPLES: usize = 16;
+
+/// Уровни мини-графика от минимума к максимуму
+const SPARKLINE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Окна большого графика, минуты; история хранится за самое длинное
+const CHART_WINDOWS: [u64; 4] = [1, 5, 15, 60];
+
+/// Восстановление терминала при выходе из полноэкранного режима, в том числе при ошибке
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        if let Err(e) = execute!(io::stdout(), EnterAlternateScreen) {
+            let _ = disable_raw_mode();
+            return Err(e);
+        }
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Строка ввода внизу экрана
+enum InputMode {
+    Normal,
+    Filter(String),
+    Write { index: usize, value: String },
+}
+
+/// Что сделать после нажатия клавиши
+enum Action {
+    None,
+    Quit,
+    Pause(bool),
+    Write { index: usize, value: String },
+}
+
+/// Состояние тега на экране
+struct TagRow {
+    register: RegisterConfig,
+    quality: Option<Quality>,
+    error: Option<String>,
+}
+
+/// Полноэкранный просмотр опроса
+struct Dashboard {
+    conn: ConnectionSettings,
+    rows: Vec<TagRow>,
+    session: PollSession,
+    table: TableState,
+    input: InputMode,
+    filter: String,
+    paused: bool,
+    connected: bool,
+    stopped: bool,
+    /// Сообщение в нижней строке и его цвет
+    message: Option<(String, Color)>,
+    last_cycle_at: Option<Instant>,
+    cycle_period: Option<Duration>,
+    /// Числовые значения тегов для мини-графиков и большого графика
+    history: TagHistory,
+    /// Открыт большой график выбранного тега
+    chart_open: bool,
+    /// Номер окна в CHART_WINDOWS
+    chart_window: usize,
+}
+
+impl Dashboard {
+    fn new(conn: ConnectionSettings, registers: &[RegisterConfig], interval: Duration) -> Self {
+        let enabled: Vec<&RegisterConfig> = registers.iter().collect();
+        // За цикл добавляется не больше одной точки тега
+        let retention = Duration::from_secs(CHART_WINDOWS[CHART_WINDOWS.len() - 1] * 60);
+        let max_samples = (retention.as_millis() / interval.as_millis().max(1)) as usize + 1;
+        let mut table = TableState::default();
+        table.select(Some(0));
+        Dashboard {
+            conn,
+            rows: registers
+                .iter()
+                .map(|register| TagRow {
+                    register: register.clone(),
+                    quality: None,
+                    error: None,
+                })
+                .collect(),
+            session: PollSession::start(&enabled),
+            table,
+            input: InputMode::Normal,
+            filter: String::new(),
+            paused: false,
+            connected: false,
+            stopped: false,
+            message: None,
+            last_cycle_at: None,
+            cycle_period: None,
+            history: TagHistory::new(retention, max_samples),
+            chart_open: false,
+            chart_window: 1,
+        }
+    }
+
+    /// Номера тегов, подходящих под фильтр (по имени или описанию)
+    fn visible(&self) -> Vec<usize> {
+        let filter = match &self.input {
+            InputMode::Filter(text) => text.to_lowercase(),
+            _ => self.filter.to_lowercase(),
+        };
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                filter.is_empty()
+                    || row.register.name.to_lowercase().contains(&filter)
+                    || row.register.description.to_lowercase().contains(&filter)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Номер выбранного тега в полном списке
+    fn selected(&self) -> Option<usize> {
+        let visible = self.visible();
+        self.table.selected().and_then(|position| visible.get(position).copied())
+    }
+
+    fn apply(&mut self, event: PollEvent) {
+        match event {
+            PollEvent::Connected { port } => {
+                self.connected = true;
+                self.message = Some((fill(msg().dashboard_polling_started, &[&port]), Color::Green));
+            }
+            PollEvent::Reading(reading) => {
+                let stats = &mut self.session.tags[reading.index];
+                let row = &mut self.rows[reading.index];
+                row.quality = Some(reading.quality());
+                self.history.record(&reading);
+                match &reading.value {
+                    Ok(value) => {
+                        stats.last_value = Some(value.text.clone());
+                        stats.last_update = Some(reading.timestamp);
+                        row.error = None;
+                    }
+                    Err(e) => {
+                        stats.errors += 1;
+                        row.error = Some(read_error_text(e));
+                    }
+                }
+            }
+            PollEvent::CycleComplete { cycle, failed } => {
+                self.session.cycles = cycle;
+                if failed > 0 {
+                    self.session.failed_cycles += 1;
+                }
+                let now = Instant::now();
+                self.cycle_period = self.last_cycle_at.map(|previous| now - previous);
+                self.last_cycle_at = Some(now);
+                // История тегов, которые перестали читаться, тоже устаревает
+                self.history.prune(chrono::Local::now().timestamp_millis());
+            }
+            PollEvent::ConnectFailed { port, error } => {
+                self.message = Some((fill(msg().port_open_error, &[&port, &error]), Color::Red));
+            }
+            PollEvent::Stopped => self.stopped = true,
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let count = self.visible().len();
+        if count == 0 {
+            self.table.select(None);
+            return;
+        }
+        let current = self.table.selected().unwrap_or(0) as isize;
+        let next = (current + delta).clamp(0, count as isize - 1);
+        self.table.select(Some(next as usize));
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Action::Quit;
+        }
+        match &mut self.input {
+            InputMode::Filter(text) => match key.code {
+                KeyCode::Enter => {
+                    self.filter = std::mem::take(text);
+                    self.input = InputMode::Normal;
+                }
+                KeyCode::Esc => {
+                    self.filter.clear();
+                    self.input = InputMode::Normal;
+                    self.table.select(Some(0));
+                }
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) => {
+                    text.push(c);
+                    self.table.select(Some(0));
+                }
+                _ => {}
+            },
+            InputMode::Write { index, value } => match key.code {
+                KeyCode::Enter => {
+                    let action = Action::Write {
+                        index: *index,
+                        value: std::mem::take(value),
+                    };
+                    self.input = InputMode::Normal;
+                    return action;
+                }
+                KeyCode::Esc => self.input = InputMode::Normal,
+                KeyCode::Backspace => {
+                    value.pop();
+                }
+                KeyCode::Char(c) => value.push(c),
+                _ => {}
+            },
+            InputMode::Normal => match key.code {
+                // Из большого графика Esc и q возвращают к таблице
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Char('й') | KeyCode::Char('Й')
+                    if self.chart_open =>
+                {
+                    self.chart_open = false;
+                }
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Char('й') | KeyCode::Char('Й') => {
+                    return Action::Quit;
+                }
+                KeyCode::Char('g') | KeyCode::Char('п') => self.chart_open = !self.chart_open,
+                KeyCode::Left | KeyCode::Char('-') if self.chart_open => {
+                    self.chart_window = self.chart_window.saturating_sub(1);
+                }
+                KeyCode::Right | KeyCode::Char('+') if self.chart_open => {
+                    self.chart_window = (self.chart_window + 1).min(CHART_WINDOWS.len() - 1);
+                }
+                KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+                KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+                KeyCode::PageUp => self.move_selection(-10),
+                KeyCode::PageDown => self.move_selection(10),
+                KeyCode::Char('p') | KeyCode::Char('з') | KeyCode::Char(' ') => {
+                    self.paused = !self.paused;
+                    return Action::Pause(self.paused);
+                }
+                KeyCode::Char('/') | KeyCode::Char('f') | KeyCode::Char('а') => {
+                    self.input = InputMode::Filter(self.filter.clone());
+                }
+                KeyCode::Char('w') | KeyCode::Char('ц') | KeyCode::Enter => match self.selected() {
+                    Some(index) if !self.rows[index].register.modbus_type.is_writable() => {
+                        let register = &self.rows[index].register;
+                        self.message = Some((
+                            fill(msg().tag_read_only, &[&register.name, &register.modbus_type]),
+                            Color::Yellow,
+                        ));
+                    }
+                    Some(index) => {
+                        self.input = InputMode::Write {
+                            index,
+                            value: String::new(),
+                        }
+                    }
+                    None => {}
+                },
+                _ => {}
+            },
+        }
+        Action::None
+    }
+
+    fn status_line(&self) -> Line<'static> {
+        let m = msg();
+        let state = if !self.connected {
+            Span::styled(m.state_connecting, Style::default().fg(Color::Black).bg(Color::Yellow))
+        } else if self.paused {
+            Span::styled(m.state_paused, Style::default().fg(Color::Black).bg(Color::Yellow))
+        } else {
+            Span::styled(m.state_polling, Style::default().fg(Color::Black).bg(Color::Green))
+        };
+        let period = self
+            .cycle_period
+            .map(|period| fill(m.seconds_value, &[&format!("{:.2}", period.as_secs_f64())]))
+            .unwrap_or_else(|| "—".to_string());
+        Line::from(vec![
+            state,
+            Span::raw(fill(
+                m.dashboard_link,
+                &[
+                    &self.conn.port,
+                    &self.conn.baud_rate,
+                    &self.conn.frame_format(),
+                    &self.conn.device_address,
+                ],
+            )),
+            Span::styled(fill(m.dashboard_cycle, &[&self.session.cycles]), Style::default().fg(Color::Cyan)),
+            Span::raw(fill(m.dashboard_failed_period, &[&self.session.failed_cycles, &period])),
+        ])
+    }
+
+    fn input_line(&self) -> Line<'static> {
+        let m = msg();
+        let key = |text: &str| Span::styled(text.to_string(), Style::default().fg(Color::Yellow));
+        match &self.input {
+            InputMode::Normal if self.chart_open => Line::from(vec![
+                key("←→"),
+                Span::raw(m.hint_window),
+                key("↑↓"),
+                Span::raw(m.hint_tag),
+                key("p"),
+                Span::raw(if self.paused { m.hint_resume } else { m.hint_pause }),
+                key("g/Esc"),
+                Span::raw(m.hint_back_to_table),
+            ]),
+            InputMode::Normal => {
+                let mut spans = vec![
+                    key("↑↓"),
+                    Span::raw(m.hint_select),
+                    key("p"),
+                    Span::raw(if self.paused { m.hint_resume } else { m.hint_pause }),
+                    key("/"),
+                    Span::raw(m.hint_filter),
+                    key("w"),
+                    Span::raw(m.hint_write),
+                    key("g"),
+                    Span::raw(m.hint_chart),
+                    key("q"),
+                    Span::raw(m.hint_quit),
+                ];
+                if !self.filter.is_empty() {
+                    spans.push(Span::styled(fill(m.active_filter, &[&self.filter]), Style::default().fg(Color::Cyan)));
+                }
+                Line::from(spans)
+            }
+            InputMode::Filter(text) => Line::from(vec![
+                Span::raw(m.filter_prompt),
+                Span::styled(format!("{}_", text), Style::default().fg(Color::White)),
+                Span::styled(m.filter_hint, Style::default().fg(Color::DarkGray)),
+            ]),
+            InputMode::Write { index, value } => {
+                let register = &self.rows[*index].register;
+                // Целые holding-теги можно менять по одному биту (функция 0x16)
+                let hint = if register.modbus_type == ModbusType::HoldingRegister && register.var_type.bit_width().is_some() {
+                    m.write_bit_hint
+                } else {
+                    m.write_hint
+                };
+                Line::from(vec![
+                    Span::raw(fill(m.write_prompt, &[&register.name, &register.var_type])),
+                    Span::styled(format!("{}_", value), Style::default().fg(Color::White)),
+                    Span::styled(hint, Style::default().fg(Color::DarkGray)),
+                ])
+            }
+        }
+    }
+
+    /// Описание и ошибка выбранного тега либо последнее сообщение
+    fn message_line(&self) -> Line<'static> {
+        if let Some((message, color)) = &self.message {
+            return Line::from(Span::styled(message.clone(), Style::default().fg(*color)));
+        }
+        let Some(row) = self.selected().map(|index| &self.rows[index]) else {
+            return Line::default();
+        };
+        match &row.error {
+            Some(error) => Line::from(Span::styled(
+                format!("{}: {}", row.register.name, error),
+                Style::default().fg(Color::Red),
+            )),
+            None => Line::from(Span::styled(row.register.description.clone(), Style::default().fg(Color::DarkGray))),
+        }
+    }
+
+    /// Мини-график последних значений числового тега
+    fn sparkline(&self, index: usize) -> String {
+        let register = &self.rows[index].register;
+        if register.var_type == VarType::Bool {
+            return String::new();
+        }
+        let samples = self.history.last(&register.name, SPARKLINE_SAMPLES);
+        let (min, max) = samples
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), sample| {
+                (min.min(sample.value), max.max(sample.value))
+            });
+        samples
+            .iter()
+            .map(|sample| {
+                // Постоянное значение рисуется средним уровнем
+                let level = if max > min {
+                    ((sample.value - min) / (max - min) * (SPARKLINE_LEVELS.len() - 1) as f64).round() as usize
+                } else {
+                    SPARKLINE_LEVELS.len() / 2
+                };
+                SPARKLINE_LEVELS[level.min(SPARKLINE_LEVELS.len() - 1)]
+            })
+            .collect()
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [status_area, main_area, input_area, message_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(
+            Paragraph::new(self.status_line())
+                .block(Block::default().borders(Borders::ALL).title(msg().dashboard_frame_title)),
+            status_area,
+        );
+
+        match self.selected() {
+            Some(index) if self.chart_open => self.draw_chart(frame, main_area, index),
+            _ => self.draw_table(frame, main_area),
+        }
+
+        frame.render_widget(Paragraph::new(self.input_line()), input_area);
+        frame.render_widget(Paragraph::new(self.message_line()), message_area);
+    }
+
+    fn draw_table(&mut self, frame: &mut Frame, area: Rect) {
+        let m = msg();
+        let rows: Vec<Row> = self
+            .visible()
+            .into_iter()
+            .map(|index| {
+                let row = &self.rows[index];
+                let stats = &self.session.tags[index];
+                let (quality, color) = match row.quality {
+                    None => ("—", Color::DarkGray),
+                    Some(Quality::Good) => ("OK", Color::Green),
+                    Some(Quality::Timeout) => (m.quality_timeout, Color::Red),
+                    Some(Quality::Exception) => (m.quality_exception, Color::Red),
+                    Some(Quality::Error) => (m.quality_error, Color::Red),
+                };
+                // Последнее удачное значение остается на экране, но гаснет при ошибке чтения
+                let value_style = if row.quality == Some(Quality::Good) {
+                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                let errors_style = if stats.errors == 0 {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default().fg(Color::Red)
+                };
+                Row::new(vec![
+                    Span::styled(row.register.name.clone(), Style::default().fg(Color::Cyan)),
+                    Span::styled(stats.last_value.clone().unwrap_or_else(|| "—".to_string()), value_style),
+                    Span::raw(row.register.unit.clone()),
+                    Span::styled(self.sparkline(index), Style::default().fg(Color::Blue)),
+                    Span::styled(quality, Style::default().fg(color)),
+                    Span::styled(
+                        stats
+                            .last_update
+                            .map(|time| time.format("%H:%M:%S").to_string())
+                            .unwrap_or_else(|| "—".to_string()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(stats.errors.to_string(), errors_style),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(16),
+                Constraint::Length(14),
+                Constraint::Length(6),
+                Constraint::Length(SPARKLINE_SAMPLES as u16),
+                Constraint::Length(10),
+                Constraint::Length(9),
+                Constraint::Length(6),
+            ],
+        )
+        .header(
+            Row::new(vec![m.col_name, m.col_value, m.col_unit, m.col_trend, m.col_quality, m.col_updated, m.col_errors])
+                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::default().borders(Borders::ALL))
+        .row_highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("> ");
+        frame.render_stateful_widget(table, area, &mut self.table);
+    }
+
+    /// Большой график тега за выбранное окно со статистикой min/max/avg
+    fn draw_chart(&self, frame: &mut Frame, area: Rect, index: usize) {
+        let m = msg();
+        let register = &self.rows[index].register;
+        let minutes = CHART_WINDOWS[self.chart_window];
+        let now_ms = chrono::Local::now().timestamp_millis();
+        let window_ms = (minutes * 60_000) as i64;
+        let from_ms = now_ms - window_ms;
+
+        // Точки прореживаются до ширины графика: по две точки Брайля на символ
+        let buckets = usize::from(area.width.saturating_sub(10)).max(1) * 2;
+        let trend = self.history.trend(&register.name, from_ms, now_ms, buckets);
+        let points: Vec<(f64, f64)> = trend
+            .iter()
+            .map(|bucket| ((bucket.start_ms - now_ms) as f64 / 1000.0, bucket.avg))
+            .collect();
+
+        let count: usize = trend.iter().map(|bucket| bucket.count).sum();
+        let unit = if register.unit.is_empty() { String::new() } else { format!(" {}", register.unit) };
+        let title = if count == 0 {
+            fill(m.chart_no_data, &[&register.name, &minutes])
+        } else {
+            let min = trend.iter().map(|bucket| bucket.min).fold(f64::INFINITY, f64::min);
+            let max = trend.iter().map(|bucket| bucket.max).fold(f64::NEG_INFINITY, f64::max);
+            let avg = trend.iter().map(|bucket| bucket.avg * bucket.count as f64).sum::<f64>() / count as f64;
+            let with_unit = |value: f64| format!("{}{}", format_number(value), unit);
+            fill(
+                m.chart_stats,
+                &[&register.name, &minutes, &with_unit(min), &with_unit(max), &with_unit(avg), &count],
+            )
+        };
+
+        let (low, high) = points
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), (_, value)| (low.min(*value), high.max(*value)));
+        let (low, high) = match (low.is_finite(), high > low) {
+            (false, _) => (0.0, 1.0),
+            (true, false) => (low - 1.0, high + 1.0),
+            (true, true) => {
+                let margin = (high - low) * 0.05;
+                (low - margin, high + margin)
+            }
+        };
+        let window_secs = window_ms as f64 / 1000.0;
+
+        let dataset = Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(&points);
+        let chart = Chart::new(vec![dataset])
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(Color::DarkGray))
+                    .bounds([-window_secs, 0.0])
+                    .labels([
+                        fill(m.chart_minutes_ago, &[&minutes]),
+                        fill(m.chart_minutes_ago, &[&format_number(minutes as f64 / 2.0)]),
+                        m.chart_now.to_string(),
+                    ]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(Color::DarkGray))
+                    .bounds([low, high])
+                    .labels([format_number(low), format_number((low + high) / 2.0), format_number(high)]),
+            );
+        frame.render_widget(chart, area);
+    }
+}
+
+/// Число для подписей графика: без лишних нулей после запятой
+fn format_number(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Ожидание клавиши не дольше `timeout`
+fn read_key(timeout: Duration) -> io::Result<Option<KeyEvent>> {
+    if event::poll(timeout)?
+        && let Event::Key(key) = event::read()?
+        && key.kind == KeyEventKind::Press
+    {
+        return Ok(Some(key));
+    }
+    Ok(None)
+}
+
+/// Цикл отрисовки и обработки клавиш до выхода пользователя или остановки опроса
+async fn run_loop(
+    dashboard: &mut Dashboard,
+    handle: &PollerHandle,
+    events: &mut mpsc::UnboundedReceiver<PollEvent>,
+) -> io::Result<()> {
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    terminal.clear()?;
+
+    loop {
+        while let Ok(event) = events.try_recv() {
+            dashboard.apply(event);
+        }
+        if dashboard.stopped || poll_session::stop_requested() {
+            return Ok(());
+        }
+        terminal.draw(|frame| dashboard.draw(frame))?;
+
+        let Some(key) = tokio::task::block_in_place(|| read_key(REFRESH_INTERVAL))? else {
+            continue;
+        };
+        // Сообщение показывается до следующего нажатия
+        dashboard.message = None;
+        match dashboard.handle_key(key) {
+            Action::None => {}
+            Action::Quit => return Ok(()),
+            Action::Pause(true) => handle.pause(),
+            Action::Pause(false) => handle.resume(),
+            Action::Write { index, value } => {
+                let name = dashboard.rows[index].register.name.clone();
+                dashboard.message = Some(match handle.write(index, &value).await {
+                    Ok(()) => (fill(msg().write_done, &[&name, &value]), Color::Green),
+                    Err(e) => (fill(msg().write_failed, &[&name, &write_error_text(&e)]), Color::Red),
+                });
+            }
+        }
+    }
+}
+
+/// Опрос в полноэкранном режиме: таблица тегов обновляется на месте
+pub async fn run_dashboard() -> io::Result<()> {
+    let m = msg();
+    clear_screen();
+    println!("{}", m.dashboard_title.cyan().bold());
+
+    let config = match load_settings() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+            println!("{}", m.save_settings_hint.yellow());
+            return Err(e);
+        }
+    };
+    let registers_config = match load_registers() {
+        Ok(registers_config) => registers_config,
+        Err(e) => {
+            eprintln!("{}", fill(m.registers_config_load_error, &[&e]).red());
+            println!("{}", m.check_tags_file_hint.yellow());
+            return Err(e);
+        }
+    };
+
+    let registers: Vec<RegisterConfig> = registers_config
+        .registers
+        .into_iter()
+        .filter(|reg| reg.enabled)
+        .collect();
+    if registers.is_empty() {
+        println!("{}", m.no_active_registers.red());
+        println!("{}", m.no_active_registers_hint.yellow());
+        return Ok(());
+    }
+
+    let mut connection = config.connection;
+    resolve_bound_port(&mut connection);
+
+    let options = PollOptions::default();
+    let (handle, mut events) = spawn_poller(connection.clone(), registers.clone(), options);
+    let mut dashboard = Dashboard::new(connection, &registers, options.interval);
+
+    let result = match TerminalGuard::enter() {
+        Ok(guard) => {
+            let result = run_loop(&mut dashboard, &handle, &mut events).await;
+            drop(guard);
+            result
+        }
+        Err(e) => Err(e),
+    };
+    handle.shutdown().await;
+
+    // Ошибка открытия порта приходит событием до остановки опроса
+    while let Ok(event) = events.try_recv() {
+        dashboard.apply(event);
+    }
+    if let Some((message, Color::Red)) = &dashboard.message {
+        eprintln!("{}", message.red());
+    }
+    result?;
+    if !dashboard.connected {
+        return Ok(());
+    }
+
+    println!("\n{}", m.polling_stopped.yellow());
+    dashboard.session.finish();
+    dashboard.session.print_summary();
+    Ok(())
+}
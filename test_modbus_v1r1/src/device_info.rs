@@ -0,0 +1,126 @@
+use colored::*;
+use std::io;
+use std::time::Duration;
+
+use modbus_core::device_id::{read_device_identification, Conformity, ReadDeviceIdCode};
+use modbus_core::settings::{is_valid_slave_address, save_device_info, MAX_SLAVE_ADDRESS, MIN_SLAVE_ADDRESS};
+use modbus_core::{RawClient, ReadError};
+
+use crate::i18n::{fill, msg, read_error_text};
+use crate::port_binding::resolve_bound_port;
+use crate::{clear_screen, get_settings_path, load_settings, read_value_or_default};
+
+/// Таймаут ответа на каждый запрос потока
+const RESPONSE_TIMEOUT_MS: u64 = 1000;
+
+fn category_title(category: ReadDeviceIdCode) -> &'static str {
+	let m = msg();
+	match category {
+		ReadDeviceIdCode::Basic => m.device_id_basic,
+		ReadDeviceIdCode::Regular => m.device_id_regular,
+		ReadDeviceIdCode::Extended => m.device_id_extended,
+	}
+}
+
+/// Описание уровня соответствия устройства
+fn conformity_title(conformity: Option<Conformity>) -> String {
+	let m = msg();
+	match conformity {
+		Some(conformity) if conformity.individual_access => {
+			fill(m.conformity_individual, &[&conformity.category.name()])
+		}
+		Some(conformity) => fill(m.conformity_stream_only, &[&conformity.category.name()]),
+		None => m.conformity_unknown.to_string(),
+	}
+}
+
+/// Чтение идентификации устройства (функция 0x2B/0x0E) с выводом таблицы
+/// и сохранением в профиле подключения (интерактивно)
+pub async fn show_device_info() -> io::Result<()> {
+	let m = msg();
+	clear_screen();
+	println!("{}", m.device_info_title.cyan().bold());
+
+	let config = match load_settings() {
+		Ok(config) => config,
+		Err(e) => {
+			eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+			println!("{}", m.save_settings_first.yellow());
+			return Ok(());
+		}
+	};
+	let mut conn = config.connection.clone();
+	resolve_bound_port(&mut conn);
+	println!("{}", fill(m.port_value, &[&conn.port.bright_white()]));
+
+	let slave = read_value_or_default::<u8>(m.prompt_device_address, conn.device_address)?;
+	if !is_valid_slave_address(slave) {
+		println!("{}", fill(m.slave_address_out_of_range, &[&MIN_SLAVE_ADDRESS, &MAX_SLAVE_ADDRESS]).red());
+		return Ok(());
+	}
+	println!("\n{}", m.device_id_categories.yellow());
+	for (index, category) in ReadDeviceIdCode::ALL.iter().enumerate() {
+		println!("  {}. {}", index + 1, category_title(*category));
+	}
+	let choice = read_value_or_default::<usize>(&fill(m.choose_number, &[&ReadDeviceIdCode::ALL.len()]), 1)?;
+	let Some(&category) = choice.checked_sub(1).and_then(|index| ReadDeviceIdCode::ALL.get(index)) else {
+		println!("{}", m.device_id_invalid_category.red());
+		return Ok(());
+	};
+
+	let mut client = match RawClient::connect(&conn, Duration::from_millis(RESPONSE_TIMEOUT_MS)) {
+		Ok(client) => client,
+		Err(e) => {
+			eprintln!("{}", fill(m.port_open_error, &[&conn.port, &e]).red());
+			return Ok(());
+		}
+	};
+	let result = read_device_identification(&mut client, slave, category).await;
+	if let Err(e) = client.disconnect().await {
+		eprintln!("{}", fill(m.port_close_error, &[&conn.port, &e]).red());
+	}
+
+	let identification = match result {
+		Ok(identification) => identification,
+		Err(ReadError::Exception(code @ 0x01..=0x03)) => {
+			println!("{}", fill(m.device_id_unsupported, &[&format!("0x{:02X}", code)]).yellow());
+			println!("{}", m.device_id_unsupported_hint.yellow());
+			return Ok(());
+		}
+		Err(e) => {
+			println!("{}", fill(m.device_id_read_error, &[&read_error_text(&e)]).red());
+			return Ok(());
+		}
+	};
+
+	println!(
+		"\n{} 0x{:02X} ({})",
+		m.label_conformity.green(),
+		identification.conformity_level,
+		conformity_title(identification.conformity())
+	);
+	println!("{}", "─".repeat(80));
+	println!("{:<6} {:<24} {}", "ID", m.col_object, m.col_value);
+	println!("{}", "─".repeat(80));
+	for object in &identification.objects {
+		println!(
+			"{:<6} {:<24} {}",
+			format!("0x{:02X}", object.id),
+			object.name().cyan(),
+			object.text().bright_white()
+		);
+	}
+	println!("{}", "─".repeat(80));
+
+	if identification.objects.is_empty() {
+		return Ok(());
+	}
+	let answer = read_value_or_default::<String>(m.device_id_save_prompt, "n".to_string())?;
+	if matches!(answer.to_lowercase().as_str(), "y" | "yes" | "да" | "д" | "1") {
+		match save_device_info(&get_settings_path(), identification.to_info(slave)) {
+			Ok(()) => println!("{}", m.device_id_saved.green()),
+			Err(e) => eprintln!("{}", fill(m.settings_save_error, &[&e]).red()),
+		}
+	}
+	Ok(())
+}
@@ -0,0 +1,182 @@
+use colored::*;
+use std::io::{self, Write};
+
+use modbus_core::register_types::{ModbusType, VarType};
+use modbus_core::tags::file_is_one_based;
+use modbus_core::tags_validation::validate_candidate;
+
+use crate::address_notation::read_address;
+use crate::i18n::{fill, msg};
+use crate::{
+	clear_screen,
+	get_registers_path,
+	print_issues,
+	wait_for_continue,
+	load_registers_or_warn,
+	read_value_or_default,
+	save_registers_to_csv,
+	RegisterConfig,
+};
+
+/// Чтение строки; пустой ввод оставляет текущее значение
+fn read_text_or_keep(prompt: &str, current: &str) -> io::Result<String> {
+	print!("{} [{}]: ", prompt.yellow(), current.bright_white());
+	io::stdout().flush()?;
+	let mut input = String::new();
+	io::stdin().read_line(&mut input)?;
+	let trimmed = input.trim();
+	Ok(if trimmed.is_empty() { current.to_string() } else { trimmed.to_string() })
+}
+
+/// Выбор значения из списка по номеру или имени; пустой ввод оставляет текущее значение
+fn select_or_keep<T>(prompt: &str, options: &[T], current: T, name: fn(T) -> &'static str) -> io::Result<T>
+where
+	T: Copy + PartialEq,
+{
+	let m = msg();
+	println!("{}", prompt.yellow());
+	for (index, option) in options.iter().enumerate() {
+		let marker = if *option == current { m.option_current.green().to_string() } else { String::new() };
+		println!("  {}. {}{}", index + 1, name(*option), marker);
+	}
+	loop {
+		print!("{}", fill(m.enter_number_or_name, &[&options.len(), &name(current).bright_white()]));
+		io::stdout().flush()?;
+		let mut input = String::new();
+		io::stdin().read_line(&mut input)?;
+		let trimmed = input.trim();
+		if trimmed.is_empty() {
+			return Ok(current);
+		}
+		if let Ok(n) = trimmed.parse::<usize>()
+			&& (1..=options.len()).contains(&n)
+		{
+			return Ok(options[n - 1]);
+		}
+		if let Some(option) = options.iter().find(|option| name(**option) == trimmed) {
+			return Ok(*option);
+		}
+		println!("{}", m.invalid_choice_retry.red());
+	}
+}
+
+/// Поиск регистра по порядковому номеру или имени
+fn find_register(registers: &[RegisterConfig], input: &str) -> Option<usize> {
+	match input.parse::<usize>() {
+		Ok(num) if (1..=registers.len()).contains(&num) => Some(num - 1),
+		_ => registers.iter().position(|reg| reg.name == input),
+	}
+}
+
+/// Изменение существующего регистра (интерактивно)
+pub fn edit_register() -> io::Result<()> {
+	let m = msg();
+	clear_screen();
+	println!("{}", m.edit_register_title.cyan().bold());
+
+	let mut cfg = match load_registers_or_warn() {
+		Some(c) => c,
+		None => {
+			return Ok(());
+		}
+	};
+
+	if cfg.registers.is_empty() {
+		println!("{}", m.nothing_to_edit.yellow());
+		wait_for_continue()?;
+		return Ok(());
+	}
+
+	println!("\n{}", m.registers_list.yellow());
+	for (idx, reg) in cfg.registers.iter().enumerate() {
+		println!("  {:<3} {:<20} ({}: {:<5} {}: {:<6} modbus: {:<16})",
+			 (idx + 1).to_string().bright_black(),
+			 reg.name.cyan(),
+			 m.word_address,
+			 reg.address,
+			 m.word_type,
+			 reg.var_type.as_str().yellow(),
+			 reg.modbus_type.as_str().blue());
+	}
+
+	print!("\n{}", fill(m.enter_register_to_edit, &[&cfg.registers.len()]));
+	io::stdout().flush()?;
+	let mut input = String::new();
+	io::stdin().read_line(&mut input)?;
+	let trimmed = input.trim();
+
+	if trimmed == "0" || trimmed.is_empty() {
+		println!("{}", m.edit_cancelled.bright_black());
+		wait_for_continue()?;
+		return Ok(());
+	}
+
+	let Some(index) = find_register(&cfg.registers, trimmed) else {
+		println!("{}", fill(m.register_not_found, &[&trimmed]).yellow());
+		wait_for_continue()?;
+		return Ok(());
+	};
+
+	let current = cfg.registers[index].clone();
+	println!("\n{}", m.keep_current_hint.bright_black());
+
+	let name = read_text_or_keep(m.edit_prompt_name, &current.name)?;
+	let description = read_text_or_keep(m.edit_prompt_description, &current.description)?;
+	// "-" убирает единицу измерения: пустой ввод оставляет текущую
+	let unit = match read_text_or_keep(m.edit_prompt_unit, &current.unit)?.as_str() {
+		"-" => String::new(),
+		unit => unit.to_string(),
+	};
+	let one_based = file_is_one_based(&get_registers_path());
+	let Some(address) = read_address(m.edit_prompt_address, one_based, Some((current.address, current.modbus_type)))? else {
+		println!("{}", m.changes_not_saved.red());
+		wait_for_continue()?;
+		return Ok(());
+	};
+	let var_type = select_or_keep(m.edit_prompt_var_type, &VarType::ALL, current.var_type, VarType::as_str)?;
+	// Адрес Modicon подсказывает область, но ее можно изменить
+	let modbus_type = select_or_keep(
+		m.edit_prompt_modbus_type,
+		&ModbusType::ALL,
+		address.area.unwrap_or(current.modbus_type),
+		ModbusType::as_str,
+	)?;
+	let enabled_default = if current.enabled { "y" } else { "n" };
+	let enabled = match read_value_or_default::<String>(m.edit_prompt_enabled, enabled_default.to_string())?
+		.to_lowercase()
+		.as_str()
+	{
+		"1" | "да" | "y" | "yes" | "true" => true,
+		"2" | "нет" | "n" | "no" | "false" => false,
+		_ => {
+			println!("{}", m.invalid_enabled.red());
+			wait_for_continue()?;
+			return Ok(());
+		}
+	};
+
+	let edited = RegisterConfig {
+		name,
+		description,
+		address: address.offset,
+		var_type,
+		modbus_type,
+		enabled,
+		unit,
+	};
+
+	// Те же проверки, что и при добавлении: сравнение со всеми остальными регистрами
+	let issues = validate_candidate(&cfg.registers, Some(index), &edited, one_based);
+	if !issues.is_empty() {
+		print_issues(&issues);
+		println!("{}", m.changes_not_saved.red());
+		wait_for_continue()?;
+		return Ok(());
+	}
+
+	cfg.registers[index] = edited;
+	save_registers_to_csv(&cfg.registers)?;
+	println!("{}", fill(m.register_edited, &[&cfg.registers[index].name]).green());
+	wait_for_continue()?;
+	Ok(())
+}
@@ -0,0 +1,2218 @@
+//! Каталог сообщений интерфейса на русском и английском языках.
+//!
+//! Каждое сообщение - поле структуры `Messages`, поэтому пропущенный перевод
+//! не даст собрать программу. Места подстановки в тексте обозначаются `{}`
+//! и заполняются функцией `fill`.
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use modbus_core::address::AddressNotation;
+use modbus_core::raw_request::hex_bytes;
+use modbus_core::tags::TagsWriteError;
+use modbus_core::tags_exchange::{ExchangeFormat, MergeMode};
+use modbus_core::tags_validation::{IssueKind, TagIssue};
+use modbus_core::{FlowControl, Language, PinState, ReadError, RtsMode, WriteError};
+
+/// Текущий язык интерфейса (индекс в `Language::ALL`)
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Переменные окружения с локалью в порядке приоритета (как в POSIX)
+const LOCALE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// Выбор языка: флаг `--lang`, затем настройка из connect_settings.json,
+/// затем локаль системы; по умолчанию - русский
+pub fn resolve_language(flag: Option<Language>, setting: Option<Language>) -> Language {
+    flag.or(setting).or_else(locale_language).unwrap_or_default()
+}
+
+/// Язык по переменным окружения LC_ALL, LC_MESSAGES, LANG
+pub fn locale_language() -> Option<Language> {
+    LOCALE_VARIABLES
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| Language::from_locale(&value))
+}
+
+pub fn set_language(language: Language) {
+    let index = Language::ALL.iter().position(|l| *l == language).unwrap_or(0);
+    CURRENT.store(index as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    Language::ALL[CURRENT.load(Ordering::Relaxed) as usize]
+}
+
+/// Сообщения на текущем языке
+pub fn msg() -> &'static Messages {
+    match language() {
+        Language::Ru => &RU,
+        Language::En => &EN,
+    }
+}
+
+/// Подстановка значений вместо `{}` по порядку
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut parts = template.split("{}");
+    if let Some(first) = parts.next() {
+        result.push_str(first);
+    }
+    for part in parts {
+        if let Some(arg) = args.next() {
+            result.push_str(&arg.to_string());
+        }
+        result.push_str(part);
+    }
+    result
+}
+
+/// Название записи адресов на текущем языке
+pub fn notation_title(notation: AddressNotation) -> &'static str {
+    let m = msg();
+    match notation {
+        AddressNotation::Decimal => m.notation_decimal,
+        AddressNotation::Hex => m.notation_hex,
+        AddressNotation::Modicon => m.notation_modicon,
+    }
+}
+
+/// Название формата файла обмена тегами на текущем языке
+pub fn exchange_format_title(format: ExchangeFormat) -> &'static str {
+    match format {
+        ExchangeFormat::Json => "JSON",
+        ExchangeFormat::Yaml => "YAML",
+        ExchangeFormat::Toml => "TOML",
+        ExchangeFormat::Csv => msg().format_csv_comma,
+    }
+}
+
+/// Название режима импорта тегов на текущем языке
+pub fn merge_mode_title(mode: MergeMode) -> &'static str {
+    let m = msg();
+    match mode {
+        MergeMode::Replace => m.merge_replace,
+        MergeMode::Append => m.merge_append,
+        MergeMode::UpdateByName => m.merge_update_by_name,
+    }
+}
+
+/// Название режима управления потоком на текущем языке
+pub fn flow_control_title(flow_control: FlowControl) -> &'static str {
+    match flow_control {
+        FlowControl::None => msg().flow_none,
+        FlowControl::Software => "XON/XOFF",
+        FlowControl::Hardware => "RTS/CTS",
+    }
+}
+
+/// Описание режима линии RTS на текущем языке
+pub fn rts_mode_title(mode: RtsMode) -> &'static str {
+    let m = msg();
+    match mode {
+        RtsMode::Unchanged => m.rts_unchanged,
+        RtsMode::On => m.rts_on,
+        RtsMode::Off => m.rts_off,
+        RtsMode::Transmit => m.rts_transmit,
+        RtsMode::TransmitInverted => m.rts_transmit_inverted,
+    }
+}
+
+/// Описание состояния линии DTR на текущем языке
+pub fn pin_state_title(state: PinState) -> &'static str {
+    let m = msg();
+    match state {
+        PinState::Unchanged => m.pin_unchanged,
+        PinState::On => m.pin_on,
+        PinState::Off => m.pin_off,
+    }
+}
+
+/// Описание кода исключения Modbus на текущем языке
+pub fn exception_title(code: u8) -> &'static str {
+    let m = msg();
+    match code {
+        0x01 => m.exception_illegal_function,
+        0x02 => m.exception_illegal_data_address,
+        0x03 => m.exception_illegal_data_value,
+        0x04 => m.exception_server_failure,
+        0x05 => m.exception_acknowledge,
+        0x06 => m.exception_server_busy,
+        0x08 => m.exception_memory_parity,
+        0x0A => m.exception_gateway_path,
+        0x0B => m.exception_gateway_target,
+        _ => m.exception_unknown,
+    }
+}
+
+/// Текст ошибки чтения на текущем языке
+pub fn read_error_text(error: &ReadError) -> String {
+    let m = msg();
+    match error {
+        ReadError::Timeout => m.timeout.to_string(),
+        ReadError::Exception(code) => {
+            fill(m.read_exception, &[&format!("0x{:02X}", code), &exception_title(*code)])
+        }
+        ReadError::Crc(frame) => fill(m.read_crc, &[&hex_bytes(frame)]),
+        ReadError::Malformed(pdu) => fill(m.read_malformed, &[&hex_bytes(pdu)]),
+        ReadError::Io(message) => message.clone(),
+    }
+}
+
+/// Текст ошибки записи tags.csv на текущем языке
+pub fn tags_write_error_text(error: &TagsWriteError) -> String {
+    match error {
+        TagsWriteError::AddressTooLarge(name) => fill(msg().tag_address_too_large, &[name]),
+        TagsWriteError::Io(e) => e.to_string(),
+    }
+}
+
+/// Текст ошибки записи на текущем языке
+pub fn write_error_text(error: &WriteError) -> String {
+    let m = msg();
+    match error {
+        WriteError::ReadOnly => m.write_read_only.to_string(),
+        WriteError::InvalidValue { value, var_type } => fill(m.write_invalid_value, &[value, var_type]),
+        WriteError::InvalidBitWrite(text) => fill(m.write_invalid_bit, &[text]),
+        WriteError::BitWriteArea(_) => m.write_bit_area.to_string(),
+        WriteError::BitWriteType(var_type) => fill(m.write_bit_type, &[var_type]),
+        WriteError::BitOutOfRange { bit, var_type } => {
+            let last_bit = var_type.bit_width().unwrap_or(1) - 1;
+            fill(m.write_bit_range, &[bit, &last_bit, var_type])
+        }
+        WriteError::UnknownTag => m.write_unknown_tag.to_string(),
+        WriteError::Request(e) => read_error_text(e),
+        WriteError::Stopped => m.write_stopped.to_string(),
+    }
+}
+
+/// Описание проблемы в теге на текущем языке
+pub fn issue_text(kind: &IssueKind) -> String {
+    let m = msg();
+    match kind {
+        IssueKind::EmptyName => m.issue_empty_name.to_string(),
+        IssueKind::DuplicateName { first_line } => fill(m.issue_duplicate_name, &[first_line]),
+        IssueKind::InvalidCombination { var_type, modbus_type } => {
+            fill(m.issue_invalid_combination, &[var_type, modbus_type])
+        }
+        IssueKind::AddressOutOfRange { last_address } => fill(m.issue_address_out_of_range, &[last_address]),
+        IssueKind::OneBasedOverflow => m.issue_one_based_overflow.to_string(),
+        IssueKind::ZeroAddressOneBased => m.issue_zero_address_one_based.to_string(),
+        IssueKind::Overlap { other_name, other_line } => fill(m.issue_overlap, &[other_name, other_line]),
+        IssueKind::Parse { field, message } => fill(m.issue_parse, &[field, message]),
+        IssueKind::ColumnCount { found, expected } => fill(m.issue_column_count, &[found, expected]),
+    }
+}
+
+/// Проблема в строке tags.csv на текущем языке
+pub fn tag_issue_text(issue: &TagIssue) -> String {
+    fill(msg().tag_issue, &[&issue.line, &issue.name, &issue_text(&issue.kind)])
+}
+
+/// Тексты интерфейса
+pub struct Messages {
+    // Общие
+    pub choose_action: &'static str,
+    pub your_choice: &'static str,
+    pub back_to_main_menu: &'static str,
+    pub press_enter: &'static str,
+    pub invalid_format_retry: &'static str,
+    pub invalid_choice_range: &'static str,
+    pub invalid_choice_value: &'static str,
+    pub invalid_format_range: &'static str,
+    pub invalid_choice_back: &'static str,
+    pub enter_number_range: &'static str,
+    pub choose_number: &'static str,
+    pub selected: &'static str,
+    pub available_options: &'static str,
+    pub unknown: &'static str,
+    pub baud: &'static str,
+    pub timeout: &'static str,
+    pub error_with: &'static str,
+    pub word_address: &'static str,
+    pub word_type: &'static str,
+    pub word_quantity: &'static str,
+    pub shutting_down: &'static str,
+    pub unknown_language: &'static str,
+
+    // Поиск и выбор порта
+    pub no_ports_found: &'static str,
+    pub no_ports_exit: &'static str,
+    pub no_ports_retry: &'static str,
+    pub exiting: &'static str,
+    pub retrying_port_scan: &'static str,
+    pub no_ports_invalid_choice: &'static str,
+    pub select_port: &'static str,
+    pub enter_port_number: &'static str,
+    pub port_selected: &'static str,
+
+    // Параметры линии
+    pub device_address_title: &'static str,
+    pub device_address_range: &'static str,
+    pub enter_device_address: &'static str,
+    pub device_address_selected: &'static str,
+    pub device_address_invalid: &'static str,
+    pub baud_rate_title: &'static str,
+    pub available_baud_rates: &'static str,
+    pub enter_baud_rate_number: &'static str,
+    pub baud_rate_selected: &'static str,
+    pub baud_rate_other: &'static str,
+    pub enter_custom_baud_rate: &'static str,
+    pub invalid_baud_rate: &'static str,
+    pub parity_title: &'static str,
+    pub available_parity: &'static str,
+    pub parity_none_hint: &'static str,
+    pub parity_even_hint: &'static str,
+    pub parity_odd_hint: &'static str,
+    pub parity_mark_hint: &'static str,
+    pub parity_space_hint: &'static str,
+    pub enter_parity_number: &'static str,
+    pub parity_selected: &'static str,
+    pub stop_bits_title: &'static str,
+    pub stop_bits_one: &'static str,
+    pub stop_bits_two: &'static str,
+    pub stop_bits_one_hint: &'static str,
+    pub stop_bits_two_hint: &'static str,
+    pub enter_stop_bits_number: &'static str,
+    pub advanced_line_prompt: &'static str,
+    pub data_bits_prompt: &'static str,
+    pub invalid_data_bits: &'static str,
+    pub flow_none: &'static str,
+    pub rts_unchanged: &'static str,
+    pub rts_on: &'static str,
+    pub rts_off: &'static str,
+    pub rts_transmit: &'static str,
+    pub rts_transmit_inverted: &'static str,
+    pub rts_by_flow_control: &'static str,
+    pub pin_unchanged: &'static str,
+    pub pin_on: &'static str,
+    pub pin_off: &'static str,
+    pub option_number: &'static str,
+    pub delay_before_send_prompt: &'static str,
+    pub delay_after_send_prompt: &'static str,
+    pub frame_timing_computed: &'static str,
+    pub inter_char_timeout_prompt: &'static str,
+    pub silent_interval_prompt: &'static str,
+    pub discard_broken_frames_prompt: &'static str,
+    pub invalid_silent_interval: &'static str,
+
+    // Файлы тегов
+    pub errors_in_file: &'static str,
+    pub no_tag_issues: &'static str,
+    pub issues_found: &'static str,
+    pub checking_file: &'static str,
+    pub tags_in_file: &'static str,
+    pub file_read_error: &'static str,
+    pub registers_load_failed: &'static str,
+    pub check_tags_file_hint: &'static str,
+
+    // Настройки связи
+    pub connection_settings_title: &'static str,
+    pub connection_parameters: &'static str,
+    pub label_port: &'static str,
+    pub label_port_binding: &'static str,
+    pub label_device_address: &'static str,
+    pub label_baud_rate: &'static str,
+    pub label_parity: &'static str,
+    pub label_stop_bits: &'static str,
+    pub label_data_bits: &'static str,
+    pub label_flow_control: &'static str,
+    pub label_rts: &'static str,
+    pub label_dtr: &'static str,
+    pub label_rs485_delays: &'static str,
+    pub ms: &'static str,
+    pub label_frame_timing: &'static str,
+    pub frame_timing_auto: &'static str,
+    pub frame_timing_manual: &'static str,
+    pub broken_frames_counted: &'static str,
+    pub us: &'static str,
+    pub file_info: &'static str,
+    pub label_version: &'static str,
+    pub label_file_updated: &'static str,
+    pub stored_device_info: &'static str,
+    pub settings_load_error: &'static str,
+    pub using_default_settings: &'static str,
+    pub change_connection_title: &'static str,
+    pub adapter: &'static str,
+    pub bind_to_adapter_prompt: &'static str,
+    pub settings_saved: &'static str,
+    pub settings_save_error: &'static str,
+
+    // Настройки отображения
+    pub display_settings_title: &'static str,
+    pub current_settings: &'static str,
+    pub label_address_notation: &'static str,
+    pub label_tags_numbering: &'static str,
+    pub label_language: &'static str,
+    pub numbering_from_one: &'static str,
+    pub numbering_from_zero: &'static str,
+    pub change_address_notation: &'static str,
+    pub toggle_tags_numbering: &'static str,
+    pub change_language: &'static str,
+    pub notation_number: &'static str,
+    pub language_number: &'static str,
+    pub language_auto: &'static str,
+    pub language_auto_option: &'static str,
+    pub invalid_choice_unchanged: &'static str,
+    pub display_settings_saved: &'static str,
+    pub tag_address_too_large: &'static str,
+    pub tags_file_renumbered: &'static str,
+    pub notation_decimal: &'static str,
+    pub notation_hex: &'static str,
+    pub notation_modicon: &'static str,
+
+    // Опрос
+    pub port_opened: &'static str,
+    pub port_open_error: &'static str,
+    pub rtu_context_created: &'static str,
+    pub rtu_context_error: &'static str,
+    pub polling_title: &'static str,
+    pub connection_settings_loaded: &'static str,
+    pub connection_settings_load_error: &'static str,
+    pub save_settings_hint: &'static str,
+    pub save_settings_first: &'static str,
+    pub prompt_device_address: &'static str,
+    pub port_value: &'static str,
+    pub slave_address_out_of_range: &'static str,
+    pub registers_config_loaded: &'static str,
+    pub registers_config_load_error: &'static str,
+    pub no_active_registers: &'static str,
+    pub no_active_registers_hint: &'static str,
+    pub used_connection_settings: &'static str,
+    pub active_registers: &'static str,
+    pub polling_started: &'static str,
+    pub polling_stop_hint: &'static str,
+    pub polling_stopped_by_user: &'static str,
+    pub port_close_error: &'static str,
+    pub port_closed: &'static str,
+    pub polling_error: &'static str,
+
+    // Итоги сеанса опроса
+    pub summary_title: &'static str,
+    pub label_started: &'static str,
+    pub label_duration: &'static str,
+    pub label_cycles: &'static str,
+    pub label_failed_cycles: &'static str,
+    pub stats_by_register: &'static str,
+    pub col_errors: &'static str,
+    pub col_last_value: &'static str,
+    pub col_time: &'static str,
+
+    // Список регистров
+    pub registers_title: &'static str,
+    pub registers_loaded: &'static str,
+    pub config_info: &'static str,
+    pub label_description: &'static str,
+    pub label_config_updated: &'static str,
+    pub label_file_numbering: &'static str,
+    pub registers_stats: &'static str,
+    pub label_total_registers: &'static str,
+    pub label_enabled: &'static str,
+    pub label_disabled: &'static str,
+    pub no_registers: &'static str,
+    pub registers_list: &'static str,
+    pub col_name: &'static str,
+    pub col_description: &'static str,
+    pub col_address: &'static str,
+    pub col_type: &'static str,
+    pub col_unit: &'static str,
+    pub col_modbus_type: &'static str,
+    pub col_status: &'static str,
+    pub status_enabled: &'static str,
+    pub status_disabled: &'static str,
+    pub registers_load_error: &'static str,
+
+    // Удаление регистра
+    pub delete_register_title: &'static str,
+    pub nothing_to_delete: &'static str,
+    pub enter_register_to_delete: &'static str,
+    pub invalid_number_input: &'static str,
+    pub deletion_cancelled: &'static str,
+    pub number_out_of_range: &'static str,
+    pub register_deleted: &'static str,
+
+    // Добавление регистра
+    pub add_register_title: &'static str,
+    pub prompt_name: &'static str,
+    pub name_empty: &'static str,
+    pub prompt_description: &'static str,
+    pub prompt_unit: &'static str,
+    pub prompt_address: &'static str,
+    pub prompt_var_type: &'static str,
+    pub invalid_var_type: &'static str,
+    pub modbus_type_from_modicon: &'static str,
+    pub prompt_modbus_type: &'static str,
+    pub read_function: &'static str,
+    pub invalid_modbus_type: &'static str,
+    pub prompt_enabled: &'static str,
+    pub enabled_yes: &'static str,
+    pub enabled_no: &'static str,
+    pub invalid_enabled: &'static str,
+    pub register_not_added: &'static str,
+    pub register_added: &'static str,
+
+    // Меню
+    pub menu_show_connection: &'static str,
+    pub menu_change_connection: &'static str,
+    pub menu_start_polling: &'static str,
+    pub menu_registers: &'static str,
+    pub menu_tools: &'static str,
+    pub menu_display_settings: &'static str,
+    pub menu_dashboard: &'static str,
+    pub menu_exit: &'static str,
+    pub invalid_choice_default_polling: &'static str,
+    pub registers_menu_title: &'static str,
+    pub menu_show_registers: &'static str,
+    pub menu_delete_register: &'static str,
+    pub menu_add_register: &'static str,
+    pub menu_edit_register: &'static str,
+    pub menu_sort_by_address: &'static str,
+    pub menu_check_tags: &'static str,
+    pub menu_export: &'static str,
+    pub menu_import: &'static str,
+    pub check_tags_title: &'static str,
+    pub sort_done: &'static str,
+    pub sort_error: &'static str,
+    pub tools_menu_title: &'static str,
+    pub menu_bus_scan: &'static str,
+    pub menu_auto_detect: &'static str,
+    pub menu_register_discovery: &'static str,
+    pub menu_vendor_import: &'static str,
+    pub menu_raw_console: &'static str,
+    pub menu_device_info: &'static str,
+    pub menu_line_diagnostics: &'static str,
+    pub scan_error: &'static str,
+    pub auto_detect_error: &'static str,
+    pub discovery_error: &'static str,
+    pub vendor_import_error: &'static str,
+    pub raw_console_error: &'static str,
+    pub device_info_error: &'static str,
+    pub line_diagnostics_error: &'static str,
+
+    // Ошибки чтения и записи
+    pub exception_illegal_function: &'static str,
+    pub exception_illegal_data_address: &'static str,
+    pub exception_illegal_data_value: &'static str,
+    pub exception_server_failure: &'static str,
+    pub exception_acknowledge: &'static str,
+    pub exception_server_busy: &'static str,
+    pub exception_memory_parity: &'static str,
+    pub exception_gateway_path: &'static str,
+    pub exception_gateway_target: &'static str,
+    pub exception_unknown: &'static str,
+    pub read_exception: &'static str,
+    pub read_crc: &'static str,
+    pub read_malformed: &'static str,
+    pub write_read_only: &'static str,
+    pub write_invalid_value: &'static str,
+    pub write_invalid_bit: &'static str,
+    pub write_bit_area: &'static str,
+    pub write_bit_type: &'static str,
+    pub write_bit_range: &'static str,
+    pub write_unknown_tag: &'static str,
+    pub write_stopped: &'static str,
+    // Проблемы в описании тегов
+    pub issue_empty_name: &'static str,
+    pub issue_duplicate_name: &'static str,
+    pub issue_invalid_combination: &'static str,
+    pub issue_address_out_of_range: &'static str,
+    pub issue_one_based_overflow: &'static str,
+    pub issue_zero_address_one_based: &'static str,
+    pub issue_overlap: &'static str,
+    pub issue_parse: &'static str,
+    pub issue_column_count: &'static str,
+    pub tag_issue: &'static str,
+    // Полноэкранный опрос
+    pub dashboard_title: &'static str,
+    pub dashboard_frame_title: &'static str,
+    pub state_connecting: &'static str,
+    pub state_paused: &'static str,
+    pub state_polling: &'static str,
+    pub seconds_value: &'static str,
+    pub dashboard_link: &'static str,
+    pub dashboard_cycle: &'static str,
+    pub dashboard_failed_period: &'static str,
+    pub hint_window: &'static str,
+    pub hint_tag: &'static str,
+    pub hint_pause: &'static str,
+    pub hint_resume: &'static str,
+    pub hint_back_to_table: &'static str,
+    pub hint_select: &'static str,
+    pub hint_filter: &'static str,
+    pub hint_write: &'static str,
+    pub hint_chart: &'static str,
+    pub hint_quit: &'static str,
+    pub active_filter: &'static str,
+    pub filter_prompt: &'static str,
+    pub filter_hint: &'static str,
+    pub write_prompt: &'static str,
+    pub write_bit_hint: &'static str,
+    pub write_hint: &'static str,
+    pub tag_read_only: &'static str,
+    pub col_value: &'static str,
+    pub col_trend: &'static str,
+    pub col_quality: &'static str,
+    pub col_updated: &'static str,
+    pub quality_timeout: &'static str,
+    pub quality_exception: &'static str,
+    pub quality_error: &'static str,
+    pub chart_no_data: &'static str,
+    pub chart_stats: &'static str,
+    pub chart_minutes_ago: &'static str,
+    pub chart_now: &'static str,
+    pub write_done: &'static str,
+    pub write_failed: &'static str,
+    pub dashboard_polling_started: &'static str,
+    pub polling_stopped: &'static str,
+
+    // Консоль запросов
+    pub console_title: &'static str,
+    pub line_summary: &'static str,
+    pub console_slave_set: &'static str,
+    pub console_timeout_set: &'static str,
+    pub console_help_title: &'static str,
+    pub console_invalid_value: &'static str,
+    pub console_invalid_byte: &'static str,
+    pub console_expected_bit: &'static str,
+    pub console_usage: &'static str,
+    pub console_slave_range: &'static str,
+    pub console_tag_not_found: &'static str,
+    pub console_unknown_command: &'static str,
+    pub console_count_range: &'static str,
+    pub console_read_count_range: &'static str,
+    pub console_max_coils: &'static str,
+    pub console_max_registers: &'static str,
+    pub console_max_write_registers: &'static str,
+    pub console_use_raw: &'static str,
+    pub console_broadcast_sent: &'static str,
+    pub console_response_time: &'static str,
+    pub console_exception: &'static str,
+    pub console_written_single: &'static str,
+    pub console_written_multiple: &'static str,
+    pub console_mask_written: &'static str,
+    pub console_data: &'static str,
+    pub what_slave_address: &'static str,
+    pub what_timeout: &'static str,
+    pub what_bit_number: &'static str,
+    pub what_address: &'static str,
+    pub what_quantity: &'static str,
+    pub what_value: &'static str,
+    pub what_and_mask: &'static str,
+    pub what_or_mask: &'static str,
+    pub what_read_address: &'static str,
+    pub what_write_address: &'static str,
+    pub usage_slave: &'static str,
+    pub usage_timeout: &'static str,
+    pub usage_bit: &'static str,
+    pub usage_raw: &'static str,
+    pub usage_read: &'static str,
+    pub usage_write_coil: &'static str,
+    pub usage_write_register: &'static str,
+    pub usage_write_coils: &'static str,
+    pub usage_write_registers: &'static str,
+    pub usage_mask_write: &'static str,
+    pub usage_read_write: &'static str,
+    /// Строки справки консоли: синтаксис и описание команды
+    pub console_commands: [(&'static str, &'static str); 15],
+
+    // Информация об устройстве
+    pub device_info_title: &'static str,
+    pub device_id_categories: &'static str,
+    pub device_id_basic: &'static str,
+    pub device_id_regular: &'static str,
+    pub device_id_extended: &'static str,
+    pub device_id_invalid_category: &'static str,
+    pub device_id_unsupported: &'static str,
+    pub device_id_unsupported_hint: &'static str,
+    pub device_id_read_error: &'static str,
+    pub label_conformity: &'static str,
+    pub conformity_stream_only: &'static str,
+    pub conformity_individual: &'static str,
+    pub conformity_unknown: &'static str,
+    pub col_object: &'static str,
+    pub device_id_save_prompt: &'static str,
+    pub device_id_saved: &'static str,
+
+    // Диагностика линии
+    pub diag_title: &'static str,
+    pub diag_actions: &'static str,
+    pub diag_menu_echo: &'static str,
+    pub diag_menu_counters: &'static str,
+    pub diag_menu_event_log: &'static str,
+    pub diag_menu_clear: &'static str,
+    pub diag_menu_stats: &'static str,
+    pub diag_menu_exit: &'static str,
+    pub diag_invalid_choice: &'static str,
+    pub diag_clear_prompt: &'static str,
+    pub diag_cleared: &'static str,
+    pub diag_clear_error: &'static str,
+    pub diag_function_unsupported: &'static str,
+    pub echo_count_prompt: &'static str,
+    pub echo_length_prompt: &'static str,
+    pub echo_invalid: &'static str,
+    pub echo_started: &'static str,
+    pub echo_stopped: &'static str,
+    pub echo_progress: &'static str,
+    pub echo_request: &'static str,
+    pub echo_corrupted: &'static str,
+    pub echo_unsupported: &'static str,
+    pub echo_summary: &'static str,
+    pub counters_error: &'static str,
+    pub col_indicator: &'static str,
+    pub col_device: &'static str,
+    pub col_master_session: &'static str,
+    pub exception_short: &'static str,
+    pub counter_bus_messages: &'static str,
+    pub counter_bus_comm_errors: &'static str,
+    pub counter_bus_exceptions: &'static str,
+    pub counter_slave_messages: &'static str,
+    pub counter_slave_no_response: &'static str,
+    pub counter_slave_nak: &'static str,
+    pub counter_slave_busy: &'static str,
+    pub counter_character_overrun: &'static str,
+    pub master_requests: &'static str,
+    pub master_frame_errors: &'static str,
+    pub master_exceptions: &'static str,
+    pub master_responses: &'static str,
+    pub master_timeouts: &'static str,
+    pub device_busy: &'static str,
+    pub device_ready: &'static str,
+    pub comm_events_counter: &'static str,
+    pub comm_events_details: &'static str,
+    pub counters_note: &'static str,
+    pub counters_noise_hint: &'static str,
+    pub event_log_error: &'static str,
+    pub label_device_state: &'static str,
+    pub label_events: &'static str,
+    pub label_messages: &'static str,
+    pub event_log_empty: &'static str,
+    pub col_byte: &'static str,
+    pub col_event: &'static str,
+    pub event_log_order: &'static str,
+    pub event_receive: &'static str,
+    pub event_send: &'static str,
+    pub event_comm_restart: &'static str,
+    pub event_entered_listen_only: &'static str,
+    pub event_unknown: &'static str,
+    pub event_comm_error: &'static str,
+    pub event_character_overrun: &'static str,
+    pub event_listen_only: &'static str,
+    pub event_broadcast: &'static str,
+    pub event_read_exception: &'static str,
+    pub event_abort_exception: &'static str,
+    pub event_busy_exception: &'static str,
+    pub event_nak_exception: &'static str,
+    pub event_write_timeout: &'static str,
+    pub stats_title: &'static str,
+    pub stats_requests: &'static str,
+    pub stats_responses: &'static str,
+    pub stats_exceptions: &'static str,
+    pub stats_timeouts: &'static str,
+    pub stats_frame_errors: &'static str,
+    pub stats_echo_mismatches: &'static str,
+    pub stats_broken_frames: &'static str,
+    pub stats_latency: &'static str,
+    pub stats_latency_values: &'static str,
+    pub stats_error_rate: &'static str,
+    pub ms_value: &'static str,
+
+    // Экспорт и импорт тегов
+    pub format_csv_comma: &'static str,
+    pub merge_replace: &'static str,
+    pub merge_append: &'static str,
+    pub merge_update_by_name: &'static str,
+    pub option_cancel: &'static str,
+    pub invalid_choice_cancelled: &'static str,
+    pub export_title: &'static str,
+    pub export_format_prompt: &'static str,
+    pub export_file_prompt: &'static str,
+    pub export_overwrites_tags: &'static str,
+    pub exported_tags_format: &'static str,
+    pub import_title: &'static str,
+    pub import_format_hint: &'static str,
+    pub import_file_prompt: &'static str,
+    pub import_cancelled: &'static str,
+    pub import_mode_prompt: &'static str,
+    pub tags_in_named_file: &'static str,
+    pub import_issues: &'static str,
+    pub import_not_done: &'static str,
+    pub import_done: &'static str,
+
+    // Изменение регистра
+    pub edit_register_title: &'static str,
+    pub nothing_to_edit: &'static str,
+    pub enter_register_to_edit: &'static str,
+    pub edit_cancelled: &'static str,
+    pub register_not_found: &'static str,
+    pub keep_current_hint: &'static str,
+    pub edit_prompt_name: &'static str,
+    pub edit_prompt_description: &'static str,
+    pub edit_prompt_unit: &'static str,
+    pub edit_prompt_address: &'static str,
+    pub edit_prompt_var_type: &'static str,
+    pub edit_prompt_modbus_type: &'static str,
+    pub edit_prompt_enabled: &'static str,
+    pub option_current: &'static str,
+    pub enter_number_or_name: &'static str,
+    pub invalid_choice_retry: &'static str,
+    pub changes_not_saved: &'static str,
+    pub register_edited: &'static str,
+
+    // Сканирование шины и автоопределение параметров линии
+    pub probe_function_title: &'static str,
+    pub invalid_probe_function: &'static str,
+    pub probe_register_address: &'static str,
+    pub response_timeout_prompt: &'static str,
+    pub probe_data: &'static str,
+    pub probe_exception: &'static str,
+    pub response_from: &'static str,
+    pub out_of_range_unchanged: &'static str,
+    pub bus_scan_title: &'static str,
+    pub line_summary_parity: &'static str,
+    pub first_slave_prompt: &'static str,
+    pub last_slave_prompt: &'static str,
+    pub invalid_slave_range: &'static str,
+    pub bus_scan_started: &'static str,
+    pub scan_stopped: &'static str,
+    pub scan_progress: &'static str,
+    pub no_device_responded: &'static str,
+    pub check_line_hint: &'static str,
+    pub found_devices: &'static str,
+    pub save_address_prompt: &'static str,
+    pub device_address_saved: &'static str,
+    pub auto_detect_title: &'static str,
+    pub auto_detect_started: &'static str,
+    pub auto_detect_stopped: &'static str,
+    pub framing: &'static str,
+    pub response_received: &'static str,
+    pub no_framing_found: &'static str,
+    pub check_address_line_hint: &'static str,
+    pub matching_framings: &'static str,
+    pub stop_bits_ambiguous: &'static str,
+    pub save_framing_prompt: &'static str,
+    pub framing_saved: &'static str,
+
+    // Поиск карты регистров
+    pub discovery_title: &'static str,
+    pub port_and_slave: &'static str,
+    pub scan_area_prompt: &'static str,
+    pub start_address_prompt: &'static str,
+    pub end_address_prompt: &'static str,
+    pub block_size_prompt: &'static str,
+    pub end_before_start: &'static str,
+    pub invalid_block_size: &'static str,
+    pub no_area_selected: &'static str,
+    pub discovery_stop_hint: &'static str,
+    pub sweep_started: &'static str,
+    pub block_label: &'static str,
+    pub function_unsupported_skip: &'static str,
+    pub no_response: &'static str,
+    pub addresses_found: &'static str,
+    pub no_points_found: &'static str,
+    pub found_addresses_title: &'static str,
+    pub label_ranges: &'static str,
+    pub export_draft_prompt: &'static str,
+    pub draft_file_prompt: &'static str,
+    pub draft_overwrites_tags: &'static str,
+    pub draft_description: &'static str,
+    pub draft_saved: &'static str,
+
+    // Импорт таблицы регистров производителя
+    pub vendor_import_title: &'static str,
+    pub vendor_supported_files: &'static str,
+    pub vendor_file_prompt: &'static str,
+    pub workbook_no_sheets: &'static str,
+    pub workbook_sheets: &'static str,
+    pub sheet_number: &'static str,
+    pub sheet_out_of_range: &'static str,
+    pub header_row_prompt: &'static str,
+    pub header_row_out_of_table: &'static str,
+    pub no_data_rows: &'static str,
+    pub data_rows: &'static str,
+    pub table_columns: &'static str,
+    pub column_sample: &'static str,
+    pub column_for_field: &'static str,
+    pub column_hint_cancel: &'static str,
+    pub column_hint_none: &'static str,
+    pub invalid_column: &'static str,
+    pub table_address_convention: &'static str,
+    pub convention_modicon: &'static str,
+    pub convention_zero_based: &'static str,
+    pub convention_one_based: &'static str,
+    pub map_vendor_types: &'static str,
+    pub map_vendor_areas: &'static str,
+    pub skip_value_rows: &'static str,
+    pub fixed_area_prompt: &'static str,
+    pub invalid_choice_import_cancelled: &'static str,
+    pub enable_imported_prompt: &'static str,
+    pub address_not_recognized: &'static str,
+    pub area_not_determined: &'static str,
+    pub type_not_mapped: &'static str,
+    pub preview_title: &'static str,
+    pub and_more: &'static str,
+    pub skipped_rows: &'static str,
+    pub skipped_row: &'static str,
+    pub nothing_to_import: &'static str,
+    pub append_confirm: &'static str,
+
+    // Ввод адреса регистра
+    pub address_numbering_from_one: &'static str,
+    pub address_numbering_from_zero: &'static str,
+    pub address_input_hint: &'static str,
+    pub modicon_address_confirm: &'static str,
+    pub invalid_address_input: &'static str,
+    pub offset_65535_one_based: &'static str,
+    // Поиск портов и привязка к адаптеру
+    pub scanning_ports: &'static str,
+    pub port_found: &'static str,
+    pub serial_ports_not_found: &'static str,
+    pub ports_found_total: &'static str,
+    pub port_scan_error: &'static str,
+    pub bound_adapter_found: &'static str,
+    pub bound_adapter_not_found: &'static str,
+    pub bound_adapter_ambiguous: &'static str,
+    pub bound_adapter_using: &'static str,
+    pub bound_adapter_search_error: &'static str,
+    pub registers_sorted: &'static str,
+
+    // Обмен списком тегов из командной строки
+    pub unknown_file_extension: &'static str,
+    pub exported_tags: &'static str,
+    pub export_error: &'static str,
+    pub unknown_import_mode: &'static str,
+    pub import_error: &'static str,
+}
+
+static RU: Messages = Messages {
+    choose_action: "Выберите действие:",
+    your_choice: "Ваш выбор",
+    back_to_main_menu: "Назад в главное меню",
+    press_enter: "Нажмите Enter для продолжения...",
+    invalid_format_retry: "Неверный формат! Повторите ввод.",
+    invalid_choice_range: "Неверный выбор! Введите число от 1 до {}",
+    invalid_choice_value: "Недопустимый выбор: {}! Введите число от 1 до {}.",
+    invalid_format_range: "Неверный формат! Введите число от 1 до {}.",
+    invalid_choice_back: "Неверный выбор! Возвращаемся в главное меню.",
+    enter_number_range: "Введите номер (1-{}): ",
+    choose_number: "Введите номер (1-{})",
+    selected: "Выбрано: {}",
+    available_options: "Доступные варианты:",
+    unknown: "неизвестно",
+    baud: "бод",
+    timeout: "Таймаут",
+    error_with: "Ошибка: {}",
+    word_address: "адрес",
+    word_type: "тип",
+    word_quantity: "количество",
+    shutting_down: "Завершение программы...",
+    unknown_language: "Неизвестный язык '{}' (ожидается ru или en)",
+
+    no_ports_found: "Доступные последовательные порты не найдены!",
+    no_ports_exit: "выйти",
+    no_ports_retry: "повторить поиск",
+    exiting: "Выход из программы...",
+    retrying_port_scan: "Повторяем поиск портов...",
+    no_ports_invalid_choice: "Неверный выбор! Введите 0 для выхода или 1 для повторного поиска.",
+    select_port: "Выберите порт для подключения:",
+    enter_port_number: "Введите номер порта (1-{}): ",
+    port_selected: "Выбран порт: {}",
+
+    device_address_title: "Выбор адреса устройства Modbus",
+    device_address_range: "Допустимый диапазон: {}-{}",
+    enter_device_address: "Введите адрес устройства ({}-{}): ",
+    device_address_selected: "Выбран адрес устройства: {}",
+    device_address_invalid: "Недопустимый адрес: {}! Введите значение от {} до {}.",
+    baud_rate_title: "Выбор скорости передачи данных RS-485",
+    available_baud_rates: "Доступные скорости:",
+    enter_baud_rate_number: "Введите номер скорости (1-{}): ",
+    baud_rate_selected: "Выбрана скорость: {} бод",
+    baud_rate_other: "Другая скорость (ввести вручную)",
+    enter_custom_baud_rate: "Скорость, бод",
+    invalid_baud_rate: "Скорость должна быть больше нуля",
+    parity_title: "Выбор четности для RS-485",
+    available_parity: "Доступные варианты четности:",
+    parity_none_hint: "без контроля четности",
+    parity_even_hint: "четная четность",
+    parity_odd_hint: "нечетная четность",
+    parity_mark_hint: "бит четности всегда 1",
+    parity_space_hint: "бит четности всегда 0",
+    enter_parity_number: "Введите номер четности (1-5): ",
+    parity_selected: "Выбрана четность: {}",
+    stop_bits_title: "Выбор количества стоп-битов для RS-485",
+    stop_bits_one: "1 стоп-бит",
+    stop_bits_two: "2 стоп-бита",
+    stop_bits_one_hint: "стандартная настройка",
+    stop_bits_two_hint: "повышенная надежность",
+    enter_stop_bits_number: "Введите номер стоп-битов (1-2): ",
+    advanced_line_prompt: "Настроить дополнительные параметры линии (биты данных, управление потоком, RTS/DTR, интервалы RTU)? (y/n)",
+    data_bits_prompt: "Бит данных (5-8)",
+    invalid_data_bits: "Число бит данных должно быть от 5 до 8",
+    flow_none: "нет",
+    rts_unchanged: "не изменять (адаптер переключает направление сам)",
+    rts_on: "включена",
+    rts_off: "выключена",
+    rts_transmit: "включается на время передачи (направление RS-485)",
+    rts_transmit_inverted: "выключается на время передачи (инверсное направление RS-485)",
+    rts_by_flow_control: "Линией RTS управляет драйвер порта (RTS/CTS)",
+    pin_unchanged: "не изменять",
+    pin_on: "включена",
+    pin_off: "выключена",
+    option_number: "Номер варианта",
+    delay_before_send_prompt: "Пауза между включением передатчика и запросом, мс",
+    delay_after_send_prompt: "Пауза между концом запроса и переключением на прием, мс",
+    frame_timing_computed: "Интервалы RTU по скорости: t1.5 = {} мкс, t3.5 = {} мкс",
+    inter_char_timeout_prompt: "Пауза между символами t1.5, мкс (auto - по скорости, 0 - без проверки)",
+    silent_interval_prompt: "Пауза между кадрами t3.5, мкс (auto - по скорости)",
+    discard_broken_frames_prompt: "Отбрасывать кадры с паузой между символами больше t1.5? n - только подсчитывать, для медленных шлюзов (y/n)",
+    invalid_silent_interval: "Пауза между кадрами должна быть больше нуля",
+
+    errors_in_file: "ошибки в {}:\n{}",
+    no_tag_issues: "Проблем в описании тегов не найдено",
+    issues_found: "Найдено проблем: {}",
+    checking_file: "Проверка файла {}",
+    tags_in_file: "Тегов в файле: {}",
+    file_read_error: "Ошибка чтения файла: {}",
+    registers_load_failed: "Не удалось загрузить регистры: {}",
+    check_tags_file_hint: "Убедитесь, что файл tags.csv существует и корректен",
+
+    connection_settings_title: "=== Текущие настройки связи ===",
+    connection_parameters: "Параметры подключения:",
+    label_port: "COM-порт:",
+    label_port_binding: "Привязка к адаптеру:",
+    label_device_address: "Адрес устройства:",
+    label_baud_rate: "Скорость:",
+    label_parity: "Четность:",
+    label_stop_bits: "Стоп-биты:",
+    label_data_bits: "Биты данных:",
+    label_flow_control: "Управление потоком:",
+    label_rts: "Линия RTS:",
+    label_dtr: "Линия DTR:",
+    label_rs485_delays: "Паузы RS-485 (до/после передачи):",
+    ms: "мс",
+    label_frame_timing: "Интервалы RTU (t1.5 / t3.5):",
+    frame_timing_auto: "(по скорости)",
+    frame_timing_manual: "(заданы вручную)",
+    broken_frames_counted: "кадры с паузой больше t1.5 только подсчитываются",
+    us: "мкс",
+    file_info: "Информация о файле:",
+    label_version: "Версия:",
+    label_file_updated: "Обновлен:",
+    stored_device_info: "Идентификация устройства (адрес {}, прочитана {}):",
+    settings_load_error: "Ошибка загрузки настроек: {}",
+    using_default_settings: "Будут использованы настройки по умолчанию.",
+    change_connection_title: "=== Изменение настроек связи ===",
+    adapter: "Адаптер: {}",
+    bind_to_adapter_prompt: "Привязать настройки к этому адаптеру (порт будет найден при переподключении)? (y/n)",
+    settings_saved: "Настройки успешно сохранены!",
+    settings_save_error: "Ошибка сохранения настроек: {}",
+
+    display_settings_title: "=== Настройки отображения ===",
+    current_settings: "Текущие настройки:",
+    label_address_notation: "Запись адресов:",
+    label_tags_numbering: "Нумерация адресов в tags.csv:",
+    label_language: "Язык интерфейса:",
+    numbering_from_one: "с 1",
+    numbering_from_zero: "с 0",
+    change_address_notation: "Изменить запись адресов в списке регистров",
+    toggle_tags_numbering: "Переключить нумерацию адресов в tags.csv на {}",
+    change_language: "Изменить язык интерфейса",
+    notation_number: "Номер записи",
+    language_number: "Номер языка",
+    language_auto: "по локали системы ({})",
+    language_auto_option: "Автоматически (по локали системы)",
+    invalid_choice_unchanged: "Неверный выбор, настройки не изменены",
+    display_settings_saved: "Настройки отображения сохранены",
+    tag_address_too_large: "Тег '{}' имеет адрес 65535 - нумерация с 1 для него невозможна",
+    tags_file_renumbered: "tags.csv перезаписан с нумерацией адресов {} (адреса в протоколе не изменились)",
+    notation_decimal: "Десятичная (как в tags.csv)",
+    notation_hex: "Шестнадцатеричная (0x0014)",
+    notation_modicon: "Modicon (30011, 40021)",
+
+    port_opened: "Последовательный порт {} успешно открыт",
+    port_open_error: "Ошибка открытия последовательного порта {}: {}",
+    rtu_context_created: "Modbus RTU контекст успешно создан",
+    rtu_context_error: "Ошибка создания Modbus RTU контекста: {}",
+    polling_title: "=== Запуск опроса устройства ===",
+    connection_settings_loaded: "Настройки подключения успешно загружены",
+    connection_settings_load_error: "Ошибка загрузки настроек подключения: {}",
+    save_settings_hint: "Убедитесь, что настройки сохранены (пункт 2 в главном меню)",
+    save_settings_first: "Сначала сохраните настройки связи (пункт 2 в главном меню)",
+    prompt_device_address: "Адрес устройства",
+    port_value: "Порт: {}",
+    slave_address_out_of_range: "Адрес устройства должен быть в диапазоне {}..{}",
+    registers_config_loaded: "Конфигурация регистров успешно загружена",
+    registers_config_load_error: "Ошибка загрузки конфигурации регистров: {}",
+    no_active_registers: "Нет активных регистров для опроса!",
+    no_active_registers_hint: "Проверьте файл tags.csv и убедитесь, что есть регистры с enabled: true",
+    used_connection_settings: "Используемые настройки подключения:",
+    active_registers: "Активные регистры для опроса:",
+    polling_started: "Начинается циклический опрос устройства (каждую секунду)...",
+    polling_stop_hint: "Нажмите Esc, q или Ctrl+C для остановки опроса",
+    polling_stopped_by_user: "Опрос остановлен пользователем",
+    port_close_error: "Ошибка закрытия порта {}: {}",
+    port_closed: "Последовательный порт {} закрыт",
+    polling_error: "Ошибка при опросе: {}",
+
+    summary_title: "=== Итоги сеанса опроса ===",
+    label_started: "Начало:",
+    label_duration: "Длительность:",
+    label_cycles: "Циклов опроса:",
+    label_failed_cycles: "Циклов с ошибками:",
+    stats_by_register: "Статистика по регистрам:",
+    col_errors: "Ошибок",
+    col_last_value: "Последнее значение",
+    col_time: "Время",
+
+    registers_title: "=== Конфигурация регистров ===",
+    registers_loaded: "Регистры успешно загружены из файла",
+    config_info: "Информация о конфигурации:",
+    label_description: "Описание:",
+    label_config_updated: "Обновлено:",
+    label_file_numbering: "Нумерация адресов в файле:",
+    registers_stats: "Статистика регистров:",
+    label_total_registers: "Всего регистров:",
+    label_enabled: "Активных:",
+    label_disabled: "Отключенных:",
+    no_registers: "Регистры не найдены!",
+    registers_list: "Список регистров:",
+    col_name: "Имя",
+    col_description: "Описание",
+    col_address: "Адрес",
+    col_type: "Тип",
+    col_unit: "Ед.",
+    col_modbus_type: "Modbus тип",
+    col_status: "Статус",
+    status_enabled: "Активен",
+    status_disabled: "Отключен",
+    registers_load_error: "Ошибка загрузки регистров: {}",
+
+    delete_register_title: "=== Удаление регистра ===",
+    nothing_to_delete: "Список регистров пуст — удалять нечего",
+    enter_register_to_delete: "Введите номер регистра для удаления (1-{}), либо 0 для отмены: ",
+    invalid_number_input: "Неверный ввод. Ожидалось число.",
+    deletion_cancelled: "Удаление отменено",
+    number_out_of_range: "Номер вне диапазона (1-{})",
+    register_deleted: "Регистр '{}' (адрес {}) удалён",
+
+    add_register_title: "=== Добавление регистра ===",
+    prompt_name: "Какое имя регистра? (name):",
+    name_empty: "Имя не может быть пустым",
+    prompt_description: "Описание? (description):",
+    prompt_unit: "Единица измерения? (unit, Enter - без единицы):",
+    prompt_address: "Адрес? (address)",
+    prompt_var_type: "Тип переменной? (var_type)",
+    invalid_var_type: "Неверный выбор var_type",
+    modbus_type_from_modicon: "Тип Modbus регистра (по адресу Modicon):",
+    prompt_modbus_type: "Тип Modbus регистра? (modbus_type)",
+    read_function: "ф-ция чтения 0x{}",
+    invalid_modbus_type: "Неверный выбор modbus_type",
+    prompt_enabled: "Разрешено ли запрашивать этот регистр? (enabled)",
+    enabled_yes: "Да (True)",
+    enabled_no: "Нет (False)",
+    invalid_enabled: "Неверный выбор для enabled",
+    register_not_added: "Регистр не добавлен",
+    register_added: "Регистр добавлен",
+
+    menu_show_connection: "Показать настройки связи",
+    menu_change_connection: "Изменить настройки связи",
+    menu_start_polling: "Начать опрос",
+    menu_registers: "Регистры",
+    menu_tools: "Инструменты",
+    menu_display_settings: "Настройки отображения",
+    menu_dashboard: "Опрос в полноэкранном режиме",
+    menu_exit: "Выйти",
+    invalid_choice_default_polling: "Неверный выбор! Используется пункт 3 по умолчанию.",
+    registers_menu_title: "=== Управление регистрами ===",
+    menu_show_registers: "Показать регистры",
+    menu_delete_register: "Удалить регистр",
+    menu_add_register: "Добавить регистр",
+    menu_edit_register: "Изменить регистр",
+    menu_sort_by_address: "Отсортировать по адресу",
+    menu_check_tags: "Проверить tags.csv",
+    menu_export: "Экспорт (JSON, YAML, TOML, CSV)",
+    menu_import: "Импорт (JSON, YAML, TOML, CSV)",
+    check_tags_title: "=== Проверка tags.csv ===",
+    sort_done: "Сортировка завершена",
+    sort_error: "Ошибка сортировки: {}",
+    tools_menu_title: "=== Инструменты ===",
+    menu_bus_scan: "Сканировать шину (поиск адресов устройств)",
+    menu_auto_detect: "Автоопределение скорости и формата кадра",
+    menu_register_discovery: "Поиск карты регистров устройства",
+    menu_vendor_import: "Импорт таблицы регистров производителя (CSV/XLSX)",
+    menu_raw_console: "Консоль запросов Modbus (0x0F, 0x16, 0x17, произвольный PDU)",
+    menu_device_info: "Информация об устройстве (производитель, модель, версия)",
+    menu_line_diagnostics: "Диагностика линии (эхо-тест, счетчики и журнал событий устройства)",
+    scan_error: "Ошибка сканирования: {}",
+    auto_detect_error: "Ошибка автоопределения: {}",
+    discovery_error: "Ошибка поиска регистров: {}",
+    vendor_import_error: "Ошибка импорта таблицы: {}",
+    raw_console_error: "Ошибка консоли запросов: {}",
+    device_info_error: "Ошибка чтения информации об устройстве: {}",
+    line_diagnostics_error: "Ошибка диагностики линии: {}",
+
+    exception_illegal_function: "Недопустимая функция",
+    exception_illegal_data_address: "Недопустимый адрес данных",
+    exception_illegal_data_value: "Недопустимое значение данных",
+    exception_server_failure: "Отказ устройства",
+    exception_acknowledge: "Запрос принят, выполняется",
+    exception_server_busy: "Устройство занято",
+    exception_memory_parity: "Ошибка четности памяти",
+    exception_gateway_path: "Путь шлюза недоступен",
+    exception_gateway_target: "Устройство за шлюзом не отвечает",
+    exception_unknown: "Неизвестное исключение",
+    read_exception: "исключение {} ({})",
+    read_crc: "ошибка CRC в ответе: {}",
+    read_malformed: "неверный ответ: {}",
+    write_read_only: "область только для чтения",
+    write_invalid_value: "значение '{}' не подходит для типа {}",
+    write_invalid_bit: "ожидается .N=1 или .N=0, получено '{}'",
+    write_bit_area: "запись бита возможна только в holding_register",
+    write_bit_type: "запись бита невозможна для типа {}",
+    write_bit_range: "бит {} вне диапазона 0..{} для типа {}",
+    write_unknown_tag: "тег не найден",
+    write_stopped: "опрос остановлен",
+
+    issue_empty_name: "пустое имя",
+    issue_duplicate_name: "имя повторяется (впервые в строке {})",
+    issue_invalid_combination: "тип '{}' недопустим для '{}' (для coil и discrete_input допустим только bool)",
+    issue_address_out_of_range: "значение занимает адреса до {}, что больше 65535",
+    issue_one_based_overflow: "адрес 65535 нельзя записать в tags.csv с нумерацией с 1",
+    issue_zero_address_one_based: "адрес 0 недопустим при нумерации с 1",
+    issue_overlap: "адреса пересекаются с тегом '{}' (строка {})",
+    issue_parse: "ошибка разбора ({}): {}",
+    issue_column_count: "столбцов {}, ожидалось {}",
+    tag_issue: "строка {}, тег '{}': {}",
+
+    dashboard_title: "=== Опрос в полноэкранном режиме ===",
+    dashboard_frame_title: " Modbus RTU - опрос ",
+    state_connecting: " ПОДКЛЮЧЕНИЕ ",
+    state_paused: " ПАУЗА ",
+    state_polling: " ОПРОС ",
+    seconds_value: "{} с",
+    dashboard_link: "  {}  {} бод {}  адрес {}  ",
+    dashboard_cycle: "цикл {}",
+    dashboard_failed_period: "  с ошибками {}  период {}",
+    hint_window: " окно  ",
+    hint_tag: " тег  ",
+    hint_pause: " пауза  ",
+    hint_resume: " продолжить  ",
+    hint_back_to_table: " к таблице",
+    hint_select: " выбор  ",
+    hint_filter: " фильтр  ",
+    hint_write: " запись  ",
+    hint_chart: " график  ",
+    hint_quit: " выход",
+    active_filter: "   фильтр: {}",
+    filter_prompt: "Фильтр: ",
+    filter_hint: "  (Enter - применить, Esc - сбросить)",
+    write_prompt: "Запись в {} ({}): ",
+    write_bit_hint: "  (.N=0/1 - бит, Enter - записать, Esc - отмена)",
+    write_hint: "  (Enter - записать, Esc - отмена)",
+    tag_read_only: "{}: {} доступен только для чтения",
+    col_value: "Значение",
+    col_trend: "Тренд",
+    col_quality: "Качество",
+    col_updated: "Обновлено",
+    quality_timeout: "таймаут",
+    quality_exception: "исключение",
+    quality_error: "ошибка",
+    chart_no_data: " {} - нет данных за {} мин ",
+    chart_stats: " {} за {} мин: мин {}  макс {}  сред {}  точек {} ",
+    chart_minutes_ago: "-{} мин",
+    chart_now: "сейчас",
+    write_done: "{} = {} записано",
+    write_failed: "Ошибка записи {}: {}",
+    dashboard_polling_started: "Порт {} открыт, опрос начат",
+    polling_stopped: "Опрос остановлен",
+
+    console_title: "=== Консоль запросов Modbus ===",
+    line_summary: "Порт: {}, скорость: {} бод, формат: {}",
+    console_slave_set: "Адрес устройства: {}",
+    console_timeout_set: "Таймаут ответа: {} мс",
+    console_help_title: "Команды (код функции - hex, адреса и значения - десятичные или 0x..):",
+    console_invalid_value: "Неверное значение {}: '{}'",
+    console_invalid_byte: "Неверный байт '{}'",
+    console_expected_bit: "Ожидается 0 или 1, получено '{}'",
+    console_usage: "Формат: {}",
+    console_slave_range: "Адрес устройства должен быть в диапазоне 0..{}",
+    console_tag_not_found: "Тег '{}' не найден в tags.csv",
+    console_unknown_command: "Неизвестная команда '{}', справка - help",
+    console_count_range: "Количество должно быть в диапазоне 1..{}",
+    console_read_count_range: "Количество чтения должно быть в диапазоне 1..{}",
+    console_max_coils: "Не больше {} coils за запрос",
+    console_max_registers: "Не больше {} регистров за запрос",
+    console_max_write_registers: "Не больше {} регистров записи за запрос",
+    console_use_raw: "Функция {} задается командой raw",
+    console_broadcast_sent: "Широковещательный запрос отправлен, ответа не ожидается",
+    console_response_time: "Ответ за {} мс",
+    console_exception: "Исключение {}: {} ({} мс)",
+    console_written_single: "Записано: адрес {} = {}",
+    console_written_multiple: "Записано {} начиная с адреса {}",
+    console_mask_written: "Адрес {}: AND {}, OR {}",
+    console_data: "Данные: {}",
+    what_slave_address: "адреса устройства",
+    what_timeout: "таймаута",
+    what_bit_number: "номера бита",
+    what_address: "адреса",
+    what_quantity: "количества",
+    what_value: "значения",
+    what_and_mask: "маски AND",
+    what_or_mask: "маски OR",
+    what_read_address: "адреса чтения",
+    what_write_address: "адреса записи",
+    usage_slave: "slave <адрес>",
+    usage_timeout: "timeout <мс>",
+    usage_bit: "bit <тег> <бит> <0|1>",
+    usage_raw: "raw <функция> [байт ...]",
+    usage_read: "<01..04> <адрес> <кол-во>",
+    usage_write_coil: "05 <адрес> <0|1>",
+    usage_write_register: "06 <адрес> <значение>",
+    usage_write_coils: "0F <адрес> <0|1> ...",
+    usage_write_registers: "10 <адрес> <значение> ...",
+    usage_mask_write: "16 <адрес> <AND> <OR>",
+    usage_read_write: "17 <адр.чт> <кол-во> <адр.зап> <значение> ...",
+    console_commands: [
+        ("01 <адрес> <кол-во>", "чтение coils"),
+        ("02 <адрес> <кол-во>", "чтение discrete inputs"),
+        ("03 <адрес> <кол-во>", "чтение holding registers"),
+        ("04 <адрес> <кол-во>", "чтение input registers"),
+        ("05 <адрес> <0|1>", "запись одного coil"),
+        ("06 <адрес> <значение>", "запись одного регистра"),
+        ("0F <адрес> <0|1> ...", "запись нескольких coils"),
+        ("10 <адрес> <значение> ...", "запись нескольких регистров"),
+        ("16 <адрес> <AND> <OR>", "запись регистра по маске"),
+        ("17 <адр.чт> <кол-во> <адр.зап> <знач> ...", "чтение и запись за один запрос"),
+        ("bit <тег> <бит> <0|1>", "запись одного бита тега (функция 0x16)"),
+        ("raw <функция> [байт ...]", "произвольный PDU в hex"),
+        ("slave <адрес>", "адрес устройства (0 - широковещательный)"),
+        ("timeout <мс>", "таймаут ответа"),
+        ("help, q", "справка, выход"),
+    ],
+
+    device_info_title: "=== Информация об устройстве (0x2B/0x0E) ===",
+    device_id_categories: "Категория объектов:",
+    device_id_basic: "basic - производитель, код изделия, версия",
+    device_id_regular: "regular - плюс URL, название, модель, приложение",
+    device_id_extended: "extended - плюс частные объекты производителя",
+    device_id_invalid_category: "Неверный выбор категории",
+    device_id_unsupported: "Устройство не поддерживает эту категорию (исключение {})",
+    device_id_unsupported_hint: "Попробуйте категорию basic или функцию 0x11 в консоли запросов",
+    device_id_read_error: "Ошибка чтения идентификации: {}",
+    label_conformity: "Уровень соответствия:",
+    conformity_stream_only: "{}, только поток",
+    conformity_individual: "{}, поток и отдельные объекты",
+    conformity_unknown: "неизвестный уровень",
+    col_object: "Объект",
+    device_id_save_prompt: "
+Сохранить в профиле подключения? (y/n)",
+    device_id_saved: "Идентификация сохранена в connect_settings.json",
+
+    diag_title: "=== Диагностика линии (0x08, 0x0B, 0x0C) ===",
+    diag_actions: "Действия:",
+    diag_menu_echo: "эхо-тест (0x08/0x00 Return Query Data)",
+    diag_menu_counters: "счетчики устройства (0x08/0x0B-0x12, 0x0B)",
+    diag_menu_event_log: "журнал событий (0x0C)",
+    diag_menu_clear: "сбросить счетчики устройства (0x08/0x0A)",
+    diag_menu_stats: "статистика сеанса",
+    diag_menu_exit: "выход",
+    diag_invalid_choice: "Неверный выбор",
+    diag_clear_prompt: "Сбросить счетчики и журнал событий устройства? (y/n)",
+    diag_cleared: "Счетчики устройства сброшены, статистика сеанса начата заново",
+    diag_clear_error: "Ошибка сброса счетчиков",
+    diag_function_unsupported: "Устройство не поддерживает эту функцию диагностики",
+    echo_count_prompt: "Количество запросов",
+    echo_length_prompt: "Длина данных, байт",
+    echo_invalid: "Количество должно быть больше 0, длина - 1..{} байт",
+    echo_started: "Эхо-тест, нажмите Ctrl+C для остановки...",
+    echo_stopped: "Эхо-тест остановлен пользователем",
+    echo_progress: "Запрос {}/{}...",
+    echo_request: "Запрос",
+    echo_corrupted: "данные искажены",
+    echo_unsupported: "Устройство не поддерживает функцию 0x08 (исключение 0x01)",
+    echo_summary: "Отправлено: {}, без ошибок: {}, искажено: {}, таймаутов: {}, ошибок кадра: {}",
+    counters_error: "Ошибка чтения счетчиков",
+    col_indicator: "Показатель",
+    col_device: "Устройство",
+    col_master_session: "Мастер (сеанс)",
+    exception_short: "искл. {}",
+    counter_bus_messages: "Сообщений на линии",
+    counter_bus_comm_errors: "Ошибок CRC",
+    counter_bus_exceptions: "Исключений",
+    counter_slave_messages: "Сообщений устройству",
+    counter_slave_no_response: "Без ответа",
+    counter_slave_nak: "Ответов NAK",
+    counter_slave_busy: "Ответов \"занято\"",
+    counter_character_overrun: "Переполнений приемника",
+    master_requests: "запросов",
+    master_frame_errors: "ошибок кадра",
+    master_exceptions: "исключений",
+    master_responses: "ответов",
+    master_timeouts: "таймаутов",
+    device_busy: "занято",
+    device_ready: "готово",
+    comm_events_counter: "Событий связи (0x0B)",
+    comm_events_details: "ответов: {}, состояние: {}",
+    counters_note: "Устройство считает с включения или сброса (пункт 4), мастер - {} с начала сеанса",
+    counters_noise_hint: "Рост ошибок CRC и таймаутов при росте числа сообщений - признак помех на линии",
+    event_log_error: "Ошибка чтения журнала событий",
+    label_device_state: "Состояние:",
+    label_events: "событий:",
+    label_messages: "сообщений:",
+    event_log_empty: "Журнал событий пуст",
+    col_byte: "Байт",
+    col_event: "Событие",
+    event_log_order: "Последнее событие - первое в списке",
+    event_receive: "прием запроса",
+    event_send: "отправка ответа",
+    event_comm_restart: "перезапуск связи",
+    event_entered_listen_only: "переход в режим только прослушивания",
+    event_unknown: "неизвестное событие {}",
+    event_comm_error: "ошибка связи",
+    event_character_overrun: "переполнение символов",
+    event_listen_only: "режим только прослушивания",
+    event_broadcast: "широковещательный запрос",
+    event_read_exception: "исключение чтения (1-3)",
+    event_abort_exception: "исключение аварии (4)",
+    event_busy_exception: "исключение занятости (5-6)",
+    event_nak_exception: "исключение NAK (7)",
+    event_write_timeout: "таймаут записи",
+    stats_title: "Статистика сеанса ({}):",
+    stats_requests: "Запросов:",
+    stats_responses: "Ответов:",
+    stats_exceptions: "Исключений:",
+    stats_timeouts: "Таймаутов:",
+    stats_frame_errors: "Ошибок кадра:",
+    stats_echo_mismatches: "Искажено эхо:",
+    stats_broken_frames: "Разрывов > t1.5:",
+    stats_latency: "Время ответа:",
+    stats_latency_values: "мин {}, сред {}, макс {}",
+    stats_error_rate: "Доля ошибок:",
+    ms_value: "{} мс",
+
+    format_csv_comma: "CSV (разделитель - запятая)",
+    merge_replace: "Заменить весь список",
+    merge_append: "Добавить в конец списка",
+    merge_update_by_name: "Обновить по имени (новые теги добавить в конец)",
+    option_cancel: "Отмена",
+    invalid_choice_cancelled: "Неверный выбор! Операция отменена.",
+    export_title: "=== Экспорт тегов ===",
+    export_format_prompt: "Формат файла:",
+    export_file_prompt: "Файл для экспорта",
+    export_overwrites_tags: "Экспорт не может перезаписать основной файл tags.csv",
+    exported_tags_format: "Экспортировано тегов: {} ({}) в {}",
+    import_title: "=== Импорт тегов ===",
+    import_format_hint: "Формат определяется по расширению: .json, .yaml/.yml, .toml, .csv",
+    import_file_prompt: "Файл для импорта: ",
+    import_cancelled: "Импорт отменен",
+    import_mode_prompt: "Режим импорта:",
+    tags_in_named_file: "Тегов в файле {}: {}",
+    import_issues: "Проблемы в списке тегов после импорта (номера строк - в итоговом tags.csv):",
+    import_not_done: "Импорт не выполнен, tags.csv не изменен",
+    import_done: "Импорт выполнен: добавлено {}, обновлено {}, удалено {}. Всего тегов: {}",
+
+    edit_register_title: "=== Изменение регистра ===",
+    nothing_to_edit: "Список регистров пуст — изменять нечего",
+    enter_register_to_edit: "Введите номер (1-{}) или имя регистра, либо 0 для отмены: ",
+    edit_cancelled: "Изменение отменено",
+    register_not_found: "Регистр '{}' не найден",
+    keep_current_hint: "Enter - оставить текущее значение",
+    edit_prompt_name: "Имя (name)",
+    edit_prompt_description: "Описание (description)",
+    edit_prompt_unit: "Единица измерения (unit, '-' - без единицы)",
+    edit_prompt_address: "Адрес (address)",
+    edit_prompt_var_type: "Тип переменной (var_type)",
+    edit_prompt_modbus_type: "Тип Modbus регистра (modbus_type)",
+    edit_prompt_enabled: "Разрешено ли запрашивать (enabled) (y/n)",
+    option_current: " (текущий)",
+    enter_number_or_name: "Введите номер или имя (1-{}) [{}]: ",
+    invalid_choice_retry: "Неверный выбор! Повторите ввод.",
+    changes_not_saved: "Изменения не сохранены",
+    register_edited: "Регистр '{}' изменен",
+
+    probe_function_title: "Функция пробного запроса:",
+    invalid_probe_function: "Неверный выбор функции",
+    probe_register_address: "Адрес регистра для запроса",
+    response_timeout_prompt: "Таймаут ответа, мс",
+    probe_data: "данные {}",
+    probe_exception: "исключение {}",
+    response_from: "Ответ от",
+    out_of_range_unchanged: "Номер вне диапазона, настройки не изменены",
+    bus_scan_title: "=== Сканирование шины RS-485 ===",
+    line_summary_parity: "Порт: {}, скорость: {} бод, четность: {}, стоп-биты: {}",
+    first_slave_prompt: "Начальный адрес устройства",
+    last_slave_prompt: "Конечный адрес устройства",
+    invalid_slave_range: "Неверный диапазон адресов! Допустимо {}-{}",
+    bus_scan_started: "Сканирование адресов {}-{} ({}), нажмите Ctrl+C для остановки...",
+    scan_stopped: "Сканирование остановлено пользователем",
+    scan_progress: "Адрес {}...",
+    no_device_responded: "Ни одно устройство не ответило",
+    check_line_hint: "Проверьте скорость, четность и подключение линии A/B",
+    found_devices: "Найденные устройства:",
+    save_address_prompt: "Сохранить адрес в настройках? Номер устройства (1-{}), 0 - не сохранять",
+    device_address_saved: "Адрес устройства {} сохранен в настройках",
+    auto_detect_title: "=== Автоопределение параметров линии ===",
+    auto_detect_started: "Перебор {} комбинаций для адреса {}, нажмите Ctrl+C для остановки...",
+    auto_detect_stopped: "Автоопределение остановлено пользователем",
+    framing: "{} бод, {}, {} стоп-бит",
+    response_received: "Ответ получен:",
+    no_framing_found: "Устройство не ответило ни на одной комбинации параметров",
+    check_address_line_hint: "Проверьте адрес устройства, подключение линии A/B и выбранный порт",
+    matching_framings: "Подходящие параметры линии:",
+    stop_bits_ambiguous: "Количество стоп-битов часто не различимо по ответу - при сомнении выбирайте вариант из документации",
+    save_framing_prompt: "Сохранить параметры в настройках? Номер варианта (1-{}), 0 - не сохранять",
+    framing_saved: "Параметры линии сохранены в настройках",
+
+    discovery_title: "=== Поиск карты регистров ===",
+    port_and_slave: "Порт: {}, адрес устройства: {}",
+    scan_area_prompt: "Сканировать эту область? (y/n)",
+    start_address_prompt: "Начальный адрес",
+    end_address_prompt: "Конечный адрес",
+    block_size_prompt: "Размер блока",
+    end_before_start: "Конечный адрес меньше начального - область пропущена",
+    invalid_block_size: "Размер блока должен быть от 1 до {} - область пропущена",
+    no_area_selected: "Не выбрано ни одной области для сканирования",
+    discovery_stop_hint: "Нажмите Ctrl+C для остановки сканирования",
+    sweep_started: "Сканирование: {} {}-{}",
+    block_label: "Блок {} x {}",
+    function_unsupported_skip: "Функция не поддерживается устройством - область пропущена",
+    no_response: "нет ответа",
+    addresses_found: "Найдено адресов: {}",
+    no_points_found: "Не найдено ни одного отвечающего адреса",
+    found_addresses_title: "=== Найденные адреса ===",
+    label_ranges: "Диапазоны:",
+    export_draft_prompt: "Сохранить найденные адреса как черновик tags.csv? (y/n)",
+    draft_file_prompt: "Файл для черновика тегов",
+    draft_overwrites_tags: "Черновик не может перезаписать основной файл tags.csv",
+    draft_description: "Найден при сканировании, значение {}",
+    draft_saved: "Черновик из {} тегов сохранен в {}",
+
+    vendor_import_title: "=== Импорт таблицы регистров производителя ===",
+    vendor_supported_files: "Поддерживаются файлы .csv, .xlsx, .xls, .ods",
+    vendor_file_prompt: "Файл таблицы: ",
+    workbook_no_sheets: "в книге нет листов",
+    workbook_sheets: "Листы книги:",
+    sheet_number: "Номер листа",
+    sheet_out_of_range: "номер листа вне диапазона",
+    header_row_prompt: "Номер строки с заголовками столбцов",
+    header_row_out_of_table: "Строка заголовка вне таблицы",
+    no_data_rows: "В таблице нет строк с данными",
+    data_rows: "Строк с данными: {}",
+    table_columns: "Столбцы таблицы:",
+    column_sample: "(пример: {})",
+    column_for_field: "Столбец для поля {}{}",
+    column_hint_cancel: ", 0 - отмена",
+    column_hint_none: ", 0 - нет",
+    invalid_column: "Неверный номер столбца! Повторите ввод.",
+    table_address_convention: "Запись адресов в таблице:",
+    convention_modicon: "Modicon (40001, 30011, 400001 - область по первой цифре)",
+    convention_zero_based: "Смещение от 0 (0, 20, 0x0014)",
+    convention_one_based: "Номер от 1 (1, 21, 0x0015)",
+    map_vendor_types: "Сопоставление типов производителя (var_type):",
+    map_vendor_areas: "Сопоставление областей производителя (modbus_type):",
+    skip_value_rows: "пропускать строки с этим значением",
+    fixed_area_prompt: "Область Modbus для всех тегов:",
+    invalid_choice_import_cancelled: "Неверный выбор! Импорт отменен.",
+    enable_imported_prompt: "Включить опрос импортированных тегов? (y/n)",
+    address_not_recognized: "адрес '{}' не распознан",
+    area_not_determined: "область Modbus не определена",
+    type_not_mapped: "тип '{}' не сопоставлен",
+    preview_title: "Предпросмотр ({} тегов):",
+    and_more: "... и еще {}",
+    skipped_rows: "Пропущено строк: {}",
+    skipped_row: "строка {}: {}",
+    nothing_to_import: "Нет тегов для импорта",
+    append_confirm: "Добавить теги в конец tags.csv? (y/n)",
+
+    address_numbering_from_one: "нумерация с 1",
+    address_numbering_from_zero: "нумерация с 0",
+    address_input_hint: "{} ({}, 0x0014 или 40021)",
+    modicon_address_confirm: "Адрес {} в записи Modicon: {}, смещение {}? (y - да, n - десятичный адрес) [y]:",
+    invalid_address_input: "Неверный адрес. Ожидалось число {}, 0x0000..0xFFFF или адрес Modicon",
+    offset_65535_one_based: "Смещение 65535 нельзя записать в tags.csv с нумерацией с 1 (адрес 65536 не помещается в поле)",
+
+    scanning_ports: "Сканирование доступных последовательных портов...",
+    port_found: "  Найден: {} ({})",
+    serial_ports_not_found: "  Последовательные порты не найдены",
+    ports_found_total: "  Всего найдено портов: {}",
+    port_scan_error: "Ошибка сканирования портов: {}",
+    bound_adapter_found: "Привязанный адаптер найден на порту {} (в настройках {})",
+    bound_adapter_not_found: "Адаптер ({}) не найден, используется сохраненный порт {}",
+    bound_adapter_ambiguous: "Найдено несколько адаптеров, подходящих под привязку ({}):",
+    bound_adapter_using: "Используется {}. Для однозначной привязки укажите адаптер с серийным номером",
+    bound_adapter_search_error: "Ошибка поиска привязанного адаптера: {}",
+    registers_sorted: "Регистры отсортированы по адресу и сохранены в tags.csv",
+
+    unknown_file_extension: "Неизвестное расширение файла (ожидается .json, .yaml, .yml, .toml или .csv)",
+    exported_tags: "Экспортировано тегов: {} в {}",
+    export_error: "Ошибка экспорта: {}",
+    unknown_import_mode: "Неизвестный режим импорта (ожидается replace, append или update)",
+    import_error: "Ошибка импорта: {}",
+};
+
+static EN: Messages = Messages {
+    choose_action: "Choose an action:",
+    your_choice: "Your choice",
+    back_to_main_menu: "Back to main menu",
+    press_enter: "Press Enter to continue...",
+    invalid_format_retry: "Invalid format! Please try again.",
+    invalid_choice_range: "Invalid choice! Enter a number from 1 to {}",
+    invalid_choice_value: "Invalid choice: {}! Enter a number from 1 to {}.",
+    invalid_format_range: "Invalid format! Enter a number from 1 to {}.",
+    invalid_choice_back: "Invalid choice! Returning to the main menu.",
+    enter_number_range: "Enter a number (1-{}): ",
+    choose_number: "Enter a number (1-{})",
+    selected: "Selected: {}",
+    available_options: "Available options:",
+    unknown: "unknown",
+    baud: "baud",
+    timeout: "Timeout",
+    error_with: "Error: {}",
+    word_address: "address",
+    word_type: "type",
+    word_quantity: "count",
+    shutting_down: "Shutting down...",
+    unknown_language: "Unknown language '{}' (expected ru or en)",
+
+    no_ports_found: "No serial ports found!",
+    no_ports_exit: "exit",
+    no_ports_retry: "search again",
+    exiting: "Exiting...",
+    retrying_port_scan: "Searching for ports again...",
+    no_ports_invalid_choice: "Invalid choice! Enter 0 to exit or 1 to search again.",
+    select_port: "Select a port to connect to:",
+    enter_port_number: "Enter port number (1-{}): ",
+    port_selected: "Selected port: {}",
+
+    device_address_title: "Modbus device address",
+    device_address_range: "Allowed range: {}-{}",
+    enter_device_address: "Enter device address ({}-{}): ",
+    device_address_selected: "Selected device address: {}",
+    device_address_invalid: "Invalid address: {}! Enter a value from {} to {}.",
+    baud_rate_title: "RS-485 baud rate",
+    available_baud_rates: "Available baud rates:",
+    enter_baud_rate_number: "Enter baud rate number (1-{}): ",
+    baud_rate_selected: "Selected baud rate: {} baud",
+    baud_rate_other: "Other baud rate (enter manually)",
+    enter_custom_baud_rate: "Baud rate",
+    invalid_baud_rate: "Baud rate must be greater than zero",
+    parity_title: "RS-485 parity",
+    available_parity: "Available parity options:",
+    parity_none_hint: "no parity check",
+    parity_even_hint: "even parity",
+    parity_odd_hint: "odd parity",
+    parity_mark_hint: "parity bit always 1",
+    parity_space_hint: "parity bit always 0",
+    enter_parity_number: "Enter parity number (1-5): ",
+    parity_selected: "Selected parity: {}",
+    stop_bits_title: "RS-485 stop bits",
+    stop_bits_one: "1 stop bit",
+    stop_bits_two: "2 stop bits",
+    stop_bits_one_hint: "standard setting",
+    stop_bits_two_hint: "higher reliability",
+    enter_stop_bits_number: "Enter stop bits number (1-2): ",
+    advanced_line_prompt: "Configure advanced line parameters (data bits, flow control, RTS/DTR, RTU intervals)? (y/n)",
+    data_bits_prompt: "Data bits (5-8)",
+    invalid_data_bits: "Data bits must be from 5 to 8",
+    flow_none: "none",
+    rts_unchanged: "leave unchanged (adapter switches direction itself)",
+    rts_on: "on",
+    rts_off: "off",
+    rts_transmit: "on while transmitting (RS-485 direction)",
+    rts_transmit_inverted: "off while transmitting (inverted RS-485 direction)",
+    rts_by_flow_control: "The RTS line is driven by the port driver (RTS/CTS)",
+    pin_unchanged: "leave unchanged",
+    pin_on: "on",
+    pin_off: "off",
+    option_number: "Option number",
+    delay_before_send_prompt: "Delay between enabling the transmitter and the request, ms",
+    delay_after_send_prompt: "Delay between the end of the request and switching to receive, ms",
+    frame_timing_computed: "RTU intervals for this baud rate: t1.5 = {} µs, t3.5 = {} µs",
+    inter_char_timeout_prompt: "Inter-character timeout t1.5, µs (auto - from baud rate, 0 - no check)",
+    silent_interval_prompt: "Inter-frame silence t3.5, µs (auto - from baud rate)",
+    discard_broken_frames_prompt: "Discard frames with a gap longer than t1.5 between characters? n - only count them, for slow gateways (y/n)",
+    invalid_silent_interval: "Inter-frame silence must be greater than zero",
+
+    errors_in_file: "errors in {}:\n{}",
+    no_tag_issues: "No problems found in tag definitions",
+    issues_found: "Problems found: {}",
+    checking_file: "Checking file {}",
+    tags_in_file: "Tags in file: {}",
+    file_read_error: "Error reading file: {}",
+    registers_load_failed: "Failed to load registers: {}",
+    check_tags_file_hint: "Make sure tags.csv exists and is valid",
+
+    connection_settings_title: "=== Current connection settings ===",
+    connection_parameters: "Connection parameters:",
+    label_port: "Serial port:",
+    label_port_binding: "Bound to adapter:",
+    label_device_address: "Device address:",
+    label_baud_rate: "Baud rate:",
+    label_parity: "Parity:",
+    label_stop_bits: "Stop bits:",
+    label_data_bits: "Data bits:",
+    label_flow_control: "Flow control:",
+    label_rts: "RTS line:",
+    label_dtr: "DTR line:",
+    label_rs485_delays: "RS-485 delays (before/after send):",
+    ms: "ms",
+    label_frame_timing: "RTU intervals (t1.5 / t3.5):",
+    frame_timing_auto: "(from baud rate)",
+    frame_timing_manual: "(manual)",
+    broken_frames_counted: "frames with a gap longer than t1.5 are only counted",
+    us: "µs",
+    file_info: "File information:",
+    label_version: "Version:",
+    label_file_updated: "Updated:",
+    stored_device_info: "Device identification (address {}, read {}):",
+    settings_load_error: "Error loading settings: {}",
+    using_default_settings: "Default settings will be used.",
+    change_connection_title: "=== Change connection settings ===",
+    adapter: "Adapter: {}",
+    bind_to_adapter_prompt: "Bind settings to this adapter (the port will be found after reconnecting)? (y/n)",
+    settings_saved: "Settings saved successfully!",
+    settings_save_error: "Error saving settings: {}",
+
+    display_settings_title: "=== Display settings ===",
+    current_settings: "Current settings:",
+    label_address_notation: "Address notation:",
+    label_tags_numbering: "Address numbering in tags.csv:",
+    label_language: "Interface language:",
+    numbering_from_one: "from 1",
+    numbering_from_zero: "from 0",
+    change_address_notation: "Change address notation in the register list",
+    toggle_tags_numbering: "Switch address numbering in tags.csv to {}",
+    change_language: "Change interface language",
+    notation_number: "Notation number",
+    language_number: "Language number",
+    language_auto: "from system locale ({})",
+    language_auto_option: "Automatic (from system locale)",
+    invalid_choice_unchanged: "Invalid choice, settings unchanged",
+    display_settings_saved: "Display settings saved",
+    tag_address_too_large: "Tag '{}' has address 65535 - numbering from 1 is not possible for it",
+    tags_file_renumbered: "tags.csv rewritten with address numbering {} (protocol addresses unchanged)",
+    notation_decimal: "Decimal (as in tags.csv)",
+    notation_hex: "Hexadecimal (0x0014)",
+    notation_modicon: "Modicon (30011, 40021)",
+
+    port_opened: "Serial port {} opened successfully",
+    port_open_error: "Error opening serial port {}: {}",
+    rtu_context_created: "Modbus RTU context created successfully",
+    rtu_context_error: "Error creating Modbus RTU context: {}",
+    polling_title: "=== Start device polling ===",
+    connection_settings_loaded: "Connection settings loaded successfully",
+    connection_settings_load_error: "Error loading connection settings: {}",
+    save_settings_hint: "Make sure the settings are saved (item 2 in the main menu)",
+    save_settings_first: "Save the connection settings first (item 2 in the main menu)",
+    prompt_device_address: "Device address",
+    port_value: "Port: {}",
+    slave_address_out_of_range: "Device address must be in the range {}..{}",
+    registers_config_loaded: "Register configuration loaded successfully",
+    registers_config_load_error: "Error loading register configuration: {}",
+    no_active_registers: "No enabled registers to poll!",
+    no_active_registers_hint: "Check tags.csv and make sure some registers have enabled: true",
+    used_connection_settings: "Connection settings in use:",
+    active_registers: "Registers to poll:",
+    polling_started: "Starting cyclic device polling (every second)...",
+    polling_stop_hint: "Press Esc, q or Ctrl+C to stop polling",
+    polling_stopped_by_user: "Polling stopped by user",
+    port_close_error: "Error closing port {}: {}",
+    port_closed: "Serial port {} closed",
+    polling_error: "Polling error: {}",
+
+    summary_title: "=== Polling session summary ===",
+    label_started: "Started:",
+    label_duration: "Duration:",
+    label_cycles: "Polling cycles:",
+    label_failed_cycles: "Cycles with errors:",
+    stats_by_register: "Per-register statistics:",
+    col_errors: "Errors",
+    col_last_value: "Last value",
+    col_time: "Time",
+
+    registers_title: "=== Register configuration ===",
+    registers_loaded: "Registers loaded from file",
+    config_info: "Configuration information:",
+    label_description: "Description:",
+    label_config_updated: "Updated:",
+    label_file_numbering: "Address numbering in file:",
+    registers_stats: "Register statistics:",
+    label_total_registers: "Total registers:",
+    label_enabled: "Enabled:",
+    label_disabled: "Disabled:",
+    no_registers: "No registers found!",
+    registers_list: "Register list:",
+    col_name: "Name",
+    col_description: "Description",
+    col_address: "Address",
+    col_type: "Type",
+    col_unit: "Unit",
+    col_modbus_type: "Modbus type",
+    col_status: "Status",
+    status_enabled: "Enabled",
+    status_disabled: "Disabled",
+    registers_load_error: "Error loading registers: {}",
+
+    delete_register_title: "=== Delete register ===",
+    nothing_to_delete: "The register list is empty — nothing to delete",
+    enter_register_to_delete: "Enter the number of the register to delete (1-{}), or 0 to cancel: ",
+    invalid_number_input: "Invalid input. A number was expected.",
+    deletion_cancelled: "Deletion cancelled",
+    number_out_of_range: "Number out of range (1-{})",
+    register_deleted: "Register '{}' (address {}) deleted",
+
+    add_register_title: "=== Add register ===",
+    prompt_name: "Register name? (name):",
+    name_empty: "Name cannot be empty",
+    prompt_description: "Description? (description):",
+    prompt_unit: "Unit of measure? (unit, Enter - none):",
+    prompt_address: "Address? (address)",
+    prompt_var_type: "Variable type? (var_type)",
+    invalid_var_type: "Invalid var_type choice",
+    modbus_type_from_modicon: "Modbus register type (from Modicon address):",
+    prompt_modbus_type: "Modbus register type? (modbus_type)",
+    read_function: "read function 0x{}",
+    invalid_modbus_type: "Invalid modbus_type choice",
+    prompt_enabled: "Should this register be polled? (enabled)",
+    enabled_yes: "Yes (True)",
+    enabled_no: "No (False)",
+    invalid_enabled: "Invalid choice for enabled",
+    register_not_added: "Register not added",
+    register_added: "Register added",
+
+    menu_show_connection: "Show connection settings",
+    menu_change_connection: "Change connection settings",
+    menu_start_polling: "Start polling",
+    menu_registers: "Registers",
+    menu_tools: "Tools",
+    menu_display_settings: "Display settings",
+    menu_dashboard: "Full-screen polling",
+    menu_exit: "Exit",
+    invalid_choice_default_polling: "Invalid choice! Using item 3 by default.",
+    registers_menu_title: "=== Register management ===",
+    menu_show_registers: "Show registers",
+    menu_delete_register: "Delete register",
+    menu_add_register: "Add register",
+    menu_edit_register: "Edit register",
+    menu_sort_by_address: "Sort by address",
+    menu_check_tags: "Check tags.csv",
+    menu_export: "Export (JSON, YAML, TOML, CSV)",
+    menu_import: "Import (JSON, YAML, TOML, CSV)",
+    check_tags_title: "=== Check tags.csv ===",
+    sort_done: "Sorting complete",
+    sort_error: "Sorting error: {}",
+    tools_menu_title: "=== Tools ===",
+    menu_bus_scan: "Scan bus (find device addresses)",
+    menu_auto_detect: "Auto-detect baud rate and frame format",
+    menu_register_discovery: "Discover device register map",
+    menu_vendor_import: "Import vendor register table (CSV/XLSX)",
+    menu_raw_console: "Modbus request console (0x0F, 0x16, 0x17, raw PDU)",
+    menu_device_info: "Device information (vendor, model, revision)",
+    menu_line_diagnostics: "Line diagnostics (echo test, device counters and event log)",
+    scan_error: "Scan error: {}",
+    auto_detect_error: "Auto-detection error: {}",
+    discovery_error: "Register discovery error: {}",
+    vendor_import_error: "Table import error: {}",
+    raw_console_error: "Request console error: {}",
+    device_info_error: "Device information error: {}",
+    line_diagnostics_error: "Line diagnostics error: {}",
+
+    exception_illegal_function: "Illegal function",
+    exception_illegal_data_address: "Illegal data address",
+    exception_illegal_data_value: "Illegal data value",
+    exception_server_failure: "Server device failure",
+    exception_acknowledge: "Acknowledge",
+    exception_server_busy: "Server device busy",
+    exception_memory_parity: "Memory parity error",
+    exception_gateway_path: "Gateway path unavailable",
+    exception_gateway_target: "Gateway target device failed to respond",
+    exception_unknown: "Unknown exception",
+    read_exception: "exception {} ({})",
+    read_crc: "CRC error in response: {}",
+    read_malformed: "invalid response: {}",
+    write_read_only: "read-only area",
+    write_invalid_value: "value '{}' is not valid for type {}",
+    write_invalid_bit: "expected .N=1 or .N=0, got '{}'",
+    write_bit_area: "bit writes are only possible to holding_register",
+    write_bit_type: "bit writes are not possible for type {}",
+    write_bit_range: "bit {} is out of range 0..{} for type {}",
+    write_unknown_tag: "tag not found",
+    write_stopped: "polling stopped",
+
+    issue_empty_name: "empty name",
+    issue_duplicate_name: "duplicate name (first seen on line {})",
+    issue_invalid_combination: "type '{}' is not allowed for '{}' (coil and discrete_input allow only bool)",
+    issue_address_out_of_range: "value occupies addresses up to {}, beyond 65535",
+    issue_one_based_overflow: "address 65535 cannot be written to a 1-based tags.csv",
+    issue_zero_address_one_based: "address 0 is not allowed with 1-based numbering",
+    issue_overlap: "addresses overlap with tag '{}' (line {})",
+    issue_parse: "parse error ({}): {}",
+    issue_column_count: "{} columns, expected {}",
+    tag_issue: "line {}, tag '{}': {}",
+
+    dashboard_title: "=== Full-screen polling ===",
+    dashboard_frame_title: " Modbus RTU - polling ",
+    state_connecting: " CONNECTING ",
+    state_paused: " PAUSED ",
+    state_polling: " POLLING ",
+    seconds_value: "{} s",
+    dashboard_link: "  {}  {} baud {}  address {}  ",
+    dashboard_cycle: "cycle {}",
+    dashboard_failed_period: "  failed {}  period {}",
+    hint_window: " window  ",
+    hint_tag: " tag  ",
+    hint_pause: " pause  ",
+    hint_resume: " resume  ",
+    hint_back_to_table: " back to table",
+    hint_select: " select  ",
+    hint_filter: " filter  ",
+    hint_write: " write  ",
+    hint_chart: " chart  ",
+    hint_quit: " quit",
+    active_filter: "   filter: {}",
+    filter_prompt: "Filter: ",
+    filter_hint: "  (Enter - apply, Esc - clear)",
+    write_prompt: "Write to {} ({}): ",
+    write_bit_hint: "  (.N=0/1 - bit, Enter - write, Esc - cancel)",
+    write_hint: "  (Enter - write, Esc - cancel)",
+    tag_read_only: "{}: {} is read-only",
+    col_value: "Value",
+    col_trend: "Trend",
+    col_quality: "Quality",
+    col_updated: "Updated",
+    quality_timeout: "timeout",
+    quality_exception: "exception",
+    quality_error: "error",
+    chart_no_data: " {} - no data for {} min ",
+    chart_stats: " {} for {} min: min {}  max {}  avg {}  points {} ",
+    chart_minutes_ago: "-{} min",
+    chart_now: "now",
+    write_done: "{} = {} written",
+    write_failed: "Error writing {}: {}",
+    dashboard_polling_started: "Port {} opened, polling started",
+    polling_stopped: "Polling stopped",
+
+    console_title: "=== Modbus request console ===",
+    line_summary: "Port: {}, baud rate: {} baud, format: {}",
+    console_slave_set: "Device address: {}",
+    console_timeout_set: "Response timeout: {} ms",
+    console_help_title: "Commands (function code in hex, addresses and values decimal or 0x..):",
+    console_invalid_value: "Invalid {}: '{}'",
+    console_invalid_byte: "Invalid byte '{}'",
+    console_expected_bit: "Expected 0 or 1, got '{}'",
+    console_usage: "Usage: {}",
+    console_slave_range: "Device address must be in the range 0..{}",
+    console_tag_not_found: "Tag '{}' not found in tags.csv",
+    console_unknown_command: "Unknown command '{}', type help for help",
+    console_count_range: "Quantity must be in the range 1..{}",
+    console_read_count_range: "Read quantity must be in the range 1..{}",
+    console_max_coils: "No more than {} coils per request",
+    console_max_registers: "No more than {} registers per request",
+    console_max_write_registers: "No more than {} write registers per request",
+    console_use_raw: "Function {} is sent with the raw command",
+    console_broadcast_sent: "Broadcast request sent, no response expected",
+    console_response_time: "Response in {} ms",
+    console_exception: "Exception {}: {} ({} ms)",
+    console_written_single: "Written: address {} = {}",
+    console_written_multiple: "Written {} starting at address {}",
+    console_mask_written: "Address {}: AND {}, OR {}",
+    console_data: "Data: {}",
+    what_slave_address: "device address",
+    what_timeout: "timeout",
+    what_bit_number: "bit number",
+    what_address: "address",
+    what_quantity: "quantity",
+    what_value: "value",
+    what_and_mask: "AND mask",
+    what_or_mask: "OR mask",
+    what_read_address: "read address",
+    what_write_address: "write address",
+    usage_slave: "slave <address>",
+    usage_timeout: "timeout <ms>",
+    usage_bit: "bit <tag> <bit> <0|1>",
+    usage_raw: "raw <function> [byte ...]",
+    usage_read: "<01..04> <address> <quantity>",
+    usage_write_coil: "05 <address> <0|1>",
+    usage_write_register: "06 <address> <value>",
+    usage_write_coils: "0F <address> <0|1> ...",
+    usage_write_registers: "10 <address> <value> ...",
+    usage_mask_write: "16 <address> <AND> <OR>",
+    usage_read_write: "17 <read addr> <quantity> <write addr> <value> ...",
+    console_commands: [
+        ("01 <address> <quantity>", "read coils"),
+        ("02 <address> <quantity>", "read discrete inputs"),
+        ("03 <address> <quantity>", "read holding registers"),
+        ("04 <address> <quantity>", "read input registers"),
+        ("05 <address> <0|1>", "write single coil"),
+        ("06 <address> <value>", "write single register"),
+        ("0F <address> <0|1> ...", "write multiple coils"),
+        ("10 <address> <value> ...", "write multiple registers"),
+        ("16 <address> <AND> <OR>", "mask write register"),
+        ("17 <rd addr> <qty> <wr addr> <value> ...", "read and write in one request"),
+        ("bit <tag> <bit> <0|1>", "write one bit of a tag (function 0x16)"),
+        ("raw <function> [byte ...]", "raw PDU in hex"),
+        ("slave <address>", "device address (0 - broadcast)"),
+        ("timeout <ms>", "response timeout"),
+        ("help, q", "help, quit"),
+    ],
+
+    device_info_title: "=== Device information (0x2B/0x0E) ===",
+    device_id_categories: "Object category:",
+    device_id_basic: "basic - vendor, product code, revision",
+    device_id_regular: "regular - plus URL, product name, model, application",
+    device_id_extended: "extended - plus vendor private objects",
+    device_id_invalid_category: "Invalid category choice",
+    device_id_unsupported: "The device does not support this category (exception {})",
+    device_id_unsupported_hint: "Try the basic category or function 0x11 in the request console",
+    device_id_read_error: "Error reading identification: {}",
+    label_conformity: "Conformity level:",
+    conformity_stream_only: "{}, stream access only",
+    conformity_individual: "{}, stream and individual access",
+    conformity_unknown: "unknown level",
+    col_object: "Object",
+    device_id_save_prompt: "
+Save to the connection profile? (y/n)",
+    device_id_saved: "Identification saved to connect_settings.json",
+
+    diag_title: "=== Line diagnostics (0x08, 0x0B, 0x0C) ===",
+    diag_actions: "Actions:",
+    diag_menu_echo: "echo test (0x08/0x00 Return Query Data)",
+    diag_menu_counters: "device counters (0x08/0x0B-0x12, 0x0B)",
+    diag_menu_event_log: "event log (0x0C)",
+    diag_menu_clear: "clear device counters (0x08/0x0A)",
+    diag_menu_stats: "session statistics",
+    diag_menu_exit: "exit",
+    diag_invalid_choice: "Invalid choice",
+    diag_clear_prompt: "Clear the device counters and event log? (y/n)",
+    diag_cleared: "Device counters cleared, session statistics restarted",
+    diag_clear_error: "Error clearing counters",
+    diag_function_unsupported: "The device does not support this diagnostic function",
+    echo_count_prompt: "Number of requests",
+    echo_length_prompt: "Data length, bytes",
+    echo_invalid: "Count must be greater than 0, length 1..{} bytes",
+    echo_started: "Echo test, press Ctrl+C to stop...",
+    echo_stopped: "Echo test stopped by user",
+    echo_progress: "Request {}/{}...",
+    echo_request: "Request",
+    echo_corrupted: "data corrupted",
+    echo_unsupported: "The device does not support function 0x08 (exception 0x01)",
+    echo_summary: "Sent: {}, without errors: {}, corrupted: {}, timeouts: {}, frame errors: {}",
+    counters_error: "Error reading counters",
+    col_indicator: "Indicator",
+    col_device: "Device",
+    col_master_session: "Master (session)",
+    exception_short: "exc. {}",
+    counter_bus_messages: "Bus messages",
+    counter_bus_comm_errors: "CRC errors",
+    counter_bus_exceptions: "Exceptions",
+    counter_slave_messages: "Messages to device",
+    counter_slave_no_response: "No response",
+    counter_slave_nak: "NAK responses",
+    counter_slave_busy: "Busy responses",
+    counter_character_overrun: "Character overruns",
+    master_requests: "requests",
+    master_frame_errors: "frame errors",
+    master_exceptions: "exceptions",
+    master_responses: "responses",
+    master_timeouts: "timeouts",
+    device_busy: "busy",
+    device_ready: "ready",
+    comm_events_counter: "Comm events (0x0B)",
+    comm_events_details: "responses: {}, status: {}",
+    counters_note: "The device counts since power-up or clear (item 4), the master - {} since the session start",
+    counters_noise_hint: "CRC errors and timeouts growing with message count indicate line noise",
+    event_log_error: "Error reading event log",
+    label_device_state: "Status:",
+    label_events: "events:",
+    label_messages: "messages:",
+    event_log_empty: "Event log is empty",
+    col_byte: "Byte",
+    col_event: "Event",
+    event_log_order: "The latest event is listed first",
+    event_receive: "request received",
+    event_send: "response sent",
+    event_comm_restart: "communication restart",
+    event_entered_listen_only: "entered listen only mode",
+    event_unknown: "unknown event {}",
+    event_comm_error: "communication error",
+    event_character_overrun: "character overrun",
+    event_listen_only: "listen only mode",
+    event_broadcast: "broadcast request",
+    event_read_exception: "read exception (1-3)",
+    event_abort_exception: "abort exception (4)",
+    event_busy_exception: "busy exception (5-6)",
+    event_nak_exception: "NAK exception (7)",
+    event_write_timeout: "write timeout",
+    stats_title: "Session statistics ({}):",
+    stats_requests: "Requests:",
+    stats_responses: "Responses:",
+    stats_exceptions: "Exceptions:",
+    stats_timeouts: "Timeouts:",
+    stats_frame_errors: "Frame errors:",
+    stats_echo_mismatches: "Corrupted echo:",
+    stats_broken_frames: "Gaps > t1.5:",
+    stats_latency: "Response time:",
+    stats_latency_values: "min {}, avg {}, max {}",
+    stats_error_rate: "Error rate:",
+    ms_value: "{} ms",
+
+    format_csv_comma: "CSV (comma separated)",
+    merge_replace: "Replace the whole list",
+    merge_append: "Append to the end of the list",
+    merge_update_by_name: "Update by name (append new tags to the end)",
+    option_cancel: "Cancel",
+    invalid_choice_cancelled: "Invalid choice! Operation cancelled.",
+    export_title: "=== Export tags ===",
+    export_format_prompt: "File format:",
+    export_file_prompt: "Export file",
+    export_overwrites_tags: "Export cannot overwrite the main tags.csv file",
+    exported_tags_format: "Exported {} tags ({}) to {}",
+    import_title: "=== Import tags ===",
+    import_format_hint: "The format is taken from the extension: .json, .yaml/.yml, .toml, .csv",
+    import_file_prompt: "File to import: ",
+    import_cancelled: "Import cancelled",
+    import_mode_prompt: "Import mode:",
+    tags_in_named_file: "Tags in file {}: {}",
+    import_issues: "Problems in the tag list after import (line numbers refer to the resulting tags.csv):",
+    import_not_done: "Import not done, tags.csv not changed",
+    import_done: "Import done: added {}, updated {}, removed {}. Total tags: {}",
+
+    edit_register_title: "=== Edit register ===",
+    nothing_to_edit: "The register list is empty - nothing to edit",
+    enter_register_to_edit: "Enter the register number (1-{}) or name, or 0 to cancel: ",
+    edit_cancelled: "Editing cancelled",
+    register_not_found: "Register '{}' not found",
+    keep_current_hint: "Enter - keep the current value",
+    edit_prompt_name: "Name (name)",
+    edit_prompt_description: "Description (description)",
+    edit_prompt_unit: "Unit (unit, '-' - no unit)",
+    edit_prompt_address: "Address (address)",
+    edit_prompt_var_type: "Variable type (var_type)",
+    edit_prompt_modbus_type: "Modbus register type (modbus_type)",
+    edit_prompt_enabled: "Poll this register (enabled) (y/n)",
+    option_current: " (current)",
+    enter_number_or_name: "Enter a number or name (1-{}) [{}]: ",
+    invalid_choice_retry: "Invalid choice! Please try again.",
+    changes_not_saved: "Changes not saved",
+    register_edited: "Register '{}' changed",
+
+    probe_function_title: "Probe request function:",
+    invalid_probe_function: "Invalid function choice",
+    probe_register_address: "Register address to request",
+    response_timeout_prompt: "Response timeout, ms",
+    probe_data: "data {}",
+    probe_exception: "exception {}",
+    response_from: "Response from",
+    out_of_range_unchanged: "Number out of range, settings not changed",
+    bus_scan_title: "=== RS-485 bus scan ===",
+    line_summary_parity: "Port: {}, speed: {} baud, parity: {}, stop bits: {}",
+    first_slave_prompt: "First device address",
+    last_slave_prompt: "Last device address",
+    invalid_slave_range: "Invalid address range! Allowed {}-{}",
+    bus_scan_started: "Scanning addresses {}-{} ({}), press Ctrl+C to stop...",
+    scan_stopped: "Scan stopped by user",
+    scan_progress: "Address {}...",
+    no_device_responded: "No device responded",
+    check_line_hint: "Check the baud rate, parity and the A/B line wiring",
+    found_devices: "Devices found:",
+    save_address_prompt: "Save the address in the settings? Device number (1-{}), 0 - do not save",
+    device_address_saved: "Device address {} saved in the settings",
+    auto_detect_title: "=== Line settings auto-detection ===",
+    auto_detect_started: "Trying {} combinations for address {}, press Ctrl+C to stop...",
+    auto_detect_stopped: "Auto-detection stopped by user",
+    framing: "{} baud, {}, {} stop bits",
+    response_received: "Response received:",
+    no_framing_found: "The device did not respond to any combination of settings",
+    check_address_line_hint: "Check the device address, the A/B line wiring and the selected port",
+    matching_framings: "Matching line settings:",
+    stop_bits_ambiguous: "The number of stop bits often cannot be told from the response - if in doubt, pick the one from the documentation",
+    save_framing_prompt: "Save the settings? Option number (1-{}), 0 - do not save",
+    framing_saved: "Line settings saved",
+
+    discovery_title: "=== Register map discovery ===",
+    port_and_slave: "Port: {}, device address: {}",
+    scan_area_prompt: "Scan this area? (y/n)",
+    start_address_prompt: "Start address",
+    end_address_prompt: "End address",
+    block_size_prompt: "Block size",
+    end_before_start: "End address is less than the start address - area skipped",
+    invalid_block_size: "Block size must be from 1 to {} - area skipped",
+    no_area_selected: "No area selected for scanning",
+    discovery_stop_hint: "Press Ctrl+C to stop scanning",
+    sweep_started: "Scanning: {} {}-{}",
+    block_label: "Block {} x {}",
+    function_unsupported_skip: "The device does not support this function - area skipped",
+    no_response: "no response",
+    addresses_found: "Addresses found: {}",
+    no_points_found: "No responding addresses found",
+    found_addresses_title: "=== Addresses found ===",
+    label_ranges: "Ranges:",
+    export_draft_prompt: "Save the found addresses as a tags.csv draft? (y/n)",
+    draft_file_prompt: "Tag draft file",
+    draft_overwrites_tags: "The draft cannot overwrite the main tags.csv file",
+    draft_description: "Found by scanning, value {}",
+    draft_saved: "Draft of {} tags saved to {}",
+
+    vendor_import_title: "=== Import vendor register table ===",
+    vendor_supported_files: "Supported files: .csv, .xlsx, .xls, .ods",
+    vendor_file_prompt: "Table file: ",
+    workbook_no_sheets: "the workbook has no sheets",
+    workbook_sheets: "Workbook sheets:",
+    sheet_number: "Sheet number",
+    sheet_out_of_range: "sheet number out of range",
+    header_row_prompt: "Column header row number",
+    header_row_out_of_table: "The header row is outside the table",
+    no_data_rows: "The table has no data rows",
+    data_rows: "Data rows: {}",
+    table_columns: "Table columns:",
+    column_sample: "(example: {})",
+    column_for_field: "Column for field {}{}",
+    column_hint_cancel: ", 0 - cancel",
+    column_hint_none: ", 0 - none",
+    invalid_column: "Invalid column number! Please try again.",
+    table_address_convention: "Address notation in the table:",
+    convention_modicon: "Modicon (40001, 30011, 400001 - area by the first digit)",
+    convention_zero_based: "Offset from 0 (0, 20, 0x0014)",
+    convention_one_based: "Number from 1 (1, 21, 0x0015)",
+    map_vendor_types: "Vendor type mapping (var_type):",
+    map_vendor_areas: "Vendor area mapping (modbus_type):",
+    skip_value_rows: "skip rows with this value",
+    fixed_area_prompt: "Modbus area for all tags:",
+    invalid_choice_import_cancelled: "Invalid choice! Import cancelled.",
+    enable_imported_prompt: "Enable polling of the imported tags? (y/n)",
+    address_not_recognized: "address '{}' not recognized",
+    area_not_determined: "Modbus area not determined",
+    type_not_mapped: "type '{}' not mapped",
+    preview_title: "Preview ({} tags):",
+    and_more: "... and {} more",
+    skipped_rows: "Rows skipped: {}",
+    skipped_row: "line {}: {}",
+    nothing_to_import: "No tags to import",
+    append_confirm: "Append the tags to the end of tags.csv? (y/n)",
+
+    address_numbering_from_one: "numbering from 1",
+    address_numbering_from_zero: "numbering from 0",
+    address_input_hint: "{} ({}, 0x0014 or 40021)",
+    modicon_address_confirm: "Is {} a Modicon address: {}, offset {}? (y - yes, n - decimal address) [y]:",
+    invalid_address_input: "Invalid address. Expected a number {}, 0x0000..0xFFFF or a Modicon address",
+    offset_65535_one_based: "Offset 65535 cannot be written to tags.csv numbered from 1 (address 65536 does not fit the field)",
+
+    scanning_ports: "Scanning available serial ports...",
+    port_found: "  Found: {} ({})",
+    serial_ports_not_found: "  No serial ports found",
+    ports_found_total: "  Ports found: {}",
+    port_scan_error: "Port scan error: {}",
+    bound_adapter_found: "Bound adapter found on port {} (settings had {})",
+    bound_adapter_not_found: "Adapter ({}) not found, using the saved port {}",
+    bound_adapter_ambiguous: "Several adapters match the binding ({}):",
+    bound_adapter_using: "Using {}. Bind to an adapter with a serial number to make it unambiguous",
+    bound_adapter_search_error: "Bound adapter search error: {}",
+    registers_sorted: "Registers sorted by address and saved to tags.csv",
+
+    unknown_file_extension: "Unknown file extension (expected .json, .yaml, .yml, .toml or .csv)",
+    exported_tags: "Exported {} tags to {}",
+    export_error: "Export error: {}",
+    unknown_import_mode: "Unknown import mode (expected replace, append or update)",
+    import_error: "Import error: {}",
+};
@@ -0,0 +1,397 @@
+use colored::*;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use modbus_core::diagnostics::{
+	clear_counters, get_comm_event_counter, get_comm_event_log, read_counter, return_query_data, CommEvent, LinkStats,
+	SlaveCounter,
+};
+use modbus_core::raw_request::hex_bytes;
+use modbus_core::settings::{is_valid_slave_address, MAX_SLAVE_ADDRESS, MIN_SLAVE_ADDRESS};
+use modbus_core::{RawClient, ReadError};
+
+use crate::i18n::{fill, msg, read_error_text};
+use crate::poll_session::{self, InterruptScope};
+use crate::port_binding::resolve_bound_port;
+use crate::{clear_screen, load_settings, read_value_or_default};
+
+/// Таймаут ответа на диагностические запросы
+const RESPONSE_TIMEOUT_MS: u64 = 1000;
+/// Наибольшая длина данных эхо-теста (PDU не длиннее 253 байт)
+const MAX_ECHO_BYTES: usize = 250;
+
+/// Диагностика линии RS-485: эхо-тест, счетчики и журнал событий устройства
+/// (функции 0x08, 0x0B, 0x0C) рядом со статистикой мастера за сеанс (интерактивно)
+pub async fn run_diagnostics() -> io::Result<()> {
+	let m = msg();
+	clear_screen();
+	println!("{}", m.diag_title.cyan().bold());
+
+	let config = match load_settings() {
+		Ok(config) => config,
+		Err(e) => {
+			eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+			println!("{}", m.save_settings_first.yellow());
+			return Ok(());
+		}
+	};
+	let mut conn = config.connection.clone();
+	resolve_bound_port(&mut conn);
+	println!(
+		"{}",
+		fill(
+			m.line_summary,
+			&[
+				&conn.port.bright_white(),
+				&conn.baud_rate.to_string().bright_white(),
+				&conn.frame_format().bright_white(),
+			]
+		)
+	);
+
+	let slave = read_value_or_default::<u8>(m.prompt_device_address, conn.device_address)?;
+	if !is_valid_slave_address(slave) {
+		println!("{}", fill(m.slave_address_out_of_range, &[&MIN_SLAVE_ADDRESS, &MAX_SLAVE_ADDRESS]).red());
+		return Ok(());
+	}
+
+	let mut client = match RawClient::connect(&conn, Duration::from_millis(RESPONSE_TIMEOUT_MS)) {
+		Ok(client) => client,
+		Err(e) => {
+			eprintln!("{}", fill(m.port_open_error, &[&conn.port, &e]).red());
+			return Ok(());
+		}
+	};
+
+	let mut stats = LinkStats::default();
+	let mut started = Instant::now();
+	// Кадры с паузой больше t1.5 считает транспорт; здесь - отсчет с начала сеанса
+	let mut broken_frames_base = 0;
+	loop {
+		println!("\n{}", m.diag_actions.yellow());
+		println!("  {} - {}", "1".green(), m.diag_menu_echo);
+		println!("  {} - {}", "2".blue(), m.diag_menu_counters);
+		println!("  {} - {}", "3".magenta(), m.diag_menu_event_log);
+		println!("  {} - {}", "4".red(), m.diag_menu_clear);
+		println!("  {} - {}", "5".cyan(), m.diag_menu_stats);
+		println!("  {} - {}", "9".bright_black(), m.diag_menu_exit);
+		let choice = read_value_or_default::<u8>(&format!("{} (1-5, 9)", m.your_choice), 9)?;
+		match choice {
+			1 => echo_test(&mut client, slave, &mut stats).await?,
+			2 => show_counters(&mut client, slave, &mut stats, started).await,
+			3 => show_event_log(&mut client, slave, &mut stats).await,
+			4 => {
+				let answer = read_value_or_default::<String>(m.diag_clear_prompt, "n".to_string())?;
+				if !matches!(answer.to_lowercase().as_str(), "y" | "yes" | "да" | "д" | "1") {
+					continue;
+				}
+				match stats.track(clear_counters(&mut client, slave)).await {
+					Ok(()) => {
+						// Отсчет мастера начинается заново, чтобы сравнивать с нулевыми счетчиками устройства
+						stats = LinkStats::default();
+						started = Instant::now();
+						broken_frames_base = client.broken_frames();
+						println!("{}", m.diag_cleared.green());
+					}
+					Err(e) => print_request_error(m.diag_clear_error, &e),
+				}
+			}
+			5 => print_session_stats(&stats, client.broken_frames() - broken_frames_base, started),
+			9 => break,
+			_ => println!("{}", m.diag_invalid_choice.yellow()),
+		}
+	}
+
+	let broken_frames = client.broken_frames() - broken_frames_base;
+	if let Err(e) = client.disconnect().await {
+		eprintln!("{}", fill(m.port_close_error, &[&conn.port, &e]).red());
+	}
+	print_session_stats(&stats, broken_frames, started);
+	Ok(())
+}
+
+/// Серия эхо-запросов с проверкой возвращенных данных
+async fn echo_test(client: &mut RawClient, slave: u8, stats: &mut LinkStats) -> io::Result<()> {
+	let m = msg();
+	let count = read_value_or_default::<u32>(m.echo_count_prompt, 20)?;
+	let length = read_value_or_default::<usize>(m.echo_length_prompt, 4)?;
+	if count == 0 || !(1..=MAX_ECHO_BYTES).contains(&length) {
+		println!("{}", fill(m.echo_invalid, &[&MAX_ECHO_BYTES]).red());
+		return Ok(());
+	}
+
+	println!("{}", m.echo_started.cyan());
+	let before = stats.clone();
+	let scope = InterruptScope::enter();
+	for iteration in 0..count {
+		if poll_session::stop_requested() {
+			println!("\n{}", m.echo_stopped.yellow());
+			break;
+		}
+		print!("\r  {}", fill(m.echo_progress, &[&(iteration + 1), &count]));
+		io::stdout().flush()?;
+
+		let data = echo_pattern(iteration, length);
+		match stats.track(return_query_data(client, slave, &data)).await {
+			Ok(true) => {}
+			Ok(false) => {
+				stats.echo_mismatches += 1;
+				println!("\r  {} {}: {}", m.echo_request.red(), iteration + 1, m.echo_corrupted);
+			}
+			Err(ReadError::Exception(0x01)) => {
+				println!("\n{}", m.echo_unsupported.yellow());
+				break;
+			}
+			Err(e) => println!("\r  {} {}: {}", m.echo_request.red(), iteration + 1, read_error_text(&e)),
+		}
+	}
+	drop(scope);
+	println!();
+
+	let sent = stats.requests - before.requests;
+	let failed = stats.timeouts - before.timeouts
+		+ stats.frame_errors - before.frame_errors
+		+ stats.echo_mismatches - before.echo_mismatches
+		+ stats.exceptions - before.exceptions;
+	let summary = fill(
+		m.echo_summary,
+		&[
+			&sent,
+			&(sent - failed),
+			&(stats.echo_mismatches - before.echo_mismatches),
+			&(stats.timeouts - before.timeouts),
+			&(stats.frame_errors - before.frame_errors),
+		],
+	);
+	if failed == 0 {
+		println!("{}", summary.green());
+	} else {
+		println!("{}", summary.yellow());
+	}
+	Ok(())
+}
+
+/// Данные эхо-запроса: чередование 0x55/0xAA с номером запроса,
+/// чтобы искажения отдельных битов и перепутанные ответы были заметны
+fn echo_pattern(iteration: u32, length: usize) -> Vec<u8> {
+	(0..length)
+		.map(|index| {
+			let base = if index % 2 == 0 { 0x55 } else { 0xAA };
+			base ^ (iteration as u8).wrapping_add(index as u8)
+		})
+		.collect()
+}
+
+/// Счетчики устройства рядом с соответствующими счетчиками мастера
+async fn show_counters(client: &mut RawClient, slave: u8, stats: &mut LinkStats, started: Instant) {
+	let m = msg();
+	let mut values = Vec::with_capacity(SlaveCounter::ALL.len());
+	for counter in SlaveCounter::ALL {
+		let value = stats.track(read_counter(client, slave, counter)).await;
+		if let Err(e @ (ReadError::Timeout | ReadError::Crc(_) | ReadError::Malformed(_) | ReadError::Io(_))) = &value {
+			// Устройство не отвечает - остальные счетчики читать бессмысленно
+			print_request_error(m.counters_error, e);
+			return;
+		}
+		values.push((counter, value));
+	}
+	let event_counter = stats.track(get_comm_event_counter(client, slave)).await;
+
+	println!("\n{}", "─".repeat(80));
+	println!("{:<26} {:<16} {}", m.col_indicator, m.col_device, m.col_master_session);
+	println!("{}", "─".repeat(80));
+	for (counter, value) in &values {
+		let device = match value {
+			Ok(value) => value.to_string().bright_white(),
+			Err(ReadError::Exception(code)) => fill(m.exception_short, &[&format!("0x{:02X}", code)]).bright_black(),
+			Err(e) => read_error_text(e).red(),
+		};
+		let master = master_counterpart(*counter, stats)
+			.map(|(label, value)| format!("{}: {}", label, value))
+			.unwrap_or_else(|| "—".to_string());
+		println!("{:<26} {:<16} {}", counter_title(*counter).cyan(), device, master);
+	}
+	match event_counter {
+		Ok(counter) => {
+			let state = if counter.busy() { m.device_busy.yellow() } else { m.device_ready.green() };
+			println!(
+				"{:<26} {:<16} {}",
+				m.comm_events_counter.cyan(),
+				counter.event_count.to_string().bright_white(),
+				fill(m.comm_events_details, &[&stats.responses, &state])
+			);
+		}
+		Err(e) => println!("{:<26} {}", m.comm_events_counter.cyan(), read_error_text(&e).red()),
+	}
+	println!("{}", "─".repeat(80));
+	println!("{}", fill(m.counters_note, &[&format_elapsed(started.elapsed())]).bright_black());
+	println!("{}", m.counters_noise_hint.bright_black());
+}
+
+/// Название счетчика устройства
+fn counter_title(counter: SlaveCounter) -> &'static str {
+	let m = msg();
+	match counter {
+		SlaveCounter::BusMessages => m.counter_bus_messages,
+		SlaveCounter::BusCommErrors => m.counter_bus_comm_errors,
+		SlaveCounter::BusExceptions => m.counter_bus_exceptions,
+		SlaveCounter::SlaveMessages => m.counter_slave_messages,
+		SlaveCounter::SlaveNoResponse => m.counter_slave_no_response,
+		SlaveCounter::SlaveNak => m.counter_slave_nak,
+		SlaveCounter::SlaveBusy => m.counter_slave_busy,
+		SlaveCounter::CharacterOverrun => m.counter_character_overrun,
+	}
+}
+
+/// Показатель мастера, соответствующий счетчику устройства
+fn master_counterpart(counter: SlaveCounter, stats: &LinkStats) -> Option<(&'static str, u64)> {
+	let m = msg();
+	match counter {
+		SlaveCounter::BusMessages => Some((m.master_requests, stats.requests)),
+		SlaveCounter::BusCommErrors => Some((m.master_frame_errors, stats.frame_errors)),
+		SlaveCounter::BusExceptions => Some((m.master_exceptions, stats.exceptions)),
+		SlaveCounter::SlaveMessages => Some((m.master_responses, stats.responses)),
+		SlaveCounter::SlaveNoResponse => Some((m.master_timeouts, stats.timeouts)),
+		SlaveCounter::SlaveNak | SlaveCounter::SlaveBusy | SlaveCounter::CharacterOverrun => None,
+	}
+}
+
+/// Журнал событий связи устройства
+async fn show_event_log(client: &mut RawClient, slave: u8, stats: &mut LinkStats) {
+	let m = msg();
+	let log = match stats.track(get_comm_event_log(client, slave)).await {
+		Ok(log) => log,
+		Err(e) => {
+			print_request_error(m.event_log_error, &e);
+			return;
+		}
+	};
+	println!(
+		"\n{} {}, {} {}, {} {}",
+		m.label_device_state.green(),
+		if log.busy() { m.device_busy.yellow() } else { m.device_ready.green() },
+		m.label_events.green(),
+		log.event_count.to_string().bright_white(),
+		m.label_messages.green(),
+		log.message_count.to_string().bright_white()
+	);
+	if log.events.is_empty() {
+		println!("{}", m.event_log_empty.yellow());
+		return;
+	}
+	println!("{}", "─".repeat(80));
+	println!("{:<4} {:<6} {}", "№", m.col_byte, m.col_event);
+	println!("{}", "─".repeat(80));
+	for (index, event) in log.events.iter().enumerate() {
+		println!(
+			"{:<4} {:<6} {}",
+			index + 1,
+			hex_bytes(&[*event]),
+			event_title(CommEvent::from_byte(*event)).bright_white()
+		);
+	}
+	println!("{}", "─".repeat(80));
+	println!("{}", m.event_log_order.bright_black());
+}
+
+/// Описание события журнала с установленными флагами
+fn event_title(event: CommEvent) -> String {
+	let m = msg();
+	let with_flags = |title: &str, flags: &[(bool, &str)]| {
+		let set: Vec<&str> = flags.iter().filter(|(set, _)| *set).map(|(_, name)| *name).collect();
+		if set.is_empty() { title.to_string() } else { format!("{}: {}", title, set.join(", ")) }
+	};
+	match event {
+		CommEvent::Receive {
+			comm_error,
+			character_overrun,
+			listen_only,
+			broadcast,
+		} => with_flags(
+			m.event_receive,
+			&[
+				(comm_error, m.event_comm_error),
+				(character_overrun, m.event_character_overrun),
+				(listen_only, m.event_listen_only),
+				(broadcast, m.event_broadcast),
+			],
+		),
+		CommEvent::Send {
+			read_exception,
+			abort_exception,
+			busy_exception,
+			nak_exception,
+			write_timeout,
+			listen_only,
+		} => with_flags(
+			m.event_send,
+			&[
+				(read_exception, m.event_read_exception),
+				(abort_exception, m.event_abort_exception),
+				(busy_exception, m.event_busy_exception),
+				(nak_exception, m.event_nak_exception),
+				(write_timeout, m.event_write_timeout),
+				(listen_only, m.event_listen_only),
+			],
+		),
+		CommEvent::CommRestart => m.event_comm_restart.to_string(),
+		CommEvent::EnteredListenOnly => m.event_entered_listen_only.to_string(),
+		CommEvent::Unknown(event) => fill(m.event_unknown, &[&format!("0x{:02X}", event)]),
+	}
+}
+
+/// Статистика связи со стороны мастера
+fn print_session_stats(stats: &LinkStats, broken_frames: u64, started: Instant) {
+	let m = msg();
+	let latency = |value: Option<Duration>| {
+		value
+			.map(|value| fill(m.ms_value, &[&format!("{:.1}", value.as_secs_f64() * 1000.0)]))
+			.unwrap_or_else(|| "—".to_string())
+	};
+	println!("\n{}", fill(m.stats_title, &[&format_elapsed(started.elapsed())]).yellow());
+	println!("  {:<17}{}", m.stats_requests, stats.requests.to_string().bright_white());
+	println!("  {:<17}{}", m.stats_responses, stats.responses.to_string().bright_white());
+	println!("  {:<17}{}", m.stats_exceptions, stats.exceptions.to_string().bright_white());
+	println!("  {:<17}{}", m.stats_timeouts, count_style(stats.timeouts));
+	println!("  {:<17}{}", m.stats_frame_errors, count_style(stats.frame_errors));
+	println!("  {:<17}{}", m.stats_echo_mismatches, count_style(stats.echo_mismatches));
+	println!("  {:<17}{}", m.stats_broken_frames, count_style(broken_frames));
+	println!(
+		"  {:<17}{}",
+		m.stats_latency,
+		fill(
+			m.stats_latency_values,
+			&[
+				&latency(stats.min_latency).bright_white(),
+				&latency(stats.average_latency()).bright_white(),
+				&latency(stats.max_latency).bright_white(),
+			]
+		)
+	);
+	let rate = format!("{:.1}%", stats.error_rate());
+	println!(
+		"  {:<17}{}",
+		m.stats_error_rate,
+		if stats.error_rate() == 0.0 { rate.green() } else { rate.red() }
+	);
+}
+
+fn count_style(value: u64) -> ColoredString {
+	if value == 0 {
+		value.to_string().green()
+	} else {
+		value.to_string().red()
+	}
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+	let secs = elapsed.as_secs();
+	format!("{:02}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60)
+}
+
+fn print_request_error(context: &str, error: &ReadError) {
+	println!("{}", format!("{}: {}", context, read_error_text(error)).red());
+	if let ReadError::Exception(0x01) = error {
+		println!("{}", msg().diag_function_unsupported.yellow());
+	}
+}
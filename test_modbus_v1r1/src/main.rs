@@ -1,16 +1,44 @@
 mod add_register;
+mod address_notation;
+mod auto_detect;
+mod bus_scan;
+mod dashboard;
+mod device_info;
+mod edit_register;
+mod i18n;
+mod poll_session;
+mod port_binding;
+mod line_diagnostics;
+mod raw_console;
+mod register_discovery;
 mod sort_registers;
+mod tags_exchange;
 mod scan_available_ports;
+mod vendor_import;
 use add_register::add_register;
+use edit_register::edit_register;
+use i18n::{fill, msg, notation_title, read_error_text, tag_issue_text, tags_write_error_text};
+use modbus_core::address::{max_file_offset, AddressNotation};
+use modbus_core::connection::{connect_rtu, open_serial_port, rtu_context};
+use modbus_core::port_binding::PortBinding;
+use modbus_core::settings::{
+    is_valid_slave_address, load_config, save_config, MAX_SLAVE_ADDRESS, MIN_SLAVE_ADDRESS, PARITY_VALUES,
+};
+use modbus_core::tags::{
+    file_is_one_based, read_registers_csv_lenient, write_registers_csv, TagsWriteError,
+};
+use modbus_core::tags_validation::{validate_registers, TagIssue};
+use modbus_core::{
+    Config, ConnectionSettings, FlowControl, Language, PinState, Poller, PortDescriptor, ReadError,
+    RegisterConfig, RegistersConfig, RtsMode, RtuTiming, TagReading, UiSettings,
+};
+use poll_session::{PollSession, TagStats};
+use port_binding::resolve_bound_port;
 use scan_available_ports::scan_available_ports;
 use colored::*;
-use serde::{Deserialize, Serialize};
-use serialport;
-use std::fs;
 use std::io::{self, Write};
 use std::time::Duration;
 use tokio_modbus::prelude::*;
-use tokio_serial::SerialStream;
 
 #[cfg(windows)]
 use winapi::um::consoleapi::{GetConsoleMode, SetConsoleMode};
@@ -21,59 +49,12 @@ use winapi::um::winbase::{STD_ERROR_HANDLE, STD_OUTPUT_HANDLE};
 #[cfg(windows)]
 const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
 
-/// Доступные скорости передачи данных для RS-485 (в бодах)
-const AVAILABLE_BAUD_RATES: (u32, u32, u32, u32, u32, u32, u32) =
-    (2400, 4800, 9600, 19200, 38400, 57600, 115200);
+/// Стандартные скорости передачи данных для RS-485 (в бодах); другую скорость можно ввести вручную
+const AVAILABLE_BAUD_RATES: [u32; 9] = [1200, 2400, 4800, 9600, 19200, 38400, 57600, 115200, 230400];
 
-/// Доступные варианты четности для RS-485
+/// Варианты четности, перебираемые при автоопределении (Mark/Space выбираются только вручную)
 const PARITY_OPTIONS: (&str, &str, &str) = ("None", "Even", "Odd");
 
-/// Доступные варианты стоп-битов для RS-485
-const STOP_BITS_OPTIONS: (&str, &str) = ("1 стоп-бит", "2 стоп-бита");
-
-/// Структура для хранения настроек подключения
-#[derive(Serialize, Deserialize, Debug, Clone)]
-struct ConnectionSettings {
-    port: String,
-    device_address: u8,
-    baud_rate: u32,
-    parity: String,
-    stop_bits: u8,
-}
-
-/// Структура для метаданных
-#[derive(Serialize, Deserialize, Debug)]
-struct Metadata {
-    last_updated: String,
-    version: String,
-    description: String,
-}
-
-/// Структура для описания регистра
-#[derive(Serialize, Deserialize, Debug, Clone)]
-struct RegisterConfig {
-    name: String,
-    description: String,
-    address: u16,
-    var_type: String,
-    modbus_type: String,
-    enabled: bool,
-}
-
-/// Структура для хранения всех регистров
-#[derive(Serialize, Deserialize, Debug)]
-struct RegistersConfig {
-    registers: Vec<RegisterConfig>,
-    metadata: Metadata,
-}
-
-/// Основная структура конфигурации
-#[derive(Serialize, Deserialize, Debug)]
-struct Config {
-    connection: ConnectionSettings,
-    metadata: Metadata,
-}
-
 /// Включение поддержки цветного вывода в Windows
 #[cfg(windows)]
 fn enable_ansi_support() {
@@ -99,17 +80,18 @@ fn enable_ansi_support() {
     // На Unix-системах цветной вывод работает по умолчанию
 }
 
-/// scan_available_ports function moved to scan_available_ports module
+// scan_available_ports function moved to scan_available_ports module
 
 /// Функция обработки отсутствия портов
 fn handle_no_ports() -> io::Result<bool> {
-    println!("{}", "Доступные COM-порты не найдены!".red());
-    println!("\n{}", "Выберите действие:".yellow());
-    println!("  {} - выйти", "0".red());
-    println!("  {} - повторить поиск", "1".green());
+    let m = msg();
+    println!("{}", m.no_ports_found.red());
+    println!("\n{}", m.choose_action.yellow());
+    println!("  {} - {}", "0".red(), m.no_ports_exit);
+    println!("  {} - {}", "1".green(), m.no_ports_retry);
 
     loop {
-        print!("\nВаш выбор (0-1): ");
+        print!("\n{} (0-1): ", m.your_choice);
         io::stdout().flush()?;
 
         let mut input = String::new();
@@ -117,54 +99,53 @@ fn handle_no_ports() -> io::Result<bool> {
 
         match input.trim().parse::<u8>() {
             Ok(0) => {
-                println!("{}", "Выход из программы...".yellow());
+                println!("{}", m.exiting.yellow());
                 return Ok(false); // false = выйти
             }
             Ok(1) => {
-                println!("{}", "Повторяем поиск портов...".cyan());
+                println!("{}", m.retrying_port_scan.cyan());
                 return Ok(true); // true = повторить поиск
             }
             _ => {
-                println!(
-                    "{}",
-                    "Неверный выбор! Введите 0 для выхода или 1 для повторного поиска.".red()
-                );
+                println!("{}", m.no_ports_invalid_choice.red());
             }
         }
     }
 }
 
-/// Функция выбора COM-порта пользователем
-fn select_com_port(available_ports: &[u8; 10], ports_count: usize) -> io::Result<Option<String>> {
-    if ports_count == 0 {
+/// Функция выбора последовательного порта пользователем
+fn select_com_port(available_ports: &[PortDescriptor]) -> io::Result<Option<PortDescriptor>> {
+    if available_ports.is_empty() {
         return Ok(None);
     }
 
-    println!("\n{}", "Выберите COM-порт для подключения:".cyan());
+    let m = msg();
+    let ports_count = available_ports.len();
+    println!("\n{}", m.select_port.cyan());
 
     // Показываем список доступных портов
-    for i in 0..ports_count {
-        println!("  {}. COM{}", i + 1, available_ports[i]);
+    for (i, port) in available_ports.iter().enumerate() {
+        println!("  {}. {} {}", i + 1, port.path, format!("({})", port.details()).bright_black());
+        if let Some(by_id_path) = &port.by_id_path {
+            println!("     {}", by_id_path.bright_black());
+        }
     }
 
     loop {
-        print!("\nВведите номер порта (1-{}): ", ports_count);
+        print!("\n{}", fill(m.enter_port_number, &[&ports_count]));
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
         match input.trim().parse::<usize>() {
-            Ok(choice) if choice >= 1 && choice <= ports_count => {
-                let selected_port = format!("COM{}", available_ports[choice - 1]);
-                println!("{}", format!("Выбран порт: {}", selected_port).green());
+            Ok(choice) if (1..=ports_count).contains(&choice) => {
+                let selected_port = available_ports[choice - 1].clone();
+                println!("{}", fill(m.port_selected, &[&selected_port.path]).green());
                 return Ok(Some(selected_port));
             }
             _ => {
-                println!(
-                    "{}",
-                    format!("Неверный выбор! Введите число от 1 до {}", ports_count).red()
-                );
+                println!("{}", fill(m.invalid_choice_range, &[&ports_count]).red());
             }
         }
     }
@@ -172,36 +153,30 @@ fn select_com_port(available_ports: &[u8; 10], ports_count: usize) -> io::Result
 
 /// Функция выбора адреса устройства
 fn select_device_address() -> io::Result<u8> {
-    println!("\n{}", "Выбор адреса устройства Modbus".cyan());
-    println!("Допустимый диапазон: 1-240");
+    let m = msg();
+    println!("\n{}", m.device_address_title.cyan());
+    println!("{}", fill(m.device_address_range, &[&MIN_SLAVE_ADDRESS, &MAX_SLAVE_ADDRESS]));
 
     loop {
-        print!("\nВведите адрес устройства (1-240): ");
+        print!("\n{}", fill(m.enter_device_address, &[&MIN_SLAVE_ADDRESS, &MAX_SLAVE_ADDRESS]));
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
         match input.trim().parse::<u8>() {
-            Ok(address) if address >= 1 && address <= 240 => {
-                println!(
-                    "{}",
-                    format!("Выбран адрес устройства: {}", address).green()
-                );
+            Ok(address) if is_valid_slave_address(address) => {
+                println!("{}", fill(m.device_address_selected, &[&address]).green());
                 return Ok(address);
             }
             Ok(address) => {
                 println!(
                     "{}",
-                    format!(
-                        "Недопустимый адрес: {}! Введите значение от 1 до 240.",
-                        address
-                    )
-                    .red()
+                    fill(m.device_address_invalid, &[&address, &MIN_SLAVE_ADDRESS, &MAX_SLAVE_ADDRESS]).red()
                 );
             }
             Err(_) => {
-                println!("{}", "Неверный формат! Введите число от 1 до 240.".red());
+                println!("{}", fill(m.invalid_format_range, &[&MAX_SLAVE_ADDRESS]).red());
             }
         }
     }
@@ -209,92 +184,88 @@ fn select_device_address() -> io::Result<u8> {
 
 /// Функция выбора скорости передачи данных
 fn select_baud_rate() -> io::Result<u32> {
-    println!("\n{}", "Выбор скорости передачи данных RS-485".cyan());
-    println!("Доступные скорости:");
-
-    // Показываем список доступных скоростей в обратном порядке (от большей к меньшей)
-    println!("  1. {} бод", AVAILABLE_BAUD_RATES.6);
-    println!("  2. {} бод", AVAILABLE_BAUD_RATES.5);
-    println!("  3. {} бод", AVAILABLE_BAUD_RATES.4);
-    println!("  4. {} бод", AVAILABLE_BAUD_RATES.3);
-    println!("  5. {} бод", AVAILABLE_BAUD_RATES.2);
-    println!("  6. {} бод", AVAILABLE_BAUD_RATES.1);
-    println!("  7. {} бод", AVAILABLE_BAUD_RATES.0);
+    let m = msg();
+    println!("\n{}", m.baud_rate_title.cyan());
+    println!("{}", m.available_baud_rates);
+
+    // Показываем список доступных скоростей в обратном порядке (от большей к меньшей),
+    // последний пункт - ввод произвольной скорости
+    for (index, baud_rate) in AVAILABLE_BAUD_RATES.iter().rev().enumerate() {
+        println!("  {}. {} {}", index + 1, baud_rate, m.baud);
+    }
+    let custom_choice = AVAILABLE_BAUD_RATES.len() + 1;
+    println!("  {}. {}", custom_choice, m.baud_rate_other);
 
     loop {
-        print!("\nВведите номер скорости (1-7): ");
+        print!("\n{}", fill(m.enter_baud_rate_number, &[&custom_choice]));
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
-        match input.trim().parse::<u8>() {
-            Ok(choice) if choice >= 1 && choice <= 7 => {
-                let selected_baud = match choice {
-                    1 => AVAILABLE_BAUD_RATES.6,
-                    2 => AVAILABLE_BAUD_RATES.5,
-                    3 => AVAILABLE_BAUD_RATES.4,
-                    4 => AVAILABLE_BAUD_RATES.3,
-                    5 => AVAILABLE_BAUD_RATES.2,
-                    6 => AVAILABLE_BAUD_RATES.1,
-                    7 => AVAILABLE_BAUD_RATES.0,
-                    _ => unreachable!(), // Этого никогда не произойдет из-за проверки выше
+        match input.trim().parse::<usize>() {
+            Ok(choice) if (1..custom_choice).contains(&choice) => {
+                let selected_baud = AVAILABLE_BAUD_RATES[AVAILABLE_BAUD_RATES.len() - choice];
+                println!("{}", fill(m.baud_rate_selected, &[&selected_baud]).green());
+                return Ok(selected_baud);
+            }
+            Ok(choice) if choice == custom_choice => {
+                let selected_baud = loop {
+                    let baud_rate = read_value_or_default::<u32>(m.enter_custom_baud_rate, 9600)?;
+                    if baud_rate > 0 {
+                        break baud_rate;
+                    }
+                    println!("{}", m.invalid_baud_rate.red());
                 };
-                println!(
-                    "{}",
-                    format!("Выбрана скорость: {} бод", selected_baud).green()
-                );
+                println!("{}", fill(m.baud_rate_selected, &[&selected_baud]).green());
                 return Ok(selected_baud);
             }
             Ok(choice) => {
-                println!(
-                    "{}",
-                    format!("Недопустимый выбор: {}! Введите число от 1 до 7.", choice).red()
-                );
+                println!("{}", fill(m.invalid_choice_value, &[&choice, &custom_choice]).red());
             }
             Err(_) => {
-                println!("{}", "Неверный формат! Введите число от 1 до 7.".red());
+                println!("{}", fill(m.invalid_format_range, &[&custom_choice]).red());
             }
         }
     }
 }
 
 /// Функция выбора четности
-fn select_parity() -> io::Result<tokio_serial::Parity> {
-    println!("\n{}", "Выбор четности для RS-485".cyan());
-    println!("Доступные варианты четности:");
+fn select_parity() -> io::Result<&'static str> {
+    let m = msg();
+    println!("\n{}", m.parity_title.cyan());
+    println!("{}", m.available_parity);
 
     // Показываем список доступных вариантов четности
-    println!("  1. {} - без контроля четности", PARITY_OPTIONS.0);
-    println!("  2. {} - четная четность", PARITY_OPTIONS.1);
-    println!("  3. {} - нечетная четность", PARITY_OPTIONS.2);
+    let hints = [
+        m.parity_none_hint,
+        m.parity_even_hint,
+        m.parity_odd_hint,
+        m.parity_mark_hint,
+        m.parity_space_hint,
+    ];
+    for (index, (parity, hint)) in PARITY_VALUES.iter().zip(hints).enumerate() {
+        println!("  {}. {} - {}", index + 1, parity, hint);
+    }
 
     loop {
-        print!("\nВведите номер четности (1-3): ");
+        print!("\n{}", m.enter_parity_number);
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
-        match input.trim().parse::<u8>() {
-            Ok(choice) if choice >= 1 && choice <= 3 => {
-                let (selected_parity, parity_name) = match choice {
-                    1 => (tokio_serial::Parity::None, PARITY_OPTIONS.0),
-                    2 => (tokio_serial::Parity::Even, PARITY_OPTIONS.1),
-                    3 => (tokio_serial::Parity::Odd, PARITY_OPTIONS.2),
-                    _ => unreachable!(), // Этого никогда не произойдет из-за проверки выше
-                };
-                println!("{}", format!("Выбрана четность: {}", parity_name).green());
-                return Ok(selected_parity);
+        match input.trim().parse::<usize>() {
+            Ok(choice) if (1..=PARITY_VALUES.len()).contains(&choice) => {
+                let parity = PARITY_VALUES[choice - 1];
+                println!("{}", fill(m.parity_selected, &[&parity]).green());
+                return Ok(parity);
             }
             Ok(choice) => {
-                println!(
-                    "{}",
-                    format!("Недопустимый выбор: {}! Введите число от 1 до 3.", choice).red()
-                );
+                println!("{}", fill(m.invalid_choice_value, &[&choice, &PARITY_VALUES.len()]).red());
             }
             Err(_) => {
-                println!("{}", "Неверный формат! Введите число от 1 до 3.".red());
+                println!("{}", fill(m.invalid_format_range, &[&PARITY_VALUES.len()]).red());
             }
         }
     }
@@ -302,212 +273,363 @@ fn select_parity() -> io::Result<tokio_serial::Parity> {
 
 /// Функция выбора количества стоп-битов
 fn select_stop_bits() -> io::Result<tokio_serial::StopBits> {
-    println!("\n{}", "Выбор количества стоп-битов для RS-485".cyan());
-    println!("Доступные варианты:");
+    let m = msg();
+    println!("\n{}", m.stop_bits_title.cyan());
+    println!("{}", m.available_options);
 
     // Показываем список доступных вариантов стоп-битов
-    println!("  1. {} (стандартная настройка)", STOP_BITS_OPTIONS.0);
-    println!("  2. {} (повышенная надежность)", STOP_BITS_OPTIONS.1);
+    println!("  1. {} ({})", m.stop_bits_one, m.stop_bits_one_hint);
+    println!("  2. {} ({})", m.stop_bits_two, m.stop_bits_two_hint);
 
     loop {
-        print!("\nВведите номер стоп-битов (1-2): ");
+        print!("\n{}", m.enter_stop_bits_number);
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
         match input.trim().parse::<u8>() {
-            Ok(choice) if choice >= 1 && choice <= 2 => {
+            Ok(choice) if (1..=2).contains(&choice) => {
                 let (selected_stop_bits, stop_bits_name) = match choice {
-                    1 => (tokio_serial::StopBits::One, STOP_BITS_OPTIONS.0),
-                    2 => (tokio_serial::StopBits::Two, STOP_BITS_OPTIONS.1),
+                    1 => (tokio_serial::StopBits::One, m.stop_bits_one),
+                    2 => (tokio_serial::StopBits::Two, m.stop_bits_two),
                     _ => unreachable!(), // Этого никогда не произойдет из-за проверки выше
                 };
-                println!("{}", format!("Выбрано: {}", stop_bits_name).green());
+                println!("{}", fill(m.selected, &[&stop_bits_name]).green());
                 return Ok(selected_stop_bits);
             }
             Ok(choice) => {
-                println!(
-                    "{}",
-                    format!("Недопустимый выбор: {}! Введите число от 1 до 2.", choice).red()
-                );
+                println!("{}", fill(m.invalid_choice_value, &[&choice, &2]).red());
             }
             Err(_) => {
-                println!("{}", "Неверный формат! Введите число от 1 до 2.".red());
+                println!("{}", fill(m.invalid_format_range, &[&2]).red());
             }
         }
     }
 }
 
-/// Функция получения пути к файлу настроек
-fn get_settings_path() -> String {
-    // В режиме разработки (cargo run) - в корне проекта
-    // В режиме release (exe файл) - рядом с exe файлом
-    if cfg!(debug_assertions) {
-        // Режим разработки - файл в корне проекта
-        "connect_settings.json".to_string()
-    } else {
-        // Режим release - файл рядом с exe
-        match std::env::current_exe() {
-            Ok(exe_path) => {
-                if let Some(exe_dir) = exe_path.parent() {
-                    exe_dir
-                        .join("connect_settings.json")
-                        .to_string_lossy()
-                        .to_string()
-                } else {
-                    "connect_settings.json".to_string()
-                }
-            }
-            Err(_) => "connect_settings.json".to_string(),
+/// Выбор варианта из списка по номеру; пустой ввод - текущее значение
+fn select_option<T: Copy + PartialEq>(title: &str, options: &[T], current: T, describe: fn(T) -> &'static str) -> io::Result<T> {
+    println!("{}", title.yellow());
+    for (index, option) in options.iter().enumerate() {
+        println!("  {}. {}", index + 1, describe(*option));
+    }
+    let default = options.iter().position(|option| *option == current).unwrap_or(0) + 1;
+    loop {
+        let choice = read_value_or_default::<usize>(msg().option_number, default)?;
+        match choice.checked_sub(1).and_then(|index| options.get(index)) {
+            Some(option) => return Ok(*option),
+            None => println!("{}", fill(msg().invalid_choice_range, &[&options.len()]).red()),
         }
     }
 }
 
-/// Функция получения пути к файлу регистров (CSV)
-fn get_registers_path() -> String {
-    if cfg!(debug_assertions) {
-        "tags.csv".to_string()
+/// Дополнительные параметры линии: биты данных, управление потоком, RTS/DTR, паузы RS-485
+/// и интервалы RTU. Если пользователь их не настраивает, в `conn` остаются прежние значения.
+fn select_line_options(conn: &mut ConnectionSettings) -> io::Result<()> {
+    let m = msg();
+    println!();
+    let answer = read_value_or_default::<String>(m.advanced_line_prompt, "n".to_string())?;
+    if !matches!(answer.to_lowercase().as_str(), "y" | "yes" | "да" | "д" | "1") {
+        return Ok(());
+    }
+
+    conn.data_bits = loop {
+        let bits = read_value_or_default::<u8>(m.data_bits_prompt, conn.data_bits)?;
+        if (5..=8).contains(&bits) {
+            break bits;
+        }
+        println!("{}", m.invalid_data_bits.red());
+    };
+
+    conn.flow_control = select_option(
+        m.label_flow_control,
+        &FlowControl::ALL,
+        conn.flow_control,
+        i18n::flow_control_title,
+    )?;
+
+    // При RTS/CTS линией RTS управляет драйвер порта
+    let lines = &mut conn.control_lines;
+    if conn.flow_control == FlowControl::Hardware {
+        println!("{}", m.rts_by_flow_control.bright_black());
+        lines.rts = RtsMode::Unchanged;
     } else {
-        match std::env::current_exe() {
-            Ok(exe_path) => {
-                if let Some(exe_dir) = exe_path.parent() {
-                    exe_dir
-                        .join("tags.csv")
-                        .to_string_lossy()
-                        .to_string()
-                } else {
-                    "tags.csv".to_string()
-                }
-            }
-            Err(_) => "tags.csv".to_string(),
+        lines.rts = select_option(m.label_rts, &RtsMode::ALL, lines.rts, i18n::rts_mode_title)?;
+    }
+    lines.dtr = select_option(m.label_dtr, &PinState::ALL, lines.dtr, i18n::pin_state_title)?;
+    if lines.rts.transmit_level().is_some() {
+        lines.delay_before_send_ms = read_value_or_default::<u16>(m.delay_before_send_prompt, lines.delay_before_send_ms)?;
+        lines.delay_after_send_ms = read_value_or_default::<u16>(m.delay_after_send_prompt, lines.delay_after_send_ms)?;
+    }
+
+    // Интервалы RTU: по умолчанию по скорости, вручную - для шлюзов и медленных адаптеров
+    let computed = RtuTiming::computed(conn);
+    println!(
+        "{}",
+        fill(
+            m.frame_timing_computed,
+            &[&computed.inter_char_timeout.as_micros(), &computed.silent_interval.as_micros()]
+        )
+        .bright_black()
+    );
+    conn.frame_timing.inter_char_timeout_us =
+        read_timing_override(m.inter_char_timeout_prompt, conn.frame_timing.inter_char_timeout_us, true)?;
+    conn.frame_timing.silent_interval_us =
+        read_timing_override(m.silent_interval_prompt, conn.frame_timing.silent_interval_us, false)?;
+    let current = if conn.frame_timing.discard_broken_frames { "y" } else { "n" };
+    let answer = read_value_or_default::<String>(m.discard_broken_frames_prompt, current.to_string())?;
+    conn.frame_timing.discard_broken_frames = matches!(answer.to_lowercase().as_str(), "y" | "yes" | "да" | "д" | "1");
+
+    Ok(())
+}
+
+/// Ручное значение интервала RTU в микросекундах; "auto" - вычислять по скорости
+fn read_timing_override(prompt: &str, current: Option<u32>, allow_zero: bool) -> io::Result<Option<u32>> {
+    let m = msg();
+    let default = current.map_or_else(|| "auto".to_string(), |us| us.to_string());
+    loop {
+        let answer = read_value_or_default::<String>(prompt, default.clone())?;
+        if matches!(answer.to_lowercase().as_str(), "auto" | "a" | "авто" | "а") {
+            return Ok(None);
+        }
+        match answer.parse::<u32>() {
+            Ok(us) if us > 0 || allow_zero => return Ok(Some(us)),
+            Ok(_) => println!("{}", m.invalid_silent_interval.red()),
+            Err(_) => println!("{}", m.invalid_format_retry.red()),
         }
     }
 }
 
+/// Вывод дополнительных параметров линии (общий для просмотра настроек и запуска опроса)
+fn print_line_options(conn: &ConnectionSettings) {
+    let m = msg();
+    println!("  {} {}", m.label_data_bits.green(), conn.data_bits.to_string().bright_white());
+    println!(
+        "  {} {}",
+        m.label_flow_control.green(),
+        i18n::flow_control_title(conn.flow_control).bright_white()
+    );
+    let lines = &conn.control_lines;
+    if lines.rts != RtsMode::Unchanged {
+        println!("  {} {}", m.label_rts.green(), i18n::rts_mode_title(lines.rts).bright_white());
+    }
+    if lines.dtr != PinState::Unchanged {
+        println!("  {} {}", m.label_dtr.green(), i18n::pin_state_title(lines.dtr).bright_white());
+    }
+    if lines.rts.transmit_level().is_some() {
+        println!(
+            "  {} {}",
+            m.label_rs485_delays.green(),
+            format!("{} / {} {}", lines.delay_before_send_ms, lines.delay_after_send_ms, m.ms).bright_white()
+        );
+    }
+    let timing = RtuTiming::for_settings(conn);
+    let source = if conn.frame_timing.is_auto() { m.frame_timing_auto } else { m.frame_timing_manual };
+    println!(
+        "  {} {} {}",
+        m.label_frame_timing.green(),
+        format!(
+            "{} / {} {}",
+            timing.inter_char_timeout.as_micros(),
+            timing.silent_interval.as_micros(),
+            m.us
+        )
+        .bright_white(),
+        source.bright_black()
+    );
+    if !timing.discard_broken_frames {
+        println!("    {}", m.broken_frames_counted.bright_black());
+    }
+}
+
+/// Функция получения пути к файлу настроек
+fn get_settings_path() -> String {
+    modbus_core::paths::settings_path()
+}
+
+/// Функция получения пути к файлу регистров (CSV)
+fn get_registers_path() -> String {
+    modbus_core::paths::registers_path()
+}
+
 /// Функция загрузки настроек из JSON файла
 fn load_settings() -> io::Result<Config> {
-    let settings_path = get_settings_path();
-    let file_content = fs::read_to_string(&settings_path)?;
-    let config: Config = serde_json::from_str(&file_content)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    Ok(config)
+    load_config(&get_settings_path())
 }
 
-/// Функция загрузки конфигурации регистров из CSV файла
+/// Загрузка регистров из CSV файла без проверки содержимого
+/// (для редактирования списка, в котором есть ошибки)
+fn load_registers_unchecked() -> io::Result<RegistersConfig> {
+    let registers_path = get_registers_path();
+    let (rows, issues) = read_registers_csv_lenient(&registers_path)?;
+    if !issues.is_empty() {
+        return Err(issues_error(&registers_path, &issues));
+    }
+    Ok(RegistersConfig::from_rows(rows))
+}
+
+/// Функция загрузки конфигурации регистров из CSV файла с проверкой тегов
 fn load_registers() -> io::Result<RegistersConfig> {
     let registers_path = get_registers_path();
-    let file = fs::File::open(&registers_path)?;
-
-    let mut reader = csv::ReaderBuilder::new()
-        .delimiter(b';')
-        .has_headers(true)
-        .from_reader(file);
-
-    let mut registers: Vec<RegisterConfig> = Vec::new();
-    for record in reader.deserialize::<RegisterConfig>() {
-        match record {
-            Ok(register) => registers.push(register),
-            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
-        }
+    let (rows, mut issues) = read_registers_csv_lenient(&registers_path)?;
+    issues.extend(validate_registers(&rows));
+    if !issues.is_empty() {
+        issues.sort_by_key(|issue| issue.line);
+        return Err(issues_error(&registers_path, &issues));
     }
+    Ok(RegistersConfig::from_rows(rows))
+}
 
-    let metadata = Metadata {
-        last_updated: chrono::Utc::now().to_rfc3339(),
-        version: "csv-1.0".to_string(),
-        description: "Конфигурация регистров из CSV (tags.csv)".to_string(),
-    };
+/// Ошибка загрузки со списком проблем в тегах на текущем языке
+fn issues_error(path: &str, issues: &[TagIssue]) -> io::Error {
+    let details: Vec<String> = issues.iter().map(|issue| format!("  {}", tag_issue_text(issue))).collect();
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        fill(msg().errors_in_file, &[&path, &details.join("\n")]),
+    )
+}
 
-    Ok(RegistersConfig { registers, metadata })
+/// Вывод найденных проблем в описании тегов
+fn print_issues(issues: &[TagIssue]) {
+    let m = msg();
+    if issues.is_empty() {
+        println!("{}", m.no_tag_issues.green());
+        return;
+    }
+    println!("{}", fill(m.issues_found, &[&issues.len()]).red());
+    for issue in issues {
+        println!("  {}", tag_issue_text(issue).yellow());
+    }
+}
+
+/// Проверка файла тегов с выводом диагностики; true - проблем нет
+fn check_tags_file(path: &str) -> bool {
+    let m = msg();
+    println!("{}", fill(m.checking_file, &[&path]).cyan());
+    // Строки с ошибками разбора пропускаются, остальные теги проверяются как обычно
+    match read_registers_csv_lenient(path) {
+        Ok((registers, mut issues)) => {
+            println!("{}", fill(m.tags_in_file, &[&registers.len()]));
+            issues.extend(validate_registers(&registers));
+            issues.sort_by_key(|issue| issue.line);
+            print_issues(&issues);
+            issues.is_empty()
+        }
+        Err(e) => {
+            eprintln!("{}", fill(m.file_read_error, &[&e]).red());
+            false
+        }
+    }
 }
 
 /// Загрузка регистров с единообразным уведомлением об ошибке
 fn load_registers_or_warn() -> Option<RegistersConfig> {
-    match load_registers() {
+    match load_registers_unchecked() {
         Ok(c) => Some(c),
         Err(e) => {
-            eprintln!("{}", format!("Не удалось загрузить регистры: {}", e).red());
-            println!("{}", "Убедитесь, что файл tags.csv существует и корректен".yellow());
+            eprintln!("{}", fill(msg().registers_load_failed, &[&e]).red());
+            println!("{}", msg().check_tags_file_hint.yellow());
             None
         }
     }
 }
 
+/// Настройки интерфейса из файла настроек (по умолчанию, если файла нет)
+fn load_ui_settings() -> UiSettings {
+    load_settings().map(|config| config.ui).unwrap_or_default()
+}
+
 /// Функция сохранения настроек в JSON файл
 fn save_settings(connection: ConnectionSettings) -> io::Result<()> {
-    let metadata = Metadata {
-        last_updated: chrono::Utc::now().to_rfc3339(),
-        version: "1.0".to_string(),
-        description: "Настройки подключения для Modbus RTU через RS-485".to_string(),
-    };
-
-    let config = Config {
-        connection,
-        metadata,
-    };
+    write_settings(connection, load_ui_settings())
+}
 
-    let json_content = serde_json::to_string_pretty(&config)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+/// Сохранение настроек интерфейса без изменения настроек связи
+fn save_ui_settings(ui: UiSettings) -> io::Result<()> {
+    let connection = load_settings()?.connection;
+    write_settings(connection, ui)
+}
 
-    let settings_path = get_settings_path();
-    fs::write(&settings_path, json_content)?;
-    Ok(())
+/// Запись файла настроек
+fn write_settings(connection: ConnectionSettings, ui: UiSettings) -> io::Result<()> {
+    save_config(&get_settings_path(), connection, ui)
 }
 
 /// Функция отображения настроек связи
 fn show_connection_settings() -> io::Result<()> {
+    let m = msg();
     clear_screen();
-    println!("{}", "=== Текущие настройки связи ===".cyan().bold());
+    println!("{}", m.connection_settings_title.cyan().bold());
 
     match load_settings() {
         Ok(config) => {
             let conn = &config.connection;
-            println!("\n{}", "Параметры подключения:".yellow());
-            println!("  {} {}", "COM-порт:".green(), conn.port.bright_white());
+            println!("\n{}", m.connection_parameters.yellow());
+            println!("  {} {}", m.label_port.green(), conn.port.bright_white());
+            if let Some(binding) = &conn.port_binding {
+                println!("  {} {}", m.label_port_binding.green(), binding.describe().bright_white());
+            }
             println!(
                 "  {} {}",
-                "Адрес устройства:".green(),
+                m.label_device_address.green(),
                 conn.device_address.to_string().bright_white()
             );
             println!(
-                "  {} {} бод",
-                "Скорость:".green(),
-                conn.baud_rate.to_string().bright_white()
+                "  {} {} {}",
+                m.label_baud_rate.green(),
+                conn.baud_rate.to_string().bright_white(),
+                m.baud
             );
-            println!("  {} {}", "Четность:".green(), conn.parity.bright_white());
-
-            let stop_bits_text = match conn.stop_bits {
-                1 => "1 стоп-бит",
-                2 => "2 стоп-бита",
-                _ => "неизвестно",
-            };
+            println!("  {} {}", m.label_parity.green(), conn.parity.bright_white());
             println!(
                 "  {} {}",
-                "Стоп-биты:".green(),
-                stop_bits_text.bright_white()
+                m.label_stop_bits.green(),
+                stop_bits_text(conn.stop_bits).bright_white()
             );
+            print_line_options(conn);
 
-            println!("\n{}", "Информация о файле:".yellow());
+            println!("\n{}", m.file_info.yellow());
             println!(
                 "  {} {}",
-                "Версия:".blue(),
+                m.label_version.blue(),
                 config.metadata.version.bright_white()
             );
             println!(
                 "  {} {}",
-                "Обновлен:".blue(),
+                m.label_file_updated.blue(),
                 config.metadata.last_updated.bright_white()
             );
+
+            if let Some(device) = &config.metadata.device {
+                println!(
+                    "\n{}",
+                    fill(m.stored_device_info, &[&device.device_address, &device.read_at]).yellow()
+                );
+                for object in &device.objects {
+                    println!("  {} {}", format!("{}:", object.name).green(), object.value.bright_white());
+                }
+            }
         }
         Err(e) => {
-            eprintln!("{}", format!("Ошибка загрузки настроек: {}", e).red());
-            println!("{}", "Будут использованы настройки по умолчанию.".yellow());
+            eprintln!("{}", fill(m.settings_load_error, &[&e]).red());
+            println!("{}", m.using_default_settings.yellow());
         }
     }
 
     Ok(())
 }
 
+/// Текст количества стоп-битов
+fn stop_bits_text(stop_bits: u8) -> &'static str {
+    let m = msg();
+    match stop_bits {
+        1 => m.stop_bits_one,
+        2 => m.stop_bits_two,
+        _ => m.unknown,
+    }
+}
+
 /// Функция очистки экрана консоли
 fn clear_screen() {
     print!("\x1B[2J\x1B[1;1H");
@@ -516,143 +638,186 @@ fn clear_screen() {
 
 /// Функция ожидания нажатия Enter для продолжения
 fn wait_for_continue() -> io::Result<()> {
-    println!("\n{}", "Нажмите Enter для продолжения...".bright_black());
+    println!("\n{}", msg().press_enter.bright_black());
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
     Ok(())
 }
 
-/// Функция для обработки данных регистра в зависимости от типа
-fn process_register_data(data: &[u16], register: &RegisterConfig) -> String {
-    match register.var_type.as_str() {
-        "bool" => {
-            if data.len() >= 1 {
-                if data[0] != 0 { "true".to_string() } else { "false".to_string() }
-            } else {
-                "Недостаточно данных".to_string()
-            }
-        }
-        "u16" => {
-            if data.len() >= 1 {
-                format!("{}", data[0])
-            } else {
-                "Недостаточно данных".to_string()
-            }
-        }
-        "i16" => {
-            if data.len() >= 1 {
-                let value = data[0] as i16;
-                format!("{}", value)
-            } else {
-                "Недостаточно данных".to_string()
-            }
-        }
-        "u32" | "i32" => {
-            if data.len() >= 2 {
-                // Объединяем два 16-битных регистра в одно 32-битное значение
-                let high_word = data[1] as u32;
-                let low_word = data[0] as u32;
-                let combined = (high_word << 16) | low_word;
-                
-                if register.var_type == "i32" {
-                    let value = combined as i32;
-                    format!("{}", value)
-                } else {
-                    format!("{}", combined)
-                }
-            } else {
-                "Недостаточно данных".to_string()
-            }
-        }
-        "float" => {
-            if data.len() >= 2 {
-                // Объединяем два 16-битных регистра в float (IEEE 754)
-                let high_word = data[1] as u32;
-                let low_word = data[0] as u32;
-                let combined = (high_word << 16) | low_word;
-                let value = f32::from_bits(combined);
-                format!("{:.3}", value)
-            } else {
-                "Недостаточно данных".to_string()
-            }
+/// Запрос значения у пользователя; пустой ввод - значение по умолчанию
+fn read_value_or_default<T>(prompt: &str, default: T) -> io::Result<T>
+where
+    T: std::str::FromStr + std::fmt::Display,
+{
+    loop {
+        print!("{} [{}]: ", prompt.yellow(), default.to_string().bright_white());
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Ok(default);
         }
-        _ => {
-            // Неизвестный тип - показываем как массив u16
-            let values: Vec<String> = data.iter().map(|&x| format!("{}", x)).collect();
-            format!("[{}]", values.join(", "))
+        match trimmed.parse::<T>() {
+            Ok(value) => return Ok(value),
+            Err(_) => println!("{}", msg().invalid_format_retry.red()),
         }
     }
 }
 
-/// Вычисляет количество 16-битных регистров для чтения по типу переменной
-fn compute_quantity(var_type: &str) -> u16 {
-    match var_type {
-        "bool" | "u16" | "i16" => 1,
-        "u32" | "i32" | "float" => 2,
-        _ => 1,
-    }
-}
-
-/// Единообразная обработка результата чтения регистра с таймаутом
-fn print_register_result<T, E, F>(
-    register: &RegisterConfig,
-    result: Result<Result<T, E>, tokio::time::error::Elapsed>,
-    mut format_ok: F,
-    all_success: &mut bool,
-) where
-    E: std::fmt::Debug,
-    F: FnMut(T) -> String,
-{
-    match result {
-        Ok(Ok(data)) => {
-            let processed_value = format_ok(data);
-            print!("{}: {} | ", register.name.cyan(), processed_value.green());
+/// Вывод результата чтения тега и обновление статистики
+fn print_tag_reading(reading: &TagReading, all_success: &mut bool, stats: &mut TagStats) {
+    match &reading.value {
+        Ok(value) => {
+            print!("{}: {} | ", reading.name.cyan(), value.text.green());
+            stats.last_value = Some(value.text.clone());
+            stats.last_update = Some(reading.timestamp);
         }
-        Ok(Err(e)) => {
-            print!("{}: {} | ", register.name.cyan(), format!("Ошибка: {:?}", e).red());
+        Err(ReadError::Timeout) => {
+            print!("{}: {} | ", reading.name.cyan(), msg().timeout.red());
             *all_success = false;
+            stats.errors += 1;
         }
-        Err(_) => {
-            print!("{}: {} | ", register.name.cyan(), "Таймаут".red());
+        Err(e) => {
+            print!("{}: {} | ", reading.name.cyan(), fill(msg().error_with, &[&read_error_text(e)]).red());
             *all_success = false;
+            stats.errors += 1;
         }
     }
 }
 
-/// Форматирование значения для булевых регистров (coil/discrete_input)
-fn format_bool_value(data: Vec<bool>) -> String {
-    if data.first().copied().unwrap_or(false) {
-        "true".to_string()
+/// Функция изменения настроек отображения (запись адресов, нумерация в tags.csv, язык)
+fn change_display_settings() -> io::Result<()> {
+    let m = msg();
+    clear_screen();
+    println!("{}", m.display_settings_title.cyan().bold());
+
+    let mut ui = load_ui_settings();
+    let registers_path = get_registers_path();
+    let one_based = file_is_one_based(&registers_path);
+    let (numbering, other_numbering) = if one_based {
+        (m.numbering_from_one, m.numbering_from_zero)
     } else {
-        "false".to_string()
+        (m.numbering_from_zero, m.numbering_from_one)
+    };
+    let language = match ui.language {
+        Some(language) => language.native_name().to_string(),
+        None => fill(m.language_auto, &[&i18n::locale_language().unwrap_or_default().native_name()]),
+    };
+
+    println!("\n{}", m.current_settings.yellow());
+    println!("  {} {}", m.label_address_notation.green(), notation_title(ui.address_notation).bright_white());
+    println!("  {} {}", m.label_tags_numbering.green(), numbering.bright_white());
+    println!("  {} {}", m.label_language.green(), language.bright_white());
+
+    println!("\n{}", m.choose_action.yellow());
+    println!("  {} - {}", "1".green(), m.change_address_notation);
+    println!("  {} - {}", "2".blue(), fill(m.toggle_tags_numbering, &[&other_numbering]));
+    println!("  {} - {}", "3".magenta(), m.change_language);
+    println!("  {} - {}", "9".bright_black(), m.back_to_main_menu);
+
+    match read_value_or_default::<u8>(m.your_choice, 9)? {
+        1 => {
+            for (index, notation) in AddressNotation::ALL.iter().enumerate() {
+                println!("  {}. {}", index + 1, notation_title(*notation));
+            }
+            let choice = read_value_or_default::<usize>(m.notation_number, 1)?;
+            let Some(notation) = choice.checked_sub(1).and_then(|index| AddressNotation::ALL.get(index)) else {
+                println!("{}", m.invalid_choice_unchanged.yellow());
+                return Ok(());
+            };
+            ui.address_notation = *notation;
+            match save_ui_settings(ui) {
+                Ok(()) => println!("{}", m.display_settings_saved.green()),
+                Err(e) => eprintln!("{}", fill(m.settings_save_error, &[&e]).red()),
+            }
+        }
+        2 => {
+            let registers = match load_registers_unchecked() {
+                Ok(cfg) => cfg.registers,
+                Err(e) => {
+                    eprintln!("{}", fill(m.registers_load_failed, &[&e]).red());
+                    return Ok(());
+                }
+            };
+            if let Some(register) = registers.iter().find(|reg| reg.address > max_file_offset(!one_based)) {
+                println!("{}", fill(m.tag_address_too_large, &[&register.name]).red());
+                return Ok(());
+            }
+            write_tags_file(&registers_path, &registers, !one_based)?;
+            println!("{}", fill(m.tags_file_renumbered, &[&other_numbering]).green());
+        }
+        3 => {
+            // Пункт 1 - язык по локали системы, далее языки по порядку
+            println!("  1. {}", m.language_auto_option);
+            for (index, language) in Language::ALL.iter().enumerate() {
+                println!("  {}. {}", index + 2, language.native_name());
+            }
+            let choice = read_value_or_default::<usize>(m.language_number, 1)?;
+            ui.language = match choice {
+                1 => None,
+                _ => match choice.checked_sub(2).and_then(|index| Language::ALL.get(index)) {
+                    Some(language) => Some(*language),
+                    None => {
+                        println!("{}", m.invalid_choice_unchanged.yellow());
+                        return Ok(());
+                    }
+                },
+            };
+            i18n::set_language(i18n::resolve_language(None, ui.language));
+            match save_ui_settings(ui) {
+                Ok(()) => println!("{}", msg().display_settings_saved.green()),
+                Err(e) => eprintln!("{}", fill(msg().settings_save_error, &[&e]).red()),
+            }
+        }
+        _ => {}
     }
+
+    Ok(())
 }
 
 /// Функция изменения настроек связи
 fn change_connection_settings() -> io::Result<()> {
+    let m = msg();
     clear_screen();
-    println!("{}", "=== Изменение настроек связи ===".cyan().bold());
+    println!("{}", m.change_connection_title.cyan().bold());
     println!();
 
     // Сканирование доступных портов
-    let mut available_ports: [u8; 10] = [0; 10];
-    let ports_count = scan_available_ports(&mut available_ports);
+    let mut available_ports = scan_available_ports();
 
-    // Выбор COM-порта
-    let port = loop {
-        match select_com_port(&available_ports, ports_count)? {
+    // Выбор порта
+    let selected_port = loop {
+        match select_com_port(&available_ports)? {
             Some(port) => break port,
             None => {
                 if !handle_no_ports()? {
                     return Ok(()); // Пользователь выбрал выход
                 }
                 println!(); // Пустая строка для разделения
-                let ports_count = scan_available_ports(&mut available_ports);
-                if ports_count == 0 {
-                    continue;
-                }
+                available_ports = scan_available_ports();
+            }
+        }
+    };
+
+    let port = selected_port.path.clone();
+
+    // Привязка к USB-адаптеру, чтобы настройки не зависели от имени порта
+    let port_binding = match PortBinding::from_descriptor(&selected_port) {
+        Some(binding) => {
+            println!("\n{}", fill(m.adapter, &[&binding.describe()]).cyan());
+            let answer = read_value_or_default::<String>(
+                m.bind_to_adapter_prompt,
+                "y".to_string(),
+            )?;
+            if matches!(answer.to_lowercase().as_str(), "y" | "yes" | "да" | "д" | "1") {
+                Some(binding)
+            } else {
+                None
             }
         }
+        None => None,
     };
 
     // Выбор адреса устройства
@@ -662,12 +827,7 @@ fn change_connection_settings() -> io::Result<()> {
     let baud_rate = select_baud_rate()?;
 
     // Выбор четности
-    let parity_enum = select_parity()?;
-    let parity = match parity_enum {
-        tokio_serial::Parity::None => "None".to_string(),
-        tokio_serial::Parity::Even => "Even".to_string(),
-        tokio_serial::Parity::Odd => "Odd".to_string(),
-    };
+    let parity = select_parity()?.to_string();
 
     // Выбор количества стоп-битов
     let stop_bits_enum = select_stop_bits()?;
@@ -676,45 +836,81 @@ fn change_connection_settings() -> io::Result<()> {
         tokio_serial::StopBits::Two => 2,
     };
 
-    // Создание структуры настроек
-    let connection_settings = ConnectionSettings {
+    // Создание структуры настроек; дополнительные параметры - как в сохраненных настройках
+    let previous = load_settings().ok().map(|config| config.connection);
+    let mut connection_settings = ConnectionSettings {
         port,
         device_address,
         baud_rate,
         parity,
         stop_bits,
+        data_bits: previous.as_ref().map_or(8, |conn| conn.data_bits),
+        flow_control: previous.as_ref().map_or(FlowControl::None, |conn| conn.flow_control),
+        control_lines: previous.as_ref().map(|conn| conn.control_lines.clone()).unwrap_or_default(),
+        frame_timing: previous.as_ref().map(|conn| conn.frame_timing).unwrap_or_default(),
+        port_binding,
     };
 
+    // Биты данных, управление потоком, RTS/DTR, интервалы RTU
+    select_line_options(&mut connection_settings)?;
+
     // Сохранение настроек в файл
     match save_settings(connection_settings) {
         Ok(()) => {
-            println!("\n{}", "Настройки успешно сохранены!".green().bold());
+            println!("\n{}", m.settings_saved.green().bold());
         }
         Err(e) => {
-            eprintln!("{}", format!("Ошибка сохранения настроек: {}", e).red());
+            eprintln!("{}", fill(m.settings_save_error, &[&e]).red());
         }
     }
 
     Ok(())
 }
 
+/// Открытие последовательного порта и создание контекста Modbus RTU
+async fn open_modbus_context(conn: &ConnectionSettings) -> io::Result<client::Context> {
+    let m = msg();
+
+    // Открытие последовательного порта
+    let port = match open_serial_port(conn) {
+        Ok(port) => {
+            println!("{}", fill(m.port_opened, &[&conn.port]).green());
+            port
+        }
+        Err(e) => {
+            eprintln!("{}", fill(m.port_open_error, &[&conn.port, &format!("{:?}", e)]).red());
+            return Err(e);
+        }
+    };
+
+    // Создание контекста Modbus RTU
+    match rtu_context(port, conn).await {
+        Ok(ctx) => {
+            println!("{}", m.rtu_context_created.green());
+            Ok(ctx)
+        }
+        Err(e) => {
+            eprintln!("{}", fill(m.rtu_context_error, &[&format!("{:?}", e)]).red());
+            Err(e)
+        }
+    }
+}
+
 /// Функция запуска опроса с использованием сохраненных настроек
 async fn start_polling() -> io::Result<()> {
+    let m = msg();
     clear_screen();
-    println!("{}", "=== Запуск опроса устройства ===".cyan().bold());
+    println!("{}", m.polling_title.cyan().bold());
 
     // Загрузка настроек подключения
     let config = match load_settings() {
         Ok(config) => {
-            println!("{}", "Настройки подключения успешно загружены".green());
+            println!("{}", m.connection_settings_loaded.green());
             config
         }
         Err(e) => {
-            eprintln!("{}", format!("Ошибка загрузки настроек подключения: {}", e).red());
-            println!(
-                "{}",
-                "Убедитесь, что настройки сохранены (пункт 2 в главном меню)".yellow()
-            );
+            eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+            println!("{}", m.save_settings_hint.yellow());
             return Err(e);
         }
     };
@@ -722,129 +918,82 @@ async fn start_polling() -> io::Result<()> {
     // Загрузка конфигурации регистров
     let registers_config = match load_registers() {
         Ok(registers_config) => {
-            println!("{}", "Конфигурация регистров успешно загружена".green());
+            println!("{}", m.registers_config_loaded.green());
             registers_config
         }
         Err(e) => {
-            eprintln!("{}", format!("Ошибка загрузки конфигурации регистров: {}", e).red());
-            println!("{}", "Убедитесь, что файл tags.csv существует и корректен".yellow());
+            eprintln!("{}", fill(m.registers_config_load_error, &[&e]).red());
+            println!("{}", m.check_tags_file_hint.yellow());
             return Err(e);
         }
     };
 
-    let conn = &config.connection;
+    // Поиск привязанного адаптера: имя порта могло измениться после переподключения
+    let mut connection = config.connection.clone();
+    resolve_bound_port(&mut connection);
+    let conn = &connection;
     let enabled_registers: Vec<&RegisterConfig> = registers_config.registers
         .iter()
         .filter(|reg| reg.enabled)
         .collect();
 
     if enabled_registers.is_empty() {
-        println!("{}", "Нет активных регистров для опроса!".red());
-            println!("{}", "Проверьте файл tags.csv и убедитесь, что есть регистры с enabled: true".yellow());
+        println!("{}", m.no_active_registers.red());
+            println!("{}", m.no_active_registers_hint.yellow());
         return Ok(());
     }
 
-    println!("Используемые настройки подключения:");
-    println!("  COM-порт: {}", conn.port.bright_white());
+    println!("{}", m.used_connection_settings);
+    println!("  {} {}", m.label_port, conn.port.bright_white());
     println!(
-        "  Адрес устройства: {}",
+        "  {} {}",
+        m.label_device_address,
         conn.device_address.to_string().bright_white()
     );
     println!(
-        "  Скорость: {} бод",
-        conn.baud_rate.to_string().bright_white()
+        "  {} {} {}",
+        m.label_baud_rate,
+        conn.baud_rate.to_string().bright_white(),
+        m.baud
     );
-    println!("  Четность: {}", conn.parity.bright_white());
-
-    let stop_bits_text = match conn.stop_bits {
-        1 => "1 стоп-бит",
-        2 => "2 стоп-бита",
-        _ => "неизвестно",
-    };
-    println!("  Стоп-биты: {}", stop_bits_text.bright_white());
+    println!("  {} {}", m.label_parity, conn.parity.bright_white());
+    println!("  {} {}", m.label_stop_bits, stop_bits_text(conn.stop_bits).bright_white());
+    print_line_options(conn);
     
-    println!("\nАктивные регистры для опроса:");
+    println!("\n{}", m.active_registers);
     for register in &enabled_registers {
-        let qty = compute_quantity(&register.var_type);
-        println!("  {} (адрес: {}, тип: {}, количество: {})", 
+        let qty = register.var_type.quantity();
+        println!("  {} ({}: {}, {}: {}, {}: {})", 
                  register.name.cyan(), 
+                 m.word_address,
                  register.address, 
-                 register.var_type.yellow(), 
+                 m.word_type,
+                 register.var_type.as_str().yellow(), 
+                 m.word_quantity,
                  qty);
     }
     println!();
 
-    // Преобразование настроек для tokio_serial
-    let parity = match conn.parity.as_str() {
-        "None" => tokio_serial::Parity::None,
-        "Even" => tokio_serial::Parity::Even,
-        "Odd" => tokio_serial::Parity::Odd,
-        _ => tokio_serial::Parity::None,
-    };
-
-    let stop_bits = match conn.stop_bits {
-        1 => tokio_serial::StopBits::One,
-        2 => tokio_serial::StopBits::Two,
-        _ => tokio_serial::StopBits::One,
-    };
-
-    // Настройка параметров последовательного порта
-    let builder = tokio_serial::new(&conn.port, conn.baud_rate)
-        .data_bits(tokio_serial::DataBits::Eight)
-        .parity(parity)
-        .stop_bits(stop_bits);
-
-    // Открытие последовательного порта
-    let port = match SerialStream::open(&builder) {
-        Ok(port) => {
-            println!(
-                "{}",
-                format!("Последовательный порт {} успешно открыт", conn.port).green()
-            );
-            port
-        }
-        Err(e) => {
-            eprintln!(
-                "{}",
-                format!(
-                    "Ошибка открытия последовательного порта {}: {:?}",
-                    conn.port, e
-                )
-                .red()
-            );
-            return Err(e.into());
-        }
-    };
-
-    // Создание контекста Modbus RTU
-    let mut ctx = match rtu::connect(port).await {
-        Ok(ctx) => {
-            println!("{}", "Modbus RTU контекст успешно создан".green());
-            ctx
+    // Открытие последовательного порта; адрес устройства задается при подключении
+    let registers: Vec<RegisterConfig> = enabled_registers.iter().map(|reg| (*reg).clone()).collect();
+    let mut poller = match Poller::connect(conn, registers, Duration::from_millis(1000)).await {
+        Ok(poller) => {
+            println!("{}", fill(m.port_opened, &[&conn.port]).green());
+            poller
         }
         Err(e) => {
-            eprintln!(
-                "{}",
-                format!("Ошибка создания Modbus RTU контекста: {:?}", e).red()
-            );
-            return Err(e.into());
+            eprintln!("{}", fill(m.port_open_error, &[&conn.port, &format!("{:?}", e)]).red());
+            return Err(e);
         }
     };
 
-    // Установка адреса устройства
-    let slave_addr = Slave(conn.device_address);
-    ctx.set_slave(slave_addr);
-
     // Циклический опрос устройства каждую секунду
-    println!(
-        "{}",
-        "Начинается циклический опрос устройства (каждую секунду)...".cyan()
-    );
-    println!("{}", "Нажмите Ctrl+C для остановки опроса".yellow());
+    println!("{}", m.polling_started.cyan());
+    println!("{}", m.polling_stop_hint.yellow());
     println!();
 
-    let timeout_duration = Duration::from_millis(1000);
     let mut error_count = 0;
+    let mut session = PollSession::start(&enabled_registers);
 
     loop {
         // Показываем только время
@@ -852,66 +1001,33 @@ async fn start_polling() -> io::Result<()> {
         print!("{} ", timestamp.to_string().bright_black());
 
         let mut all_success = true;
+        let mut interrupted = false;
 
         // Опрашиваем каждый активный регистр
-        for register in &enabled_registers {
-            match register.modbus_type.as_str() {
-                "input_register" => {
-                    let qty = compute_quantity(&register.var_type);
-                    let result = tokio::time::timeout(
-                        timeout_duration,
-                        ctx.read_input_registers(register.address, qty),
-                    )
-                    .await;
-
-                    print_register_result(register, result, |data: Vec<u16>| {
-                        process_register_data(&data, register)
-                    }, &mut all_success);
-                }
-                "holding_register" => {
-                    let qty = compute_quantity(&register.var_type);
-                    let result = tokio::time::timeout(
-                        timeout_duration,
-                        ctx.read_holding_registers(register.address, qty),
-                    )
-                    .await;
-
-                    print_register_result(register, result, |data: Vec<u16>| {
-                        process_register_data(&data, register)
-                    }, &mut all_success);
-                }
-                "coil" => {
-                    let qty: u16 = 1;
-                    let result = tokio::time::timeout(
-                        timeout_duration,
-                        ctx.read_coils(register.address, qty),
-                    )
-                    .await;
-
-                    print_register_result(register, result, format_bool_value, &mut all_success);
-                }
-                "discrete_input" => {
-                    let qty: u16 = 1;
-                    let result = tokio::time::timeout(
-                        timeout_duration,
-                        ctx.read_discrete_inputs(register.address, qty),
-                    )
-                    .await;
-
-                    print_register_result(register, result, format_bool_value, &mut all_success);
-                }
-                _ => {
-                    println!("Неизвестный тип регистра: {}", register.modbus_type.red());
-                    continue;
-                }
+        for index in 0..poller.registers().len() {
+            if poll_session::stop_requested() {
+                interrupted = true;
+                break;
             }
+            let reading = poller.read(index).await;
+            print_tag_reading(&reading, &mut all_success, &mut session.tags[index]);
+            io::stdout().flush()?;
+        }
+
+        // Прерванный цикл не учитывается в итогах
+        if interrupted {
+            println!();
+            break;
         }
 
+        session.cycles += 1;
+
         // Обновляем счетчик ошибок
         if all_success {
             error_count = 0;
         } else {
             error_count += 1;
+            session.failed_cycles += 1;
         }
 
         if !all_success {
@@ -920,50 +1036,79 @@ async fn start_polling() -> io::Result<()> {
 
         println!(); // Переход на новую строку
 
-        // Ожидание 1 секунды перед следующим опросом
-        tokio::time::sleep(Duration::from_secs(1)).await;
+        // Ожидание 1 секунды перед следующим опросом (или остановка по клавише)
+        if poll_session::wait_for_next_cycle(Duration::from_secs(1)).await {
+            break;
+        }
+    }
+
+    println!("\n{}", m.polling_stopped_by_user.yellow());
+
+    // Закрытие порта
+    if let Err(e) = poller.disconnect().await {
+        eprintln!("{}", fill(m.port_close_error, &[&conn.port, &format!("{:?}", e)]).red());
     }
+    println!("{}", fill(m.port_closed, &[&conn.port]).green());
+
+    session.finish();
+    session.print_summary();
+
+    Ok(())
 }
 
 /// Функция отображения всех регистров и их настроек
 fn show_registers() -> io::Result<()> {
+    let m = msg();
     clear_screen();
-    println!("{}", "=== Конфигурация регистров ===".cyan().bold());
-
-    match load_registers() {
-        Ok(registers_config) => {
-            println!("{}", "Регистры успешно загружены из файла".green());
+    println!("{}", m.registers_title.cyan().bold());
+
+    let notation = load_ui_settings().address_notation;
+    let one_based = file_is_one_based(&get_registers_path());
+
+    // Файл читается один раз: те же теги и выводятся, и проверяются
+    match read_registers_csv_lenient(&get_registers_path()) {
+        Ok((rows, mut issues)) => {
+            // Строки, которые не удалось разобрать, показываются среди проблем под таблицей
+            issues.extend(validate_registers(&rows));
+            issues.sort_by_key(|issue| issue.line);
+            let registers_config = RegistersConfig::from_rows(rows);
+            println!("{}", m.registers_loaded.green());
             
             // Показываем метаданные
-            println!("\n{}", "Информация о конфигурации:".yellow());
-            println!("  {} {}", "Версия:".blue(), registers_config.metadata.version.bright_white());
-            println!("  {} {}", "Описание:".blue(), registers_config.metadata.description.bright_white());
-            println!("  {} {}", "Обновлено:".blue(), registers_config.metadata.last_updated.bright_white());
+            println!("\n{}", m.config_info.yellow());
+            println!("  {} {}", m.label_version.blue(), registers_config.metadata.version.bright_white());
+            println!("  {} {}", m.label_description.blue(), registers_config.metadata.description.bright_white());
+            println!("  {} {}", m.label_config_updated.blue(), registers_config.metadata.last_updated.bright_white());
+            println!(
+                "  {} {}",
+                m.label_file_numbering.blue(),
+                if one_based { m.numbering_from_one } else { m.numbering_from_zero }.bright_white()
+            );
             
             // Показываем статистику
             let total_count = registers_config.registers.len();
             let enabled_count = registers_config.registers.iter().filter(|reg| reg.enabled).count();
             let disabled_count = total_count - enabled_count;
             
-            println!("\n{}", "Статистика регистров:".yellow());
-            println!("  {} {}", "Всего регистров:".blue(), total_count.to_string().bright_white());
-            println!("  {} {}", "Активных:".green(), enabled_count.to_string().bright_white());
-            println!("  {} {}", "Отключенных:".red(), disabled_count.to_string().bright_white());
+            println!("\n{}", m.registers_stats.yellow());
+            println!("  {} {}", m.label_total_registers.blue(), total_count.to_string().bright_white());
+            println!("  {} {}", m.label_enabled.green(), enabled_count.to_string().bright_white());
+            println!("  {} {}", m.label_disabled.red(), disabled_count.to_string().bright_white());
             
             if registers_config.registers.is_empty() {
-                println!("\n{}", "Регистры не найдены!".red());
+                println!("\n{}", m.no_registers.red());
             } else {
-                println!("\n{}", "Список регистров:".yellow());
-                println!("{}", "─".repeat(120));
-                println!("{:<3} {:<20} {:<40} {:<8} {:<10} {:<20} {:<10}",
-                         "#", "Имя", "Описание", "Адрес", "Тип", "Modbus тип", "Статус");
-                println!("{}", "─".repeat(120));
+                println!("\n{}", m.registers_list.yellow());
+                println!("{}", "─".repeat(127));
+                println!("{:<3} {:<20} {:<40} {:<8} {:<10} {:<6} {:<20} {:<10}",
+                         "#", m.col_name, m.col_description, m.col_address, m.col_type, m.col_unit, m.col_modbus_type, m.col_status);
+                println!("{}", "─".repeat(127));
                 
                 for (index, register) in registers_config.registers.iter().enumerate() {
                     let status = if register.enabled { 
-                        "Активен".green() 
+                        m.status_enabled.green() 
                     } else { 
-                        "Отключен".red() 
+                        m.status_disabled.red() 
                     };
                     
                     let name = if register.name.chars().count() > 19 {
@@ -980,61 +1125,53 @@ fn show_registers() -> io::Result<()> {
                         register.description.clone()
                     };
                     
-                    println!("{:<3} {:<20} {:<40} {:<8} {:<10} {:<20} {}", 
+                    println!("{:<3} {:<20} {:<40} {:<8} {:<10} {:<6} {:<20} {}", 
                              (index + 1).to_string().bright_black(),
                              name.cyan(),
                              description,
-                             register.address.to_string().bright_white(),
-                             register.var_type.yellow(),
-                             register.modbus_type.blue(),
+                             notation.format(register.address, register.modbus_type, one_based).bright_white(),
+                             register.var_type.as_str().yellow(),
+                             register.unit,
+                             register.modbus_type.as_str().blue(),
                              status);
                 }
-                println!("{}", "─".repeat(120));
+                println!("{}", "─".repeat(127));
+            }
+
+            // Проблемы в описании тегов показываем сразу под таблицей
+            if !issues.is_empty() {
+                println!();
+                print_issues(&issues);
             }
         }
         Err(e) => {
-            eprintln!("{}", format!("Ошибка загрузки регистров: {}", e).red());
-            println!("{}", "Убедитесь, что файл tags.csv существует и корректен".yellow());
+            eprintln!("{}", fill(m.registers_load_error, &[&e]).red());
+            println!("{}", m.check_tags_file_hint.yellow());
         }
     }
 
     Ok(())
 }
 
-/// Сохранение регистров обратно в CSV (tags.csv)
+/// Сохранение регистров обратно в CSV (tags.csv) с сохранением нумерации адресов файла
 fn save_registers_to_csv(registers: &[RegisterConfig]) -> io::Result<()> {
     let path = get_registers_path();
-    let mut writer = csv::WriterBuilder::new()
-        .delimiter(b';')
-        .from_path(&path)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-
-	// Заголовок
-    writer
-        .write_record(["name", "description", "address", "var_type", "modbus_type", "enabled"])
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-
-    for reg in registers {
-        writer
-            .write_record([
-                reg.name.as_str(),
-                reg.description.as_str(),
-                &reg.address.to_string(),
-                reg.var_type.as_str(),
-                reg.modbus_type.as_str(),
-                if reg.enabled { "true" } else { "false" },
-            ])
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    }
+    write_tags_file(&path, registers, file_is_one_based(&path))
+}
 
-    writer.flush().map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    Ok(())
+/// Запись файла формата tags.csv; ошибка с текстом на текущем языке
+fn write_tags_file(path: &str, registers: &[RegisterConfig], one_based: bool) -> io::Result<()> {
+    write_registers_csv(path, registers, one_based).map_err(|e| match e {
+        TagsWriteError::Io(e) => e,
+        e => io::Error::new(io::ErrorKind::InvalidInput, tags_write_error_text(&e)),
+    })
 }
 
 /// Удаление регистра по порядковому номеру (интерактивно)
 fn delete_register() -> io::Result<()> {
+    let m = msg();
     clear_screen();
-    println!("{}", "=== Удаление регистра ===".cyan().bold());
+    println!("{}", m.delete_register_title.cyan().bold());
 
     let mut cfg = match load_registers_or_warn() {
 		Some(c) => c,
@@ -1044,77 +1181,110 @@ fn delete_register() -> io::Result<()> {
 	};
 
     if cfg.registers.is_empty() {
-		println!("{}", "Список регистров пуст — удалять нечего".yellow());
+		println!("{}", m.nothing_to_delete.yellow());
 		wait_for_continue()?;
 		return Ok(());
 	}
 
-    println!("\n{}", "Список регистров:".yellow());
+    println!("\n{}", m.registers_list.yellow());
 	for (idx, reg) in cfg.registers.iter().enumerate() {
-		println!("  {:<3} {:<20} (адрес: {:<5} тип: {:<6} modbus: {:<16})",
+		println!("  {:<3} {:<20} ({}: {:<5} {}: {:<6} modbus: {:<16})",
 			 (idx + 1).to_string().bright_black(),
 			 reg.name.cyan(),
+			 m.word_address,
 			 reg.address,
-			 reg.var_type.yellow(),
-			 reg.modbus_type.blue());
+			 m.word_type,
+			 reg.var_type.as_str().yellow(),
+			 reg.modbus_type.as_str().blue());
 	}
 
-    print!("\nВведите номер регистра для удаления (1-{}), либо 0 для отмены: ", cfg.registers.len());
+    print!("\n{}", fill(m.enter_register_to_delete, &[&cfg.registers.len()]));
 	io::stdout().flush()?;
 	let mut input = String::new();
 	io::stdin().read_line(&mut input)?;
 
     let trimmed = input.trim();
 	let Ok(num) = trimmed.parse::<usize>() else {
-		println!("{}", "Неверный ввод. Ожидалось число.".yellow());
+		println!("{}", m.invalid_number_input.yellow());
 		wait_for_continue()?;
 		return Ok(());
 	};
 
     if num == 0 {
-		println!("{}", "Удаление отменено".bright_black());
+		println!("{}", m.deletion_cancelled.bright_black());
 		wait_for_continue()?;
 		return Ok(());
 	}
 
     if num < 1 || num > cfg.registers.len() {
-		println!("{}", format!("Номер вне диапазона (1-{})", cfg.registers.len()).yellow());
+		println!("{}", fill(m.number_out_of_range, &[&cfg.registers.len()]).yellow());
 		wait_for_continue()?;
 		return Ok(());
 	}
 
     let removed = cfg.registers.remove(num - 1);
     save_registers_to_csv(&cfg.registers)?;
-    println!("{}", format!("Регистр '{}' (адрес {}) удалён", removed.name, removed.address).green());
+    println!("{}", fill(m.register_deleted, &[&removed.name, &removed.address]).green());
     wait_for_continue()?;
     Ok(())
 }
 
 /// Функция отображения меню регистров
 fn show_registers_menu() -> io::Result<u8> {
+    let m = msg();
     clear_screen();
-    println!("{}", "=== Управление регистрами ===".cyan().bold());
-    println!("\n{}", "Выберите действие:".yellow());
-    println!("  {} - Показать регистры", "1".green());
-    println!("  {} - Удалить регистр", "2".red());
-    println!("  {} - Добавить регистр", "3".blue());
-    println!("  {} - Изменить регистр", "4".magenta());
-    println!("  {} - Отсортировать по адресу", "5".cyan());
-    println!("  {} - Назад в главное меню", "9".bright_black());
-
-    print!("\nВаш выбор (1-5, 9): ");
+    println!("{}", m.registers_menu_title.cyan().bold());
+    println!("\n{}", m.choose_action.yellow());
+    println!("  {} - {}", "1".green(), m.menu_show_registers);
+    println!("  {} - {}", "2".red(), m.menu_delete_register);
+    println!("  {} - {}", "3".blue(), m.menu_add_register);
+    println!("  {} - {}", "4".magenta(), m.menu_edit_register);
+    println!("  {} - {}", "5".cyan(), m.menu_sort_by_address);
+    println!("  {} - {}", "6".yellow(), m.menu_check_tags);
+    println!("  {} - {}", "7".green(), m.menu_export);
+    println!("  {} - {}", "8".blue(), m.menu_import);
+    println!("  {} - {}", "9".bright_black(), m.back_to_main_menu);
+
+    print!("\n{} (1-9): ", m.your_choice);
     io::stdout().flush()?;
 
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
 
     match input.trim().parse::<u8>() {
-        Ok(1) | Ok(2) | Ok(3) | Ok(4) | Ok(5) | Ok(9) => Ok(input.trim().parse().unwrap()),
+        Ok(1..=9) => Ok(input.trim().parse().unwrap()),
         _ => {
-            println!(
-                "{}",
-                "Неверный выбор! Возвращаемся в главное меню.".yellow()
-            );
+            println!("{}", m.invalid_choice_back.yellow());
+            Ok(9)
+        }
+    }
+}
+
+/// Функция отображения меню инструментов
+fn show_tools_menu() -> io::Result<u8> {
+    let m = msg();
+    clear_screen();
+    println!("{}", m.tools_menu_title.cyan().bold());
+    println!("\n{}", m.choose_action.yellow());
+    println!("  {} - {}", "1".green(), m.menu_bus_scan);
+    println!("  {} - {}", "2".blue(), m.menu_auto_detect);
+    println!("  {} - {}", "3".magenta(), m.menu_register_discovery);
+    println!("  {} - {}", "4".yellow(), m.menu_vendor_import);
+    println!("  {} - {}", "5".cyan(), m.menu_raw_console);
+    println!("  {} - {}", "6".bright_blue(), m.menu_device_info);
+    println!("  {} - {}", "7".bright_magenta(), m.menu_line_diagnostics);
+    println!("  {} - {}", "9".bright_black(), m.back_to_main_menu);
+
+    print!("\n{} (1-7, 9): ", m.your_choice);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    match input.trim().parse::<u8>() {
+        Ok(1) | Ok(2) | Ok(3) | Ok(4) | Ok(5) | Ok(6) | Ok(7) | Ok(9) => Ok(input.trim().parse().unwrap()),
+        _ => {
+            println!("{}", m.invalid_choice_back.yellow());
             Ok(9)
         }
     }
@@ -1122,38 +1292,118 @@ fn show_registers_menu() -> io::Result<u8> {
 
 /// Функция отображения главного меню
 fn show_main_menu() -> io::Result<u8> {
+    let m = msg();
     clear_screen();
     println!("{}", "=== Modbus RTU Client ===".cyan().bold());
-    println!("\n{}", "Выберите действие:".yellow());
-    println!("  {} - Показать настройки связи", "1".green());
-    println!("  {} - Изменить настройки связи", "2".blue());
-    println!("  {} - Начать опрос", "3".magenta());
-    println!("  {} - Регистры", "4".bright_blue());
-    println!("  {} - Выйти", "9".red());
-
-    print!("\nВаш выбор (1-4, 9): ");
+    println!("\n{}", m.choose_action.yellow());
+    println!("  {} - {}", "1".green(), m.menu_show_connection);
+    println!("  {} - {}", "2".blue(), m.menu_change_connection);
+    println!("  {} - {}", "3".magenta(), m.menu_start_polling);
+    println!("  {} - {}", "4".bright_blue(), m.menu_registers);
+    println!("  {} - {}", "5".cyan(), m.menu_tools);
+    println!("  {} - {}", "6".yellow(), m.menu_display_settings);
+    println!("  {} - {}", "7".magenta(), m.menu_dashboard);
+    println!("  {} - {}", "9".red(), m.menu_exit);
+
+    print!("\n{} (1-7, 9): ", m.your_choice);
     io::stdout().flush()?;
 
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
 
     match input.trim().parse::<u8>() {
-        Ok(1) | Ok(2) | Ok(3) | Ok(4) | Ok(9) => Ok(input.trim().parse().unwrap()),
+        Ok(1) | Ok(2) | Ok(3) | Ok(4) | Ok(5) | Ok(6) | Ok(7) | Ok(9) => Ok(input.trim().parse().unwrap()),
         _ => {
-            println!(
-                "{}",
-                "Неверный выбор! Используется пункт 3 по умолчанию.".yellow()
-            );
+            println!("{}", m.invalid_choice_default_polling.yellow());
             Ok(3)
         }
     }
 }
 
+/// Команды `tags export` и `tags import` без меню; возвращает код завершения
+fn run_tags_exchange_command(command: &str, path: &str, mode: Option<&str>) -> i32 {
+    let m = msg();
+    let Some(format) = modbus_core::tags_exchange::ExchangeFormat::from_path(path) else {
+        eprintln!("{}", m.unknown_file_extension.red());
+        return 2;
+    };
+
+    if command == "export" {
+        return match load_registers_unchecked()
+            .and_then(|cfg| modbus_core::tags_exchange::export_registers(path, format, &cfg.registers).map(|()| cfg.registers.len()))
+        {
+            Ok(count) => {
+                println!("{}", fill(m.exported_tags, &[&count, &path]).green());
+                0
+            }
+            Err(e) => {
+                eprintln!("{}", fill(m.export_error, &[&e]).red());
+                1
+            }
+        };
+    }
+
+    let Some(mode) = modbus_core::tags_exchange::MergeMode::from_arg(mode.unwrap_or("replace")) else {
+        eprintln!("{}", m.unknown_import_mode.red());
+        return 2;
+    };
+    match tags_exchange::import_into_tags_file(path, format, mode) {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(e) => {
+            eprintln!("{}", fill(m.import_error, &[&e]).red());
+            1
+        }
+    }
+}
+
+/// Извлечение флага `--lang <код>` (или `--lang=<код>`) из аргументов командной строки.
+/// Ошибка содержит нераспознанное значение.
+fn take_language_flag(args: &mut Vec<String>) -> Result<Option<Language>, String> {
+    let Some(position) = args.iter().position(|arg| arg == "--lang" || arg.starts_with("--lang=")) else {
+        return Ok(None);
+    };
+    let flag = args.remove(position);
+    let value = match flag.strip_prefix("--lang=") {
+        Some(value) => value.to_string(),
+        None if position < args.len() => args.remove(position),
+        None => String::new(),
+    };
+    Language::from_code(&value).map(Some).ok_or(value)
+}
+
 #[tokio::main]
 async fn main() -> io::Result<()> {
     // Включение поддержки цветного вывода в Windows
     enable_ansi_support();
 
+    // Язык интерфейса: `--lang ru|en`, затем настройка, затем локаль системы
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let language_flag = match take_language_flag(&mut args) {
+        Ok(flag) => flag,
+        Err(value) => {
+            eprintln!("{}", fill(msg().unknown_language, &[&value]).red());
+            std::process::exit(2);
+        }
+    };
+    i18n::set_language(i18n::resolve_language(language_flag, load_ui_settings().language));
+
+    // Команда без меню: `tags check [путь]` - проверка файла тегов
+    if args.len() >= 2 && args[0] == "tags" && args[1] == "check" {
+        let path = args.get(2).cloned().unwrap_or_else(get_registers_path);
+        if !check_tags_file(&path) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    // `tags export <файл>` и `tags import <файл> [replace|append|update]` - обмен списком тегов
+    if args.len() >= 3 && args[0] == "tags" && (args[1] == "export" || args[1] == "import") {
+        std::process::exit(run_tags_exchange_command(&args[1], &args[2], args.get(3).map(String::as_str)));
+    }
+
+    // Ctrl+C во время опроса останавливает опрос, а не всю программу
+    poll_session::install_ctrl_c_handler();
+
     // Главный цикл программы
     loop {
         let choice = show_main_menu()?;
@@ -1174,14 +1424,11 @@ async fn main() -> io::Result<()> {
             }
             3 => {
                 // Начать опрос с использованием сохраненных настроек
-                match start_polling().await {
-                    Ok(()) => return Ok(()),
-                    Err(e) => {
-                        eprintln!("{}", format!("Ошибка при опросе: {}", e).red());
-                        wait_for_continue()?;
-                        continue; // Возвращаемся к главному меню
-                    }
+                if let Err(e) = start_polling().await {
+                    eprintln!("{}", fill(msg().polling_error, &[&e]).red());
                 }
+                wait_for_continue()?;
+                continue; // Возвращаемся к главному меню
             }
             4 => {
                 // Меню управления регистрами
@@ -1202,22 +1449,40 @@ async fn main() -> io::Result<()> {
                             add_register()?;
                         }
                         4 => {
-                            // Изменить регистр (пока не реализовано)
-                            println!("{}", "Функция 'Изменить регистр' пока не реализована".yellow());
-                            wait_for_continue()?;
+                            // Изменить регистр
+                            edit_register()?;
                         }
                         5 => {
                             // Отсортировать по адресу
                             match sort_registers::sort_registers_by_address() {
                                 Ok(()) => {
-                                    println!("{}", "Сортировка завершена".green());
+                                    println!("{}", msg().sort_done.green());
                                 }
                                 Err(e) => {
-                                    eprintln!("{}", format!("Ошибка сортировки: {}", e).red());
+                                    eprintln!("{}", fill(msg().sort_error, &[&e]).red());
                                 }
                             }
                             wait_for_continue()?;
                         }
+                        6 => {
+                            // Проверить tags.csv
+                            clear_screen();
+                            println!("{}", msg().check_tags_title.cyan().bold());
+                            check_tags_file(&get_registers_path());
+                            wait_for_continue()?;
+                        }
+                        7 => {
+                            // Экспорт тегов
+                            if let Err(e) = tags_exchange::export_tags() {
+                                eprintln!("{}", fill(msg().export_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        8 => {
+                            // Импорт тегов
+                            tags_exchange::import_tags()?;
+                            wait_for_continue()?;
+                        }
                         9 => {
                             // Назад в главное меню
                             break;
@@ -1227,8 +1492,85 @@ async fn main() -> io::Result<()> {
                 }
                 continue; // Возвращаемся к главному меню
             }
+            5 => {
+                // Меню инструментов
+                loop {
+                    let tools_choice = show_tools_menu()?;
+                    match tools_choice {
+                        1 => {
+                            // Сканирование шины
+                            if let Err(e) = bus_scan::scan_bus().await {
+                                eprintln!("{}", fill(msg().scan_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        2 => {
+                            // Автоопределение параметров линии
+                            if let Err(e) = auto_detect::auto_detect_line_settings().await {
+                                eprintln!("{}", fill(msg().auto_detect_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        3 => {
+                            // Поиск карты регистров
+                            if let Err(e) = register_discovery::discover_register_map().await {
+                                eprintln!("{}", fill(msg().discovery_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        4 => {
+                            // Импорт таблицы производителя
+                            if let Err(e) = vendor_import::import_vendor_table() {
+                                eprintln!("{}", fill(msg().vendor_import_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        5 => {
+                            // Консоль запросов Modbus
+                            if let Err(e) = raw_console::run_console().await {
+                                eprintln!("{}", fill(msg().raw_console_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        6 => {
+                            // Идентификация устройства
+                            if let Err(e) = device_info::show_device_info().await {
+                                eprintln!("{}", fill(msg().device_info_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        7 => {
+                            // Диагностика линии
+                            if let Err(e) = line_diagnostics::run_diagnostics().await {
+                                eprintln!("{}", fill(msg().line_diagnostics_error, &[&e]).red());
+                            }
+                            wait_for_continue()?;
+                        }
+                        9 => {
+                            // Назад в главное меню
+                            break;
+                        }
+                        _ => unreachable!(), // Этого не произойдет из-за проверки в show_tools_menu
+                    }
+                }
+                continue; // Возвращаемся к главному меню
+            }
+            6 => {
+                // Настройки отображения
+                change_display_settings()?;
+                wait_for_continue()?;
+                continue; // Возвращаемся к главному меню
+            }
+            7 => {
+                // Опрос в полноэкранном режиме
+                if let Err(e) = dashboard::run_dashboard().await {
+                    eprintln!("{}", fill(msg().polling_error, &[&e]).red());
+                }
+                wait_for_continue()?;
+                continue; // Возвращаемся к главному меню
+            }
             9 => {
-                println!("{}", "Завершение программы...".yellow());
+                println!("{}", msg().shutting_down.yellow());
                 return Ok(()); // Завершаем программу
             }
             _ => unreachable!(), // Этого не произойдет из-за проверки в show_main_menu
@@ -0,0 +1,213 @@
+use colored::*;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::i18n::msg;
+use crate::RegisterConfig;
+
+/// Идет ли сейчас прерываемая операция (Ctrl+C останавливает ее, а не программу)
+static POLLING_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Запрошена ли остановка опроса
+static STOP_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Установка обработчика Ctrl+C.
+/// Во время опроса Ctrl+C только останавливает опрос, в меню - завершает программу, как раньше.
+pub fn install_ctrl_c_handler() {
+	tokio::spawn(async {
+		loop {
+			if tokio::signal::ctrl_c().await.is_err() {
+				return;
+			}
+			if POLLING_ACTIVE.load(Ordering::SeqCst) {
+				STOP_REQUESTED.store(true, Ordering::SeqCst);
+			} else {
+				println!("\n{}", msg().shutting_down.yellow());
+				std::process::exit(130);
+			}
+		}
+	});
+}
+
+/// Была ли запрошена остановка опроса
+pub fn stop_requested() -> bool {
+	STOP_REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Ожидание перед следующим циклом опроса с проверкой клавиш остановки (Esc, q, Ctrl+C).
+/// Возвращает true, если пользователь запросил остановку.
+pub async fn wait_for_next_cycle(interval: Duration) -> bool {
+	if stop_requested() {
+		return true;
+	}
+	match tokio::task::spawn_blocking(move || wait_for_stop_key(interval)).await {
+		Ok(Ok(stop)) => {
+			if stop {
+				STOP_REQUESTED.store(true, Ordering::SeqCst);
+			}
+			stop_requested()
+		}
+		// Если терминал не поддерживает raw-режим - просто ждем, остановка остается по Ctrl+C
+		_ => {
+			tokio::time::sleep(interval).await;
+			stop_requested()
+		}
+	}
+}
+
+/// Чтение клавиатуры в raw-режиме в течение заданного времени
+fn wait_for_stop_key(timeout: Duration) -> io::Result<bool> {
+	terminal::enable_raw_mode()?;
+	let deadline = Instant::now() + timeout;
+
+	let result = loop {
+		if stop_requested() {
+			break Ok(true);
+		}
+		let remaining = deadline.saturating_duration_since(Instant::now());
+		if remaining.is_zero() {
+			break Ok(false);
+		}
+		match event::poll(remaining.min(Duration::from_millis(50))) {
+			Ok(true) => match event::read() {
+				Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+					let is_stop_key = match key.code {
+						KeyCode::Esc => true,
+						KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Char('й') | KeyCode::Char('Й') => true,
+						KeyCode::Char('c') => key.modifiers.contains(KeyModifiers::CONTROL),
+						_ => false,
+					};
+					if is_stop_key {
+						break Ok(true);
+					}
+				}
+				Ok(_) => {}
+				Err(e) => break Err(e),
+			},
+			Ok(false) => {}
+			Err(e) => break Err(e),
+		}
+	};
+
+	terminal::disable_raw_mode()?;
+	result
+}
+
+/// Область, в которой Ctrl+C прерывает текущую операцию (опрос, сканирование), а не программу
+pub struct InterruptScope;
+
+impl InterruptScope {
+	pub fn enter() -> Self {
+		STOP_REQUESTED.store(false, Ordering::SeqCst);
+		POLLING_ACTIVE.store(true, Ordering::SeqCst);
+		InterruptScope
+	}
+}
+
+impl Drop for InterruptScope {
+	fn drop(&mut self) {
+		POLLING_ACTIVE.store(false, Ordering::SeqCst);
+		STOP_REQUESTED.store(false, Ordering::SeqCst);
+	}
+}
+
+/// Статистика опроса одного тега
+pub struct TagStats {
+	pub name: String,
+	pub errors: u64,
+	pub last_value: Option<String>,
+	pub last_update: Option<chrono::DateTime<chrono::Local>>,
+}
+
+/// Статистика сеанса опроса
+pub struct PollSession {
+	scope: Option<InterruptScope>,
+	started: Instant,
+	started_at: chrono::DateTime<chrono::Local>,
+	pub cycles: u64,
+	pub failed_cycles: u64,
+	pub tags: Vec<TagStats>,
+}
+
+impl PollSession {
+	/// Начало нового сеанса опроса
+	pub fn start(registers: &[&RegisterConfig]) -> Self {
+		PollSession {
+			scope: Some(InterruptScope::enter()),
+			started: Instant::now(),
+			started_at: chrono::Local::now(),
+			cycles: 0,
+			failed_cycles: 0,
+			tags: registers
+				.iter()
+				.map(|reg| TagStats {
+					name: reg.name.clone(),
+					errors: 0,
+					last_value: None,
+					last_update: None,
+				})
+				.collect(),
+		}
+	}
+
+	/// Завершение сеанса: Ctrl+C снова завершает программу
+	pub fn finish(&mut self) {
+		self.scope.take();
+	}
+
+	/// Длительность сеанса
+	pub fn duration(&self) -> Duration {
+		self.started.elapsed()
+	}
+
+	/// Вывод итогов сеанса опроса
+	pub fn print_summary(&self) {
+		let m = msg();
+		let secs = self.duration().as_secs();
+		println!("\n{}", m.summary_title.cyan().bold());
+		println!(
+			"  {} {}",
+			m.label_started.green(),
+			self.started_at.format("%Y-%m-%d %H:%M:%S").to_string().bright_white()
+		);
+		println!(
+			"  {} {}",
+			m.label_duration.green(),
+			format!("{:02}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60).bright_white()
+		);
+		println!("  {} {}", m.label_cycles.green(), self.cycles.to_string().bright_white());
+		println!(
+			"  {} {}",
+			m.label_failed_cycles.green(),
+			self.failed_cycles.to_string().bright_white()
+		);
+
+		println!("\n{}", m.stats_by_register.yellow());
+		println!("{}", "─".repeat(80));
+		println!("{:<20} {:<10} {:<30} {:<10}", m.col_name, m.col_errors, m.col_last_value, m.col_time);
+		println!("{}", "─".repeat(80));
+		for tag in &self.tags {
+			let errors = if tag.errors == 0 {
+				tag.errors.to_string().green()
+			} else {
+				tag.errors.to_string().red()
+			};
+			let last_value = tag.last_value.clone().unwrap_or_else(|| "—".to_string());
+			let last_update = tag
+				.last_update
+				.map(|t| t.format("%H:%M:%S").to_string())
+				.unwrap_or_else(|| "—".to_string());
+			println!(
+				"{:<20} {:<10} {:<30} {:<10}",
+				tag.name.cyan(),
+				errors,
+				last_value.bright_white(),
+				last_update.bright_black()
+			);
+		}
+		println!("{}", "─".repeat(80));
+	}
+}
@@ -0,0 +1,45 @@
+use colored::*;
+
+use modbus_core::port_binding::BindingResolution;
+use modbus_core::ConnectionSettings;
+
+use crate::i18n::{fill, msg};
+
+/// Определение текущего имени порта по привязке к адаптеру с выводом сообщений.
+/// Если адаптер не найден, остается сохраненное имя порта.
+pub fn resolve_bound_port(conn: &mut ConnectionSettings) {
+    let m = msg();
+    let binding = conn
+        .port_binding
+        .as_ref()
+        .map(|binding| binding.describe())
+        .unwrap_or_default();
+
+    match modbus_core::port_binding::resolve_bound_port(conn) {
+        BindingResolution::NotBound => {}
+        BindingResolution::Found { previous } => {
+            if let Some(previous) = previous {
+                println!(
+                    "{}",
+                    fill(m.bound_adapter_found, &[&conn.port, &previous]).cyan()
+                );
+            }
+        }
+        BindingResolution::NotFound => {
+            println!(
+                "{}",
+                fill(m.bound_adapter_not_found, &[&binding, &conn.port]).yellow()
+            );
+        }
+        BindingResolution::Ambiguous { candidates } => {
+            println!("{}", fill(m.bound_adapter_ambiguous, &[&binding]).yellow());
+            for port in &candidates {
+                println!("  {} ({})", port.path, port.details());
+            }
+            println!("{}", fill(m.bound_adapter_using, &[&conn.port]).yellow());
+        }
+        BindingResolution::ScanFailed(e) => {
+            eprintln!("{}", fill(m.bound_adapter_search_error, &[&e]).red());
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use colored::*;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use modbus_core::poller::bit_write_mask;
+use modbus_core::raw_request::{hex_bytes, rtu_adu, BROADCAST_ADDRESS};
+use modbus_core::settings::MAX_SLAVE_ADDRESS;
+use modbus_core::{RawClient, ReadError, RegisterConfig};
+
+use crate::i18n::{exception_title, fill, msg, read_error_text, write_error_text};
+use crate::port_binding::resolve_bound_port;
+use crate::{clear_screen, load_registers_unchecked, load_settings};
+
+/// Таймаут ответа в консоли по умолчанию
+const DEFAULT_TIMEOUT_MS: u64 = 1000;
+
+/// Ограничения количества в одном запросе (спецификация Modbus Application Protocol)
+const MAX_READ_BITS: u16 = 2000;
+const MAX_READ_REGISTERS: u16 = 125;
+const MAX_WRITE_COILS: usize = 1968;
+const MAX_WRITE_REGISTERS: usize = 123;
+const MAX_READ_WRITE_REGISTERS: usize = 121;
+
+/// Команда консоли
+enum Command {
+	/// Запрос к устройству (PDU)
+	Request(Vec<u8>),
+	Slave(u8),
+	Timeout(u64),
+	Help,
+	Quit,
+}
+
+/// Консоль запросов Modbus: стандартные функции, 0x0F, 0x16, 0x17,
+/// запись бита тега и произвольный PDU (интерактивно)
+pub async fn run_console() -> io::Result<()> {
+	let m = msg();
+	clear_screen();
+	println!("{}", m.console_title.cyan().bold());
+
+	let config = match load_settings() {
+		Ok(config) => config,
+		Err(e) => {
+			eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+			println!("{}", m.save_settings_first.yellow());
+			return Ok(());
+		}
+	};
+	// Теги нужны только для команды bit; без tags.csv консоль работает
+	let registers = load_registers_unchecked().map(|cfg| cfg.registers).unwrap_or_default();
+
+	let mut conn = config.connection.clone();
+	resolve_bound_port(&mut conn);
+	println!(
+		"{}",
+		fill(
+			m.line_summary,
+			&[
+				&conn.port.bright_white(),
+				&conn.baud_rate.to_string().bright_white(),
+				&conn.frame_format().bright_white(),
+			]
+		)
+	);
+
+	let mut client = match RawClient::connect(&conn, Duration::from_millis(DEFAULT_TIMEOUT_MS)) {
+		Ok(client) => client,
+		Err(e) => {
+			eprintln!("{}", fill(m.port_open_error, &[&conn.port, &e]).red());
+			return Ok(());
+		}
+	};
+	print_help();
+
+	let mut slave = conn.device_address;
+	loop {
+		print!("\n{} ", format!("[{}]>", slave).cyan());
+		io::stdout().flush()?;
+		let mut input = String::new();
+		if io::stdin().read_line(&mut input)? == 0 {
+			break;
+		}
+		let line = input.trim();
+		if line.is_empty() {
+			continue;
+		}
+
+		match parse_command(line, &registers) {
+			Ok(Command::Request(pdu)) => execute(&mut client, slave, &pdu).await,
+			Ok(Command::Slave(address)) => {
+				slave = address;
+				println!("{}", fill(m.console_slave_set, &[&slave]).green());
+			}
+			Ok(Command::Timeout(ms)) => {
+				client.set_timeout(Duration::from_millis(ms));
+				println!("{}", fill(m.console_timeout_set, &[&ms]).green());
+			}
+			Ok(Command::Help) => print_help(),
+			Ok(Command::Quit) => break,
+			Err(message) => println!("{}", message.red()),
+		}
+	}
+
+	if let Err(e) = client.disconnect().await {
+		eprintln!("{}", fill(m.port_close_error, &[&conn.port, &e]).red());
+	}
+	Ok(())
+}
+
+fn print_help() {
+	let m = msg();
+	println!("\n{}", m.console_help_title.yellow());
+	for (syntax, description) in m.console_commands {
+		println!("  {:<42} {}", syntax.bright_white(), description);
+	}
+}
+
+/// Число: десятичное или шестнадцатеричное с префиксом 0x
+fn parse_number<T: TryFrom<u64>>(text: &str, what: &str) -> Result<T, String> {
+	let parsed = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+		Some(hex) => u64::from_str_radix(hex, 16),
+		None => text.parse::<u64>(),
+	};
+	parsed
+		.ok()
+		.and_then(|value| T::try_from(value).ok())
+		.ok_or_else(|| fill(msg().console_invalid_value, &[&what, &text]))
+}
+
+/// Байт в hex (префикс 0x необязателен)
+fn parse_hex_byte(text: &str) -> Result<u8, String> {
+	let digits = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")).unwrap_or(text);
+	u8::from_str_radix(digits, 16).map_err(|_| fill(msg().console_invalid_byte, &[&text]))
+}
+
+fn parse_bit(text: &str) -> Result<bool, String> {
+	match text {
+		"1" | "on" | "true" => Ok(true),
+		"0" | "off" | "false" => Ok(false),
+		_ => Err(fill(msg().console_expected_bit, &[&text])),
+	}
+}
+
+/// Проверка количества аргументов команды
+fn expect_args(args: &[&str], count: usize, usage: &str) -> Result<(), String> {
+	if args.len() == count {
+		Ok(())
+	} else {
+		Err(fill(msg().console_usage, &[&usage]))
+	}
+}
+
+fn parse_command(line: &str, registers: &[RegisterConfig]) -> Result<Command, String> {
+	let m = msg();
+	let words: Vec<&str> = line.split_whitespace().collect();
+	let (name, args) = (words[0].to_lowercase(), &words[1..]);
+	match name.as_str() {
+		"q" | "quit" | "exit" | "выход" => return Ok(Command::Quit),
+		"help" | "?" | "h" | "справка" => return Ok(Command::Help),
+		"slave" => {
+			expect_args(args, 1, m.usage_slave)?;
+			let address: u8 = parse_number(args[0], m.what_slave_address)?;
+			if address > MAX_SLAVE_ADDRESS {
+				return Err(fill(m.console_slave_range, &[&MAX_SLAVE_ADDRESS]));
+			}
+			return Ok(Command::Slave(address));
+		}
+		"timeout" => {
+			expect_args(args, 1, m.usage_timeout)?;
+			return parse_number(args[0], m.what_timeout).map(Command::Timeout);
+		}
+		"bit" => {
+			expect_args(args, 3, m.usage_bit)?;
+			let register = registers
+				.iter()
+				.find(|register| register.name == args[0])
+				.ok_or_else(|| fill(m.console_tag_not_found, &[&args[0]]))?;
+			let bit: u8 = parse_number(args[1], m.what_bit_number)?;
+			let (address, and_mask, or_mask) =
+				bit_write_mask(register, bit, parse_bit(args[2])?).map_err(|e| write_error_text(&e))?;
+			return Ok(Command::Request(mask_write_pdu(address, and_mask, or_mask)));
+		}
+		"raw" => {
+			if args.is_empty() {
+				return Err(fill(m.console_usage, &[&m.usage_raw]));
+			}
+			let pdu = args.iter().map(|byte| parse_hex_byte(byte)).collect::<Result<Vec<u8>, String>>()?;
+			return Ok(Command::Request(pdu));
+		}
+		_ => {}
+	}
+
+	let function = parse_hex_byte(&name).map_err(|_| fill(m.console_unknown_command, &[&words[0]]))?;
+	let pdu = match function {
+		0x01..=0x04 => {
+			expect_args(args, 2, m.usage_read)?;
+			let address: u16 = parse_number(args[0], m.what_address)?;
+			let count: u16 = parse_number(args[1], m.what_quantity)?;
+			let limit = if function <= 0x02 { MAX_READ_BITS } else { MAX_READ_REGISTERS };
+			if !(1..=limit).contains(&count) {
+				return Err(fill(m.console_count_range, &[&limit]));
+			}
+			words_pdu(function, &[address, count])
+		}
+		0x05 => {
+			expect_args(args, 2, m.usage_write_coil)?;
+			let address: u16 = parse_number(args[0], m.what_address)?;
+			let value = if parse_bit(args[1])? { 0xFF00 } else { 0x0000 };
+			words_pdu(function, &[address, value])
+		}
+		0x06 => {
+			expect_args(args, 2, m.usage_write_register)?;
+			words_pdu(function, &[parse_number(args[0], m.what_address)?, parse_number(args[1], m.what_value)?])
+		}
+		0x0F => {
+			if args.len() < 2 {
+				return Err(fill(m.console_usage, &[&m.usage_write_coils]));
+			}
+			let address: u16 = parse_number(args[0], m.what_address)?;
+			let bits = args[1..].iter().map(|bit| parse_bit(bit)).collect::<Result<Vec<bool>, String>>()?;
+			if bits.len() > MAX_WRITE_COILS {
+				return Err(fill(m.console_max_coils, &[&MAX_WRITE_COILS]));
+			}
+			write_coils_pdu(address, &bits)
+		}
+		0x10 => {
+			if args.len() < 2 {
+				return Err(fill(m.console_usage, &[&m.usage_write_registers]));
+			}
+			let address: u16 = parse_number(args[0], m.what_address)?;
+			let values = parse_values(&args[1..])?;
+			if values.len() > MAX_WRITE_REGISTERS {
+				return Err(fill(m.console_max_registers, &[&MAX_WRITE_REGISTERS]));
+			}
+			let mut pdu = words_pdu(function, &[address, values.len() as u16]);
+			push_values(&mut pdu, &values);
+			pdu
+		}
+		0x16 => {
+			expect_args(args, 3, m.usage_mask_write)?;
+			mask_write_pdu(
+				parse_number(args[0], m.what_address)?,
+				parse_number(args[1], m.what_and_mask)?,
+				parse_number(args[2], m.what_or_mask)?,
+			)
+		}
+		0x17 => {
+			if args.len() < 4 {
+				return Err(fill(m.console_usage, &[&m.usage_read_write]));
+			}
+			let read_address: u16 = parse_number(args[0], m.what_read_address)?;
+			let read_count: u16 = parse_number(args[1], m.what_quantity)?;
+			let write_address: u16 = parse_number(args[2], m.what_write_address)?;
+			let values = parse_values(&args[3..])?;
+			if !(1..=MAX_READ_REGISTERS).contains(&read_count) {
+				return Err(fill(m.console_read_count_range, &[&MAX_READ_REGISTERS]));
+			}
+			if values.len() > MAX_READ_WRITE_REGISTERS {
+				return Err(fill(m.console_max_write_registers, &[&MAX_READ_WRITE_REGISTERS]));
+			}
+			let mut pdu = words_pdu(function, &[read_address, read_count, write_address, values.len() as u16]);
+			push_values(&mut pdu, &values);
+			pdu
+		}
+		_ => return Err(fill(m.console_use_raw, &[&format!("0x{:02X}", function)])),
+	};
+	Ok(Command::Request(pdu))
+}
+
+fn parse_values(args: &[&str]) -> Result<Vec<u16>, String> {
+	args.iter().map(|value| parse_number(value, msg().what_value)).collect()
+}
+
+/// PDU из кода функции и 16-битных полей (старший байт первым)
+fn words_pdu(function: u8, fields: &[u16]) -> Vec<u8> {
+	let mut pdu = vec![function];
+	for field in fields {
+		pdu.extend_from_slice(&field.to_be_bytes());
+	}
+	pdu
+}
+
+/// Счетчик байт и значения регистров для функций 0x10 и 0x17
+fn push_values(pdu: &mut Vec<u8>, values: &[u16]) {
+	pdu.push((values.len() * 2) as u8);
+	for value in values {
+		pdu.extend_from_slice(&value.to_be_bytes());
+	}
+}
+
+fn mask_write_pdu(address: u16, and_mask: u16, or_mask: u16) -> Vec<u8> {
+	words_pdu(0x16, &[address, and_mask, or_mask])
+}
+
+fn write_coils_pdu(address: u16, bits: &[bool]) -> Vec<u8> {
+	let mut pdu = words_pdu(0x0F, &[address, bits.len() as u16]);
+	let packed: Vec<u8> = bits
+		.chunks(8)
+		.map(|chunk| chunk.iter().enumerate().fold(0u8, |byte, (i, bit)| byte | (u8::from(*bit) << i)))
+		.collect();
+	pdu.push(packed.len() as u8);
+	pdu.extend_from_slice(&packed);
+	pdu
+}
+
+/// Выполнение запроса с выводом кадров и разбором ответа
+async fn execute(client: &mut RawClient, slave: u8, pdu: &[u8]) {
+	let m = msg();
+	println!("  {} {}", "→".bright_black(), hex_bytes(&rtu_adu(slave, pdu)).bright_black());
+	let started = Instant::now();
+	let result = client.request(slave, pdu).await;
+	let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+	match result {
+		Ok(_) if slave == BROADCAST_ADDRESS => {
+			println!("{}", m.console_broadcast_sent.green());
+		}
+		Ok(response) => {
+			println!("  {} {}", "←".bright_black(), hex_bytes(&rtu_adu(slave, &response)).bright_black());
+			println!("{}", fill(m.console_response_time, &[&format!("{:.1}", elapsed_ms)]).green());
+			for line in describe_response(pdu, &response) {
+				println!("  {}", line);
+			}
+		}
+		Err(ReadError::Exception(code)) => {
+			println!(
+				"{}",
+				fill(
+					m.console_exception,
+					&[&format!("0x{:02X}", code), &exception_title(code), &format!("{:.1}", elapsed_ms)]
+				)
+				.yellow()
+			);
+		}
+		Err(e) => println!("{}", fill(m.error_with, &[&read_error_text(&e)]).red()),
+	}
+}
+
+/// 16-битное поле PDU по смещению (старший байт первым)
+fn field(pdu: &[u8], offset: usize) -> u16 {
+	pdu.get(offset..offset + 2).map_or(0, |bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+/// Разбор ответа по запросу: значения с адресами для чтения, подтверждение для записи
+fn describe_response(request: &[u8], response: &[u8]) -> Vec<String> {
+	let m = msg();
+	let data = response.get(1..).unwrap_or_default();
+	match request[0] {
+		0x01 | 0x02 if !data.is_empty() => {
+			let (address, count) = (field(request, 1), field(request, 3));
+			(0..count)
+				.filter_map(|i| {
+					let byte = data.get(1 + usize::from(i / 8))?;
+					let bit = (byte >> (i % 8)) & 1;
+					Some(format!("{:>5}: {}", address.wrapping_add(i), bit))
+				})
+				.collect()
+		}
+		0x03 | 0x04 | 0x17 if !data.is_empty() => {
+			let address = field(request, 1);
+			data[1..]
+				.chunks_exact(2)
+				.enumerate()
+				.map(|(i, bytes)| {
+					let value = u16::from_be_bytes([bytes[0], bytes[1]]);
+					format!("{:>5}: {:>5}  0x{:04X}  {:016b}", address.wrapping_add(i as u16), value, value, value)
+				})
+				.collect()
+		}
+		0x05 | 0x06 => vec![fill(
+			m.console_written_single,
+			&[&field(response, 1), &format!("0x{:04X}", field(response, 3))]
+		)],
+		0x0F | 0x10 => vec![fill(m.console_written_multiple, &[&field(response, 3), &field(response, 1)])],
+		0x16 => vec![fill(
+			m.console_mask_written,
+			&[
+				&field(response, 1),
+				&format!("0x{:04X}", field(response, 3)),
+				&format!("0x{:04X}", field(response, 5)),
+			]
+		)],
+		_ => vec![fill(m.console_data, &[&hex_bytes(data)])],
+	}
+}
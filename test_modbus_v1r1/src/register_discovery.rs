@@ -0,0 +1,378 @@
+use colored::*;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+use tokio_modbus::prelude::*;
+
+use modbus_core::modbus_exception::{exception_code, ILLEGAL_DATA_ADDRESS, ILLEGAL_FUNCTION};
+use modbus_core::register_types::{ModbusType, VarType};
+
+use crate::i18n::{exception_title, fill, msg};
+use crate::poll_session::{self, InterruptScope};
+use crate::port_binding::resolve_bound_port;
+use crate::{
+	clear_screen,
+	get_registers_path,
+	load_settings,
+	open_modbus_context,
+	read_value_or_default,
+	write_tags_file,
+	RegisterConfig,
+};
+
+/// Имя файла для черновика найденных регистров по умолчанию
+const DEFAULT_EXPORT_FILE: &str = "discovered_tags.csv";
+
+/// Область данных Modbus, которую можно просканировать
+#[derive(Clone, Copy, PartialEq)]
+enum Area {
+	Coils,
+	DiscreteInputs,
+	InputRegisters,
+	HoldingRegisters,
+}
+
+impl Area {
+	const ALL: [Area; 4] = [
+		Area::Coils,
+		Area::DiscreteInputs,
+		Area::InputRegisters,
+		Area::HoldingRegisters,
+	];
+
+	fn title(self) -> &'static str {
+		match self {
+			Area::Coils => "Coils (0x01)",
+			Area::DiscreteInputs => "Discrete inputs (0x02)",
+			Area::InputRegisters => "Input registers (0x04)",
+			Area::HoldingRegisters => "Holding registers (0x03)",
+		}
+	}
+
+	/// Значение modbus_type в tags.csv
+	fn modbus_type(self) -> ModbusType {
+		match self {
+			Area::Coils => ModbusType::Coil,
+			Area::DiscreteInputs => ModbusType::DiscreteInput,
+			Area::InputRegisters => ModbusType::InputRegister,
+			Area::HoldingRegisters => ModbusType::HoldingRegister,
+		}
+	}
+
+	/// Префикс имени для черновых тегов
+	fn name_prefix(self) -> &'static str {
+		match self {
+			Area::Coils => "coil",
+			Area::DiscreteInputs => "di",
+			Area::InputRegisters => "ir",
+			Area::HoldingRegisters => "hr",
+		}
+	}
+
+	/// Максимальное количество элементов в одном запросе по спецификации Modbus
+	fn max_block(self) -> u16 {
+		match self {
+			Area::Coils | Area::DiscreteInputs => 2000,
+			Area::InputRegisters | Area::HoldingRegisters => 125,
+		}
+	}
+
+	fn is_bit(self) -> bool {
+		matches!(self, Area::Coils | Area::DiscreteInputs)
+	}
+}
+
+/// Параметры сканирования одной области
+struct AreaSweep {
+	area: Area,
+	start: u16,
+	end: u16,
+	block: u16,
+}
+
+/// Найденный адрес и его текущее значение
+struct DiscoveredPoint {
+	area: Area,
+	address: u16,
+	value: u16,
+}
+
+/// Результат чтения одного блока
+enum BlockResult {
+	Values(Vec<u16>),
+	Exception(u8),
+	NoResponse,
+}
+
+/// Чтение блока из области данных с таймаутом
+async fn read_block(
+	ctx: &mut client::Context,
+	area: Area,
+	address: u16,
+	quantity: u16,
+	timeout: Duration,
+) -> BlockResult {
+	let bits_to_words = |bits: Vec<bool>| bits.into_iter().map(u16::from).collect::<Vec<u16>>();
+	let result = match area {
+		Area::Coils => tokio::time::timeout(timeout, ctx.read_coils(address, quantity))
+			.await
+			.map(|r| r.map(bits_to_words)),
+		Area::DiscreteInputs => tokio::time::timeout(timeout, ctx.read_discrete_inputs(address, quantity))
+			.await
+			.map(|r| r.map(bits_to_words)),
+		Area::InputRegisters => tokio::time::timeout(timeout, ctx.read_input_registers(address, quantity)).await,
+		Area::HoldingRegisters => tokio::time::timeout(timeout, ctx.read_holding_registers(address, quantity)).await,
+	};
+
+	match result {
+		Ok(Ok(values)) => BlockResult::Values(values),
+		Ok(Err(e)) => match exception_code(&e) {
+			Some(code) => BlockResult::Exception(code),
+			None => BlockResult::NoResponse,
+		},
+		Err(_) => BlockResult::NoResponse,
+	}
+}
+
+/// Сканирование одной области: блоки уменьшаются вдвое при ответе "недопустимый адрес"
+async fn sweep_area(
+	ctx: &mut client::Context,
+	sweep: &AreaSweep,
+	timeout: Duration,
+	found: &mut Vec<DiscoveredPoint>,
+) -> io::Result<()> {
+	let m = msg();
+	println!("\n{}", fill(m.sweep_started, &[&sweep.area.title(), &sweep.start, &sweep.end]).cyan());
+
+	// Очередь блоков (адрес, количество); обрабатываются по порядку адресов
+	let mut pending: Vec<(u16, u16)> = Vec::new();
+	let mut address = u32::from(sweep.start);
+	while address <= u32::from(sweep.end) {
+		let quantity = (u32::from(sweep.end) - address + 1).min(u32::from(sweep.block)) as u16;
+		pending.push((address as u16, quantity));
+		address += u32::from(quantity);
+	}
+	pending.reverse();
+
+	let found_before = found.len();
+	while let Some((address, quantity)) = pending.pop() {
+		if poll_session::stop_requested() {
+			println!("\n{}", m.scan_stopped.yellow());
+			break;
+		}
+		print!("\r  {}", fill(m.block_label, &[&format!("{:>5}", address), &format!("{:<4}", quantity)]));
+		io::stdout().flush()?;
+
+		match read_block(ctx, sweep.area, address, quantity, timeout).await {
+			BlockResult::Values(values) => {
+				for (offset, value) in values.into_iter().take(usize::from(quantity)).enumerate() {
+					found.push(DiscoveredPoint {
+						area: sweep.area,
+						address: address + offset as u16,
+						value,
+					});
+				}
+			}
+			BlockResult::Exception(ILLEGAL_DATA_ADDRESS) if quantity > 1 => {
+				// Уменьшаем блок: сначала первая половина, затем вторая
+				let first = quantity / 2;
+				pending.push((address + first, quantity - first));
+				pending.push((address, first));
+			}
+			BlockResult::Exception(ILLEGAL_DATA_ADDRESS) => {}
+			BlockResult::Exception(ILLEGAL_FUNCTION) => {
+				println!("\r  {}", m.function_unsupported_skip.yellow());
+				break;
+			}
+			BlockResult::Exception(code) => {
+				println!(
+					"\r  {}: {}",
+					fill(m.block_label, &[&address, &quantity]),
+					fill(m.read_exception, &[&format!("0x{:02X}", code), &exception_title(code).yellow()])
+				);
+			}
+			BlockResult::NoResponse => {
+				println!("\r  {}: {}", fill(m.block_label, &[&address, &quantity]), m.no_response.red());
+			}
+		}
+	}
+
+	println!("\r  {}", fill(m.addresses_found, &[&(found.len() - found_before)]).green());
+	Ok(())
+}
+
+/// Запрос параметров сканирования области; None - область не сканируется
+fn read_area_sweep(area: Area) -> io::Result<Option<AreaSweep>> {
+	let m = msg();
+	println!("\n{}", area.title().yellow());
+	let enabled = read_value_or_default::<String>(m.scan_area_prompt, "y".to_string())?;
+	if !matches!(enabled.to_lowercase().as_str(), "y" | "yes" | "да" | "д" | "1") {
+		return Ok(None);
+	}
+
+	let start = read_value_or_default::<u16>(&format!("  {}", m.start_address_prompt), 0)?;
+	let end = read_value_or_default::<u16>(&format!("  {}", m.end_address_prompt), start.saturating_add(99))?;
+	let default_block = if area.is_bit() { 64 } else { 16 };
+	let block = read_value_or_default::<u16>(&format!("  {}", m.block_size_prompt), default_block)?;
+
+	if end < start {
+		println!("{}", m.end_before_start.red());
+		return Ok(None);
+	}
+	if block == 0 || block > area.max_block() {
+		println!("{}", fill(m.invalid_block_size, &[&area.max_block()]).red());
+		return Ok(None);
+	}
+
+	Ok(Some(AreaSweep { area, start, end, block }))
+}
+
+/// Краткое представление найденных адресов в виде диапазонов
+fn address_ranges(addresses: &[u16]) -> String {
+	let mut ranges: Vec<String> = Vec::new();
+	let mut iter = addresses.iter().copied();
+	let Some(mut first) = iter.next() else {
+		return String::new();
+	};
+	let mut last = first;
+	for address in iter {
+		if u32::from(address) != u32::from(last) + 1 {
+			ranges.push(if first == last { first.to_string() } else { format!("{}-{}", first, last) });
+			first = address;
+		}
+		last = address;
+	}
+	ranges.push(if first == last { first.to_string() } else { format!("{}-{}", first, last) });
+	ranges.join(", ")
+}
+
+/// Вывод найденных адресов и значений
+fn print_discovered(found: &[DiscoveredPoint]) {
+	let m = msg();
+	println!("\n{}", m.found_addresses_title.cyan().bold());
+	for area in Area::ALL {
+		let points: Vec<&DiscoveredPoint> = found.iter().filter(|p| p.area == area).collect();
+		if points.is_empty() {
+			continue;
+		}
+		let addresses: Vec<u16> = points.iter().map(|p| p.address).collect();
+		println!("\n{} ({})", area.title().yellow(), points.len());
+		println!("  {} {}", m.label_ranges.blue(), address_ranges(&addresses).bright_white());
+		println!("  {:<8} {:<8} {:<8}", m.col_address, m.col_value, "Hex");
+		for point in points {
+			println!(
+				"  {:<8} {:<8} {:<8}",
+				point.address.to_string().bright_white(),
+				point.value.to_string().green(),
+				if area.is_bit() { String::new() } else { format!("0x{:04X}", point.value) }
+			);
+		}
+	}
+}
+
+/// Экспорт найденных адресов в черновой файл формата tags.csv
+fn export_draft(found: &[DiscoveredPoint]) -> io::Result<()> {
+	let m = msg();
+	let registers_path = get_registers_path();
+	let default_path = Path::new(&registers_path)
+		.parent()
+		.map(|dir| dir.join(DEFAULT_EXPORT_FILE))
+		.unwrap_or_else(|| Path::new(DEFAULT_EXPORT_FILE).to_path_buf())
+		.to_string_lossy()
+		.to_string();
+	let path = read_value_or_default::<String>(m.draft_file_prompt, default_path)?;
+	if path == registers_path {
+		println!("{}", m.draft_overwrites_tags.red());
+		return Ok(());
+	}
+
+	// Черновые теги отключены: их нужно назвать и включить перед опросом
+	let drafts: Vec<RegisterConfig> = found
+		.iter()
+		.map(|point| RegisterConfig {
+			name: format!("{}_{}", point.area.name_prefix(), point.address),
+			description: fill(m.draft_description, &[&point.value]),
+			address: point.address,
+			var_type: if point.area.is_bit() { VarType::Bool } else { VarType::U16 },
+			modbus_type: point.area.modbus_type(),
+			enabled: false,
+			unit: String::new(),
+		})
+		.collect();
+
+	write_tags_file(&path, &drafts, false)?;
+	println!("{}", fill(m.draft_saved, &[&drafts.len(), &path]).green());
+	Ok(())
+}
+
+/// Сканирование карты регистров устройства (интерактивно)
+pub async fn discover_register_map() -> io::Result<()> {
+	let m = msg();
+	clear_screen();
+	println!("{}", m.discovery_title.cyan().bold());
+
+	let config = match load_settings() {
+		Ok(config) => config,
+		Err(e) => {
+			eprintln!("{}", fill(m.connection_settings_load_error, &[&e]).red());
+			println!("{}", m.save_settings_first.yellow());
+			return Ok(());
+		}
+	};
+	let mut conn = config.connection;
+	resolve_bound_port(&mut conn);
+	println!(
+		"{}",
+		fill(
+			m.port_and_slave,
+			&[&conn.port.bright_white(), &conn.device_address.to_string().bright_white()]
+		)
+	);
+
+	let mut sweeps: Vec<AreaSweep> = Vec::new();
+	for area in Area::ALL {
+		if let Some(sweep) = read_area_sweep(area)? {
+			sweeps.push(sweep);
+		}
+	}
+	if sweeps.is_empty() {
+		println!("{}", m.no_area_selected.yellow());
+		return Ok(());
+	}
+	let timeout_ms = read_value_or_default::<u64>(m.response_timeout_prompt, 300)?;
+	let timeout = Duration::from_millis(timeout_ms);
+
+	println!();
+	let mut ctx = open_modbus_context(&conn).await?;
+	ctx.set_slave(Slave(conn.device_address));
+	println!("{}", m.discovery_stop_hint.yellow());
+
+	let mut found: Vec<DiscoveredPoint> = Vec::new();
+	let scope = InterruptScope::enter();
+	for sweep in &sweeps {
+		if poll_session::stop_requested() {
+			break;
+		}
+		sweep_area(&mut ctx, sweep, timeout, &mut found).await?;
+	}
+	drop(scope);
+
+	if let Err(e) = ctx.disconnect().await {
+		eprintln!("{}", fill(m.port_close_error, &[&conn.port, &e]).red());
+	}
+	drop(ctx);
+
+	if found.is_empty() {
+		println!("\n{}", m.no_points_found.red());
+		return Ok(());
+	}
+
+	print_discovered(&found);
+
+	let export = read_value_or_default::<String>(&format!("\n{}", m.export_draft_prompt), "n".to_string())?;
+	if matches!(export.to_lowercase().as_str(), "y" | "yes" | "да" | "д" | "1") {
+		export_draft(&found)?;
+	}
+
+	Ok(())
+}
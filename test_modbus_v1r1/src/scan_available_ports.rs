@@ -1,34 +1,29 @@
 use colored::*;
 
-pub fn scan_available_ports(available_ports: &mut [u8; 10]) -> usize {
-    let mut count = 0;
+use modbus_core::{list_ports, PortDescriptor};
 
-    println!("Сканирование доступных COM-портов...");
+use crate::i18n::{fill, msg};
 
-    match serialport::available_ports() {
-        Ok(ports) => {
-            for port in ports {
-                if let Some(port_name) = port.port_name.strip_prefix("COM") {
-                    if let Ok(port_num) = port_name.parse::<u8>() {
-                        if count < 10 {
-                            available_ports[count] = port_num;
-                            println!("  Найден: COM{}", port_num);
-                            count += 1;
-                        }
-                    }
-                }
+pub fn scan_available_ports() -> Vec<PortDescriptor> {
+    let m = msg();
+    println!("{}", m.scanning_ports);
+
+    match list_ports() {
+        Ok(found) => {
+            for descriptor in &found {
+                println!("{}", fill(m.port_found, &[&descriptor.path, &descriptor.details()]));
             }
 
-            if count == 0 {
-                println!("{}", "  COM-порты не найдены".yellow());
+            if found.is_empty() {
+                println!("{}", m.serial_ports_not_found.yellow());
             } else {
-                println!("{}", format!("  Всего найдено портов: {}", count).cyan());
+                println!("{}", fill(m.ports_found_total, &[&found.len()]).cyan());
             }
+            found
         }
         Err(e) => {
-            eprintln!("{}", format!("Ошибка сканирования портов: {:?}", e).red());
+            eprintln!("{}", fill(m.port_scan_error, &[&format!("{:?}", e)]).red());
+            Vec::new()
         }
     }
-
-    count
 }
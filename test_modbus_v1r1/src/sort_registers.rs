@@ -1,8 +1,11 @@
 use std::io;
 use colored::*;
 
+use modbus_core::tags::{sort_registers, SortKey};
+
+use crate::i18n::msg;
 use crate::{
-	load_registers,
+	load_registers_unchecked,
 	save_registers_to_csv,
 	RegistersConfig,
 };
@@ -10,15 +13,15 @@ use crate::{
 #[allow(dead_code)]
 /// Читает все регистры из CSV, сортирует по возрастанию адреса и сохраняет обратно
 pub fn sort_registers_by_address() -> io::Result<()> {
-	let mut cfg: RegistersConfig = load_registers()?;
+	let mut cfg: RegistersConfig = load_registers_unchecked()?;
 
 	// Сортировка по адресу по возрастанию
-	cfg.registers.sort_by_key(|reg| reg.address);
+	sort_registers(&mut cfg.registers, SortKey::Address);
 
 	// Перезапись CSV
 	save_registers_to_csv(&cfg.registers)?;
 
-	println!("{}", "Регистры отсортированы по адресу и сохранены в tags.csv".green());
+	println!("{}", msg().registers_sorted.green());
 	Ok(())
 }
 
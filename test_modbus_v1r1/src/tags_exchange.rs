@@ -0,0 +1,161 @@
+use colored::*;
+use std::io::{self, Write};
+use std::path::Path;
+
+use modbus_core::tags_exchange::{export_registers, import_registers, merge_registers, ExchangeFormat, MergeMode};
+use modbus_core::tags_validation::validate_registers;
+
+use crate::i18n::{exchange_format_title, fill, merge_mode_title, msg};
+use crate::{
+    clear_screen,
+    get_registers_path,
+    load_registers_unchecked,
+    print_issues,
+    read_value_or_default,
+    save_registers_to_csv,
+    RegisterConfig,
+};
+
+/// Текущий список тегов; отсутствующий tags.csv считается пустым списком
+fn current_registers() -> io::Result<Vec<RegisterConfig>> {
+    match load_registers_unchecked() {
+        Ok(cfg) => Ok(cfg.registers),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Импорт файла с проверкой результата; tags.csv перезаписывается только если проблем нет
+pub fn import_into_tags_file(path: &str, format: ExchangeFormat, mode: MergeMode) -> io::Result<bool> {
+    let imported = import_registers(path, format)?;
+    println!("{}", fill(msg().tags_in_named_file, &[&path, &imported.len()]));
+    apply_imported(imported, mode)
+}
+
+/// Объединение импортированных тегов с tags.csv, проверка и сохранение
+pub fn apply_imported(imported: Vec<RegisterConfig>, mode: MergeMode) -> io::Result<bool> {
+    let m = msg();
+    let mut registers = current_registers()?;
+    let summary = merge_registers(&mut registers, imported, mode);
+
+    let numbered: Vec<(usize, RegisterConfig)> = registers
+        .iter()
+        .enumerate()
+        .map(|(index, register)| (index + 2, register.clone()))
+        .collect();
+    let issues = validate_registers(&numbered);
+    if !issues.is_empty() {
+        println!("{}", m.import_issues.yellow());
+        print_issues(&issues);
+        println!("{}", m.import_not_done.red());
+        return Ok(false);
+    }
+
+    save_registers_to_csv(&registers)?;
+    println!(
+        "{}",
+        fill(
+            m.import_done,
+            &[&summary.added, &summary.updated, &summary.removed, &registers.len()]
+        )
+        .green()
+    );
+    Ok(true)
+}
+
+/// Выбор пункта из списка по номеру
+fn select_option<T: Copy>(prompt: &str, options: &[T], title: fn(T) -> &'static str) -> io::Result<Option<T>> {
+    let m = msg();
+    println!("\n{}", prompt.yellow());
+    for (index, option) in options.iter().enumerate() {
+        println!("  {} - {}", (index + 1).to_string().green(), title(*option));
+    }
+    println!("  {} - {}", "0".bright_black(), m.option_cancel);
+    print!("\n{} (0-{}): ", m.your_choice, options.len());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    match input.trim().parse::<usize>() {
+        Ok(n) if (1..=options.len()).contains(&n) => Ok(Some(options[n - 1])),
+        Ok(0) => Ok(None),
+        _ => {
+            println!("{}", m.invalid_choice_cancelled.yellow());
+            Ok(None)
+        }
+    }
+}
+
+/// Путь рядом с tags.csv для файла экспорта по умолчанию
+fn default_export_path(format: ExchangeFormat) -> String {
+    let registers_path = get_registers_path();
+    let file_name = format!("tags_export.{}", format.extension());
+    Path::new(&registers_path)
+        .parent()
+        .map(|dir| dir.join(&file_name))
+        .unwrap_or_else(|| Path::new(&file_name).to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Экспорт списка тегов в JSON/YAML/TOML/CSV (интерактивно)
+pub fn export_tags() -> io::Result<()> {
+    let m = msg();
+    clear_screen();
+    println!("{}", m.export_title.cyan().bold());
+
+    let registers = match load_registers_unchecked() {
+        Ok(cfg) => cfg.registers,
+        Err(e) => {
+            eprintln!("{}", fill(m.registers_load_failed, &[&e]).red());
+            return Ok(());
+        }
+    };
+
+    let Some(format) = select_option(m.export_format_prompt, &ExchangeFormat::ALL, exchange_format_title)? else {
+        return Ok(());
+    };
+    let path = read_value_or_default::<String>(m.export_file_prompt, default_export_path(format))?;
+    if path == get_registers_path() {
+        println!("{}", m.export_overwrites_tags.red());
+        return Ok(());
+    }
+
+    export_registers(&path, format, &registers)?;
+    println!(
+        "{}",
+        fill(m.exported_tags_format, &[&registers.len(), &exchange_format_title(format), &path]).green()
+    );
+    Ok(())
+}
+
+/// Импорт списка тегов из JSON/YAML/TOML/CSV (интерактивно)
+pub fn import_tags() -> io::Result<()> {
+    let m = msg();
+    clear_screen();
+    println!("{}", m.import_title.cyan().bold());
+    println!("{}", m.import_format_hint.bright_black());
+
+    print!("\n{}", m.import_file_prompt.yellow());
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let path = input.trim().to_string();
+    if path.is_empty() {
+        println!("{}", m.import_cancelled.bright_black());
+        return Ok(());
+    }
+
+    let Some(format) = ExchangeFormat::from_path(&path) else {
+        println!("{}", m.unknown_file_extension.red());
+        return Ok(());
+    };
+    let Some(mode) = select_option(m.import_mode_prompt, &MergeMode::ALL, merge_mode_title)? else {
+        return Ok(());
+    };
+
+    if let Err(e) = import_into_tags_file(&path, format, mode) {
+        eprintln!("{}", fill(m.import_error, &[&e]).red());
+    }
+    Ok(())
+}
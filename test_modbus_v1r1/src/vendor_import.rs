@@ -0,0 +1,523 @@
+use calamine::{open_workbook_auto, Reader};
+use colored::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use modbus_core::register_types::{parse_modicon_reference, ModbusType, VarType};
+use modbus_core::tags_exchange::MergeMode;
+
+use crate::i18n::{fill, msg};
+use crate::tags_exchange::apply_imported;
+use crate::{clear_screen, read_value_or_default, RegisterConfig};
+
+/// Сколько строк результата показывать в предпросмотре
+const PREVIEW_ROWS: usize = 15;
+
+/// В скольких первых строках искать разделитель и строку заголовков
+const HEADER_SEARCH_ROWS: usize = 20;
+
+/// Запись адреса в таблице производителя
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AddressConvention {
+    /// 40001, 30011, 400001 - область определяется первой цифрой
+    Modicon,
+    /// Смещение от нуля (десятичное или 0x...)
+    ZeroBased,
+    /// Номер регистра от единицы (десятичный или 0x...)
+    OneBased,
+}
+
+impl AddressConvention {
+    const ALL: [AddressConvention; 3] = [
+        AddressConvention::Modicon,
+        AddressConvention::ZeroBased,
+        AddressConvention::OneBased,
+    ];
+
+    fn title(self) -> &'static str {
+        let m = msg();
+        match self {
+            AddressConvention::Modicon => m.convention_modicon,
+            AddressConvention::ZeroBased => m.convention_zero_based,
+            AddressConvention::OneBased => m.convention_one_based,
+        }
+    }
+}
+
+/// Соответствие столбцов таблицы полям RegisterConfig (индексы столбцов)
+struct ColumnMapping {
+    name: usize,
+    description: Option<usize>,
+    address: usize,
+    var_type: Option<usize>,
+    modbus_type: Option<usize>,
+    unit: Option<usize>,
+}
+
+/// Таблица производителя: заголовки и строки данных с номерами строк в файле
+struct VendorTable {
+    headers: Vec<String>,
+    rows: Vec<(usize, Vec<String>)>,
+}
+
+impl VendorTable {
+    fn cell(row: &[String], column: usize) -> &str {
+        row.get(column).map(|value| value.trim()).unwrap_or("")
+    }
+
+    /// Первое непустое значение столбца - для подсказки при выборе
+    fn sample(&self, column: usize) -> &str {
+        self.rows
+            .iter()
+            .map(|(_, row)| Self::cell(row, column))
+            .find(|value| !value.is_empty())
+            .unwrap_or("")
+    }
+
+    /// Различные значения столбца в порядке первого появления
+    fn distinct_values(&self, column: usize) -> Vec<String> {
+        let mut values: Vec<String> = Vec::new();
+        for (_, row) in &self.rows {
+            let value = Self::cell(row, column);
+            if !value.is_empty() && !values.iter().any(|known| known == value) {
+                values.push(value.to_string());
+            }
+        }
+        values
+    }
+}
+
+/// Чтение всех строк таблицы (CSV, XLSX, XLS, ODS) в виде текста
+fn read_sheet_rows(path: &str) -> io::Result<Vec<Vec<String>>> {
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    if extension == "csv" || extension == "txt" {
+        // Таблицы производителей бывают не в UTF-8 - недопустимые символы заменяются
+        let bytes = fs::read(path)?;
+        let content = String::from_utf8_lossy(&bytes);
+        // Над таблицей часто есть заголовок документа, поэтому разделитель ищется по первым строкам
+        let head: Vec<&str> = content.lines().take(HEADER_SEARCH_ROWS).collect();
+        let delimiter = [b';', b'\t', b',']
+            .into_iter()
+            .max_by_key(|delimiter| head.iter().map(|line| line.matches(char::from(*delimiter)).count()).sum::<usize>())
+            .unwrap_or(b';');
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(content.as_bytes());
+        let mut rows = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            rows.push(record.iter().map(str::to_string).collect());
+        }
+        return Ok(rows);
+    }
+
+    let mut workbook = open_workbook_auto(path).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let sheets = workbook.sheet_names();
+    let sheet = match sheets.as_slice() {
+        [] => return Err(io::Error::new(io::ErrorKind::InvalidData, msg().workbook_no_sheets)),
+        [single] => single.clone(),
+        _ => {
+            println!("\n{}", msg().workbook_sheets.yellow());
+            for (index, name) in sheets.iter().enumerate() {
+                println!("  {} - {}", (index + 1).to_string().green(), name);
+            }
+            let choice = read_value_or_default::<usize>(msg().sheet_number, 1)?;
+            sheets
+                .get(choice.wrapping_sub(1))
+                .cloned()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, msg().sheet_out_of_range))?
+        }
+    };
+    let range = workbook
+        .worksheet_range(&sheet)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(range
+        .rows()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .collect())
+}
+
+/// Загрузка таблицы: строка заголовка выбирается пользователем, пустые строки пропускаются
+fn load_vendor_table(path: &str) -> io::Result<Option<VendorTable>> {
+    let rows = read_sheet_rows(path)?;
+    // Строка заголовков по умолчанию - самая заполненная среди первых строк
+    let filled = |row: &Vec<String>| row.iter().filter(|cell| !cell.trim().is_empty()).count();
+    let widest = rows
+        .iter()
+        .take(HEADER_SEARCH_ROWS)
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, row)| filled(row))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+    let header_line = read_value_or_default::<usize>(msg().header_row_prompt, widest + 1)?;
+    if header_line == 0 || header_line > rows.len() {
+        println!("{}", msg().header_row_out_of_table.red());
+        return Ok(None);
+    }
+
+    let headers: Vec<String> = rows[header_line - 1].iter().map(|cell| cell.trim().to_string()).collect();
+    let data: Vec<(usize, Vec<String>)> = rows
+        .into_iter()
+        .enumerate()
+        .skip(header_line)
+        .filter(|(_, row)| row.iter().any(|cell| !cell.trim().is_empty()))
+        .map(|(index, row)| (index + 1, row))
+        .collect();
+    Ok(Some(VendorTable { headers, rows: data }))
+}
+
+/// Подсказка столбца по заголовку
+fn guess_column(headers: &[String], keywords: &[&str]) -> Option<usize> {
+    headers.iter().position(|header| {
+        let header = header.to_lowercase();
+        keywords.iter().any(|keyword| header.contains(keyword))
+    })
+}
+
+/// Выбор столбца по номеру; 0 - столбец не используется (для обязательного поля - отмена импорта)
+fn select_column(table: &VendorTable, field: &str, guess: Option<usize>, required: bool) -> io::Result<Option<usize>> {
+    let m = msg();
+    let default = guess.map(|index| index + 1).unwrap_or(0);
+    let hint = if required { m.column_hint_cancel } else { m.column_hint_none };
+    loop {
+        let choice = read_value_or_default::<usize>(&fill(m.column_for_field, &[&field, &hint]), default)?;
+        if choice == 0 {
+            return Ok(None);
+        }
+        if (1..=table.headers.len()).contains(&choice) {
+            return Ok(Some(choice - 1));
+        }
+        println!("{}", m.invalid_column.red());
+    }
+}
+
+/// Тип значения по названию из документации производителя
+fn guess_var_type(vendor: &str) -> Option<VarType> {
+    let normalized: String = vendor
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+    match normalized.as_str() {
+        "bool" | "bit" | "boolean" | "coil" | "digital" => Some(VarType::Bool),
+        "u16" | "uint" | "uint16" | "word" | "ushort" | "unsigned" | "unsignedint" => Some(VarType::U16),
+        "i16" | "int" | "int16" | "short" | "signed" | "signedint" => Some(VarType::I16),
+        "u32" | "uint32" | "udint" | "dword" | "ulong" | "unsignedlong" => Some(VarType::U32),
+        "i32" | "int32" | "dint" | "long" | "signedlong" => Some(VarType::I32),
+        "float" | "float32" | "real" | "single" | "f32" | "ieee754" => Some(VarType::Float),
+        _ => None,
+    }
+}
+
+/// Область Modbus по названию или номеру функции из документации производителя
+fn guess_modbus_type(vendor: &str) -> Option<ModbusType> {
+    let normalized: String = vendor
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect();
+    let function = normalized
+        .trim_start_matches("fc")
+        .trim_start_matches("0x")
+        .parse::<u8>()
+        .ok();
+    if let Some(function) = function {
+        return ModbusType::ALL.into_iter().find(|area| area.read_function() == function);
+    }
+    match normalized.as_str() {
+        "coil" | "coils" | "do" | "0x" => Some(ModbusType::Coil),
+        "di" | "discreteinput" | "discreteinputs" | "inputstatus" | "1x" => Some(ModbusType::DiscreteInput),
+        "ir" | "input" | "inputregister" | "inputregisters" | "3x" => Some(ModbusType::InputRegister),
+        "hr" | "holding" | "holdingregister" | "holdingregisters" | "4x" => Some(ModbusType::HoldingRegister),
+        _ => None,
+    }
+}
+
+/// Сопоставление значений производителя нашим типам. None в результате - строки с этим значением пропускаются
+fn map_vendor_values<T: Copy>(
+    title: &str,
+    values: &[String],
+    options: &[T],
+    name: fn(T) -> &'static str,
+    guess: fn(&str) -> Option<T>,
+) -> io::Result<BTreeMap<String, Option<T>>> {
+    let m = msg();
+    println!("\n{}", title.yellow());
+    for (index, option) in options.iter().enumerate() {
+        println!("  {} - {}", (index + 1).to_string().green(), name(*option));
+    }
+    println!("  {} - {}", "0".bright_black(), m.skip_value_rows);
+
+    let mut mapping = BTreeMap::new();
+    for value in values {
+        let default = guess(value)
+            .and_then(|guessed| options.iter().position(|option| name(*option) == name(guessed)))
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        let choice = loop {
+            let choice = read_value_or_default::<usize>(&format!("  '{}'", value), default)?;
+            if choice <= options.len() {
+                break choice;
+            }
+            println!("{}", m.invalid_choice_retry.red());
+        };
+        mapping.insert(value.clone(), choice.checked_sub(1).map(|index| options[index]));
+    }
+    Ok(mapping)
+}
+
+/// Разбор числа в десятичной записи или с префиксом 0x
+fn parse_number(text: &str) -> Option<u32> {
+    let text = text.trim();
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        // В XLSX числа иногда хранятся как 40001.0
+        None => text.strip_suffix(".0").unwrap_or(text).parse().ok(),
+    }
+}
+
+/// Преобразование адреса производителя в смещение от нуля (и область для Modicon)
+fn convert_address(text: &str, convention: AddressConvention) -> Result<(u16, Option<ModbusType>), String> {
+    let invalid = || fill(msg().address_not_recognized, &[&text]);
+    match convention {
+        AddressConvention::Modicon => {
+            let text = text.trim();
+            let text = text.strip_suffix(".0").unwrap_or(text);
+            parse_modicon_reference(text)
+                .map(|(area, offset)| (offset, Some(area)))
+                .ok_or_else(invalid)
+        }
+        AddressConvention::ZeroBased => {
+            let number = parse_number(text).ok_or_else(invalid)?;
+            u16::try_from(number).map(|offset| (offset, None)).map_err(|_| invalid())
+        }
+        AddressConvention::OneBased => {
+            let number = parse_number(text).ok_or_else(invalid)?;
+            number
+                .checked_sub(1)
+                .and_then(|offset| u16::try_from(offset).ok())
+                .map(|offset| (offset, None))
+                .ok_or_else(invalid)
+        }
+    }
+}
+
+/// Мастер импорта таблицы регистров производителя (CSV/XLSX) в tags.csv (интерактивно)
+pub fn import_vendor_table() -> io::Result<()> {
+    let m = msg();
+    clear_screen();
+    println!("{}", m.vendor_import_title.cyan().bold());
+    println!("{}", m.vendor_supported_files.bright_black());
+
+    print!("\n{}", m.vendor_file_prompt.yellow());
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let path = input.trim().trim_matches('"').to_string();
+    if path.is_empty() {
+        println!("{}", m.import_cancelled.bright_black());
+        return Ok(());
+    }
+
+    let Some(table) = load_vendor_table(&path)? else {
+        return Ok(());
+    };
+    if table.rows.is_empty() {
+        println!("{}", m.no_data_rows.yellow());
+        return Ok(());
+    }
+    println!("{}", fill(m.data_rows, &[&table.rows.len()]));
+
+    // Шаг 1: столбцы
+    println!("\n{}", m.table_columns.yellow());
+    for (index, header) in table.headers.iter().enumerate() {
+        println!(
+            "  {:>2}. {:<30} {}",
+            index + 1,
+            header.cyan(),
+            fill(m.column_sample, &[&table.sample(index)]).bright_black()
+        );
+    }
+    let headers = &table.headers;
+    let (Some(name), description, Some(address)) = (
+        select_column(&table, "name", guess_column(headers, &["name", "имя", "tag", "тег", "param", "параметр"]), true)?,
+        select_column(&table, "description", guess_column(headers, &["desc", "опис", "comment", "наимен"]), false)?,
+        select_column(&table, "address", guess_column(headers, &["addr", "адрес", "register", "регистр"]), true)?,
+    ) else {
+        println!("{}", m.import_cancelled.bright_black());
+        return Ok(());
+    };
+    let mapping = ColumnMapping {
+        name,
+        description,
+        address,
+        var_type: select_column(&table, "var_type", guess_column(headers, &["type", "тип", "format", "формат"]), false)?,
+        modbus_type: select_column(&table, "modbus_type", guess_column(headers, &["function", "функц", "area", "облас", "fc"]), false)?,
+        unit: select_column(&table, "unit", guess_column(headers, &["unit", "ед.", "единиц", "измер"]), false)?,
+    };
+
+    // Шаг 2: запись адресов
+    let addresses: Vec<&str> = table.rows.iter().map(|(_, row)| VendorTable::cell(row, mapping.address)).collect();
+    // Modicon предлагается, если так записано большинство адресов
+    let modicon_count = addresses
+        .iter()
+        .filter(|address| parse_modicon_reference(address.strip_suffix(".0").unwrap_or(address)).is_some())
+        .count();
+    let looks_modicon = modicon_count * 2 > addresses.len();
+    println!("\n{}", m.table_address_convention.yellow());
+    for (index, convention) in AddressConvention::ALL.iter().enumerate() {
+        println!("  {} - {}", (index + 1).to_string().green(), convention.title());
+    }
+    let default_convention = if looks_modicon { 1 } else { 2 };
+    let convention = loop {
+        let choice = read_value_or_default::<usize>(m.your_choice, default_convention)?;
+        if let Some(convention) = choice.checked_sub(1).and_then(|index| AddressConvention::ALL.get(index)) {
+            break *convention;
+        }
+        println!("{}", m.invalid_choice_retry.red());
+    };
+
+    // Шаг 3: типы производителя
+    let var_types = match mapping.var_type {
+        Some(column) => Some(map_vendor_values(
+            m.map_vendor_types,
+            &table.distinct_values(column),
+            &VarType::ALL,
+            VarType::as_str,
+            guess_var_type,
+        )?),
+        None => None,
+    };
+    let modbus_types = match mapping.modbus_type {
+        Some(column) => Some(map_vendor_values(
+            m.map_vendor_areas,
+            &table.distinct_values(column),
+            &ModbusType::ALL,
+            ModbusType::as_str,
+            guess_modbus_type,
+        )?),
+        None => None,
+    };
+    // Область для всех строк, если ее не дают ни адрес, ни отдельный столбец
+    let fixed_area = if mapping.modbus_type.is_none() && convention != AddressConvention::Modicon {
+        println!("\n{}", m.fixed_area_prompt.yellow());
+        for (index, area) in ModbusType::ALL.iter().enumerate() {
+            println!("  {} - {}", (index + 1).to_string().green(), area.as_str());
+        }
+        let choice = read_value_or_default::<usize>(m.your_choice, 2)?;
+        match choice.checked_sub(1).and_then(|index| ModbusType::ALL.get(index)) {
+            Some(area) => Some(*area),
+            None => {
+                println!("{}", m.invalid_choice_import_cancelled.red());
+                return Ok(());
+            }
+        }
+    } else {
+        None
+    };
+    let enabled = matches!(
+        read_value_or_default::<String>(m.enable_imported_prompt, "y".to_string())?
+            .to_lowercase()
+            .as_str(),
+        "y" | "yes" | "да" | "1"
+    );
+
+    // Шаг 4: преобразование строк
+    let mut imported: Vec<RegisterConfig> = Vec::new();
+    let mut skipped: Vec<(usize, String)> = Vec::new();
+    for (line, row) in &table.rows {
+        let name = VendorTable::cell(row, mapping.name);
+        if name.is_empty() {
+            skipped.push((*line, m.issue_empty_name.to_string()));
+            continue;
+        }
+        let (address, address_area) = match convert_address(VendorTable::cell(row, mapping.address), convention) {
+            Ok(converted) => converted,
+            Err(reason) => {
+                skipped.push((*line, reason));
+                continue;
+            }
+        };
+        let column_area = match (&modbus_types, mapping.modbus_type) {
+            (Some(types), Some(column)) => types.get(VendorTable::cell(row, column)).copied().flatten(),
+            _ => None,
+        };
+        let Some(modbus_type) = column_area.or(address_area).or(fixed_area) else {
+            skipped.push((*line, m.area_not_determined.to_string()));
+            continue;
+        };
+        let var_type = match (&var_types, mapping.var_type) {
+            (Some(types), Some(column)) => match types.get(VendorTable::cell(row, column)).copied().flatten() {
+                Some(var_type) => var_type,
+                None => {
+                    skipped.push((*line, fill(m.type_not_mapped, &[&VendorTable::cell(row, column)])));
+                    continue;
+                }
+            },
+            // Без столбца типа: bool для битовых областей, u16 для регистров
+            _ if modbus_type.is_bit_area() => VarType::Bool,
+            _ => VarType::U16,
+        };
+        imported.push(RegisterConfig {
+            name: name.to_string(),
+            description: mapping
+                .description
+                .map(|column| VendorTable::cell(row, column).to_string())
+                .unwrap_or_default(),
+            address,
+            var_type,
+            modbus_type,
+            enabled,
+            unit: mapping
+                .unit
+                .map(|column| VendorTable::cell(row, column).to_string())
+                .unwrap_or_default(),
+        });
+    }
+
+    // Шаг 5: предпросмотр
+    println!("\n{}", fill(m.preview_title, &[&imported.len()]).yellow());
+    for reg in imported.iter().take(PREVIEW_ROWS) {
+        println!(
+            "  {:<24} {}: {:<5} {}: {:<6} modbus: {:<16} {}",
+            reg.name.cyan(),
+            m.word_address,
+            reg.address,
+            m.word_type,
+            reg.var_type.as_str().yellow(),
+            reg.modbus_type.as_str().blue(),
+            reg.description.bright_black()
+        );
+    }
+    if imported.len() > PREVIEW_ROWS {
+        println!("  {}", fill(m.and_more, &[&(imported.len() - PREVIEW_ROWS)]));
+    }
+    if !skipped.is_empty() {
+        println!("\n{}", fill(m.skipped_rows, &[&skipped.len()]).yellow());
+        for (line, reason) in skipped.iter().take(PREVIEW_ROWS) {
+            println!("  {}", fill(m.skipped_row, &[line, reason]));
+        }
+    }
+    if imported.is_empty() {
+        println!("{}", m.nothing_to_import.red());
+        return Ok(());
+    }
+
+    let confirm = read_value_or_default::<String>(m.append_confirm, "y".to_string())?;
+    if !matches!(confirm.to_lowercase().as_str(), "y" | "yes" | "да" | "1") {
+        println!("{}", m.import_cancelled.bright_black());
+        return Ok(());
+    }
+    apply_imported(imported, MergeMode::Append)?;
+    Ok(())
+}